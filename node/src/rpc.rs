@@ -7,13 +7,25 @@
 
 use std::sync::Arc;
 
-use cyborg_runtime::{opaque::Block, AccountId, Balance, Nonce};
+use cyborg_primitives::{
+	model::{ModelId, ModelInfo, ModelVersion, ModelVersionId, TaskModel},
+	task::{
+		ResourceRequirements, TaskClass, TaskId, TaskInfo, TaskKind, TaskPriority, TaskStatusType,
+	},
+	worker::WorkerId,
+};
+use cyborg_runtime::{
+	apis::TaskManagementApi as TaskManagementRuntimeApi, opaque::Block, AccountId, Balance,
+	BlockNumber, Hash, Nonce,
+};
+use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::error::ErrorObject};
 
 pub use sc_rpc::DenyUnsafe;
 use sc_transaction_pool_api::TransactionPool;
-use sp_api::ProvideRuntimeApi;
+use sp_api::{ApiError, ProvideRuntimeApi};
 use sp_block_builder::BlockBuilder;
 use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
+use sp_runtime::traits::Block as BlockT;
 
 /// A type representing all RPC extensions.
 pub type RpcExtension = jsonrpsee::RpcModule<()>;
@@ -42,6 +54,7 @@ where
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
 	C::Api: BlockBuilder<Block>,
+	C::Api: TaskManagementRuntimeApi<Block>,
 	P: TransactionPool + Sync + Send + 'static,
 {
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
@@ -55,6 +68,266 @@ where
 	} = deps;
 
 	module.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
-	module.merge(TransactionPayment::new(client).into_rpc())?;
+	module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	module.merge(TaskManagement::new(client).into_rpc())?;
 	Ok(module)
 }
+
+/// Queries over the tasks of the task-management pallet, at the best block unless `at` is given.
+#[rpc(server)]
+pub trait TaskManagementApi<BlockHash> {
+	/// The task `task_id`, if it exists and has not been pruned yet.
+	#[method(name = "taskManagement_taskInfo")]
+	fn task_info(
+		&self,
+		task_id: TaskId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<TaskInfo<AccountId, BlockNumber>>>;
+
+	/// Up to `limit` tasks of `owner` with their status, following `start_after` if given.
+	/// Pages hold at most `MAX_TASKS_PER_QUERY` tasks.
+	#[method(name = "taskManagement_tasksByOwner")]
+	fn tasks_by_owner(
+		&self,
+		owner: AccountId,
+		start_after: Option<TaskId>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(TaskId, TaskStatusType)>>;
+
+	/// Up to `limit` tasks `worker` is currently involved in with their status, following
+	/// `start_after` if given.
+	#[method(name = "taskManagement_tasksByWorker")]
+	fn tasks_by_worker(
+		&self,
+		worker: (AccountId, WorkerId),
+		start_after: Option<TaskId>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(TaskId, TaskStatusType)>>;
+
+	/// Tasks of `task_class` waiting for a worker with their priority, in dispatch order.
+	#[method(name = "taskManagement_pendingTasks")]
+	fn pending_tasks(
+		&self,
+		task_class: TaskClass,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(TaskId, TaskPriority)>>;
+
+	/// What running a task of `task_kind` for `compute_hours` compute hours costs.
+	#[method(name = "taskManagement_taskCostEstimate")]
+	fn task_cost_estimate(
+		&self,
+		task_kind: TaskKind,
+		compute_hours: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Balance>;
+
+	/// Workers that can currently be handed a task of `task_class` with `requirements`.
+	#[method(name = "taskManagement_eligibleWorkers")]
+	fn eligible_workers(
+		&self,
+		task_class: TaskClass,
+		requirements: Option<ResourceRequirements>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(AccountId, WorkerId)>>;
+
+	/// How many more tasks `account` can schedule in its current rate limit window, and the
+	/// block from which its next window starts.
+	#[method(name = "taskManagement_taskSubmissionAllowance")]
+	fn task_submission_allowance(
+		&self,
+		account: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<(u32, BlockNumber)>;
+
+	/// The model `model_id` of the model registry.
+	#[method(name = "taskManagement_model")]
+	fn model(
+		&self,
+		model_id: ModelId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<ModelInfo<AccountId, BlockNumber>>>;
+
+	/// All versions of the model `model_id`, oldest first.
+	#[method(name = "taskManagement_modelVersions")]
+	fn model_versions(
+		&self,
+		model_id: ModelId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(ModelVersionId, ModelVersion<Hash, BlockNumber>)>>;
+
+	/// The model version the task `task_id` runs, if it was scheduled with one.
+	#[method(name = "taskManagement_taskModel")]
+	fn task_model(
+		&self,
+		task_id: TaskId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<TaskModel<Hash>>>;
+}
+
+/// Implements [`TaskManagementApiServer`] on top of the `TaskManagementApi` runtime API.
+pub struct TaskManagement<C> {
+	client: Arc<C>,
+}
+
+impl<C> TaskManagement<C> {
+	/// Creates the task-management RPC handler.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client }
+	}
+}
+
+/// Error code returned when the runtime API call fails.
+const RUNTIME_ERROR: i32 = 1;
+
+/// Converts a failed runtime API call into an RPC error.
+fn runtime_error(error: ApiError) -> ErrorObject<'static> {
+	ErrorObject::owned(
+		RUNTIME_ERROR,
+		"Unable to query the task-management runtime API",
+		Some(error.to_string()),
+	)
+}
+
+impl<C> TaskManagementApiServer<<Block as BlockT>::Hash> for TaskManagement<C>
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: TaskManagementRuntimeApi<Block>,
+{
+	fn task_info(
+		&self,
+		task_id: TaskId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<TaskInfo<AccountId, BlockNumber>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self
+			.client
+			.runtime_api()
+			.task_info(at, task_id)
+			.map_err(runtime_error)
+	}
+
+	fn tasks_by_owner(
+		&self,
+		owner: AccountId,
+		start_after: Option<TaskId>,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(TaskId, TaskStatusType)>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self
+			.client
+			.runtime_api()
+			.tasks_by_owner(at, owner, start_after, limit)
+			.map_err(runtime_error)
+	}
+
+	fn tasks_by_worker(
+		&self,
+		worker: (AccountId, WorkerId),
+		start_after: Option<TaskId>,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(TaskId, TaskStatusType)>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self
+			.client
+			.runtime_api()
+			.tasks_by_worker(at, worker, start_after, limit)
+			.map_err(runtime_error)
+	}
+
+	fn pending_tasks(
+		&self,
+		task_class: TaskClass,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(TaskId, TaskPriority)>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self
+			.client
+			.runtime_api()
+			.pending_tasks(at, task_class)
+			.map_err(runtime_error)
+	}
+
+	fn task_cost_estimate(
+		&self,
+		task_kind: TaskKind,
+		compute_hours: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Balance> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self
+			.client
+			.runtime_api()
+			.task_cost_estimate(at, task_kind, compute_hours)
+			.map_err(runtime_error)
+	}
+
+	fn eligible_workers(
+		&self,
+		task_class: TaskClass,
+		requirements: Option<ResourceRequirements>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(AccountId, WorkerId)>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self
+			.client
+			.runtime_api()
+			.eligible_workers(at, task_class, requirements)
+			.map_err(runtime_error)
+	}
+
+	fn task_submission_allowance(
+		&self,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<(u32, BlockNumber)> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self
+			.client
+			.runtime_api()
+			.task_submission_allowance(at, account)
+			.map_err(runtime_error)
+	}
+
+	fn model(
+		&self,
+		model_id: ModelId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<ModelInfo<AccountId, BlockNumber>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self
+			.client
+			.runtime_api()
+			.model(at, model_id)
+			.map_err(runtime_error)
+	}
+
+	fn model_versions(
+		&self,
+		model_id: ModelId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(ModelVersionId, ModelVersion<Hash, BlockNumber>)>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self
+			.client
+			.runtime_api()
+			.model_versions(at, model_id)
+			.map_err(runtime_error)
+	}
+
+	fn task_model(
+		&self,
+		task_id: TaskId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<TaskModel<Hash>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self
+			.client
+			.runtime_api()
+			.task_model(at, task_id)
+			.map_err(runtime_error)
+	}
+}
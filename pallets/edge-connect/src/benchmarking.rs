@@ -4,7 +4,11 @@ use super::*;
 use frame_benchmarking::v2::*;
 
 use cyborg_primitives::worker::*;
-use frame_support::{sp_runtime::traits::ConstU32, BoundedVec};
+use frame_support::{
+	sp_runtime::{traits::ConstU32, Saturating},
+	traits::{Currency, ReservableCurrency},
+	BoundedVec,
+};
 use frame_system::RawOrigin;
 
 // Define a constant for the worker API domain.
@@ -18,6 +22,32 @@ fn get_domain(domain_str: &str) -> BoundedVec<u8, ConstU32<128>> {
 		.expect("Domain string exceeds maximum length")
 }
 
+/// Gives `who` enough funds to reserve the bond of a worker.
+fn fund_bond<T: Config>(who: &T::AccountId) {
+	T::BondCurrency::make_free_balance_be(
+		who,
+		T::BondCurrency::minimum_balance().saturating_add(T::WorkerBond::get()),
+	);
+}
+
+/// Registers an executable worker of `owner`, funding its bond, and returns its id.
+fn register_benchmark_worker<T: Config>(owner: &T::AccountId) -> Result<WorkerId, BenchmarkError> {
+	fund_bond::<T>(owner);
+	Pallet::<T>::register_worker(
+		RawOrigin::Signed(owner.clone()).into(),
+		WorkerType::Executable,
+		get_domain(WORKER_API_DOMAIN),
+		1,
+		103,
+		5_000_000_000,
+		100_000_000_000,
+		8,
+		1,
+	)
+	.map_err(|_| BenchmarkError::Stop("Failed to register worker"))?;
+	AccountWorkers::<T>::get(owner).ok_or(BenchmarkError::Stop("Missing worker"))
+}
+
 /// A function to initialize benchmarking data by creating multiple worker accounts with various attributes
 /// for each worker, including location, specifications, and status.
 ///
@@ -51,14 +81,16 @@ fn set_initial_benchmark_data<T: Config>() {
 			cpu: 5u16,
 		};
 
-		// Maximum number of worker IDs to generate
+		// Number of workers to generate for each creator
 		const MAX_WORKER_ID: u64 = 6;
 
-		// Insert the MAX_WORKER_ID into the AccountWorkers map for the creator
-		AccountWorkers::<T>::insert(creator.clone(), MAX_WORKER_ID);
-
 		// Loop to create multiple workers for the same creator
-		for worker_id in 0..MAX_WORKER_ID {
+		for _ in 0..MAX_WORKER_ID {
+			// Worker IDs are allocated globally, as `register_worker` does
+			let worker_id = NextWorkerId::<T>::get();
+			NextWorkerId::<T>::put(worker_id + 1);
+			AccountWorkers::<T>::insert(creator.clone(), worker_id);
+
 			// Get the current block number
 			let blocknumber = <frame_system::Pallet<T>>::block_number();
 
@@ -75,6 +107,8 @@ fn set_initial_benchmark_data<T: Config>() {
 				owner: creator.clone(),
 				location: worker_location.clone(),
 				specs: worker_specs.clone(),
+				max_concurrent_tasks: 1,
+				encryption_key: None,
 				reputation: reputation,
 				start_block: blocknumber,
 				status: WorkerStatusType::Inactive,
@@ -102,6 +136,7 @@ mod benchmarks {
 		// Define the caller (account) that will be used to register the worker.
 		// `whitelisted_caller()` provides a benchmark-friendly account for testing.
 		let caller: T::AccountId = whitelisted_caller();
+		fund_bond::<T>(&caller);
 
 		// Define the worker's domain and specifications for registration.
 		let domain = get_domain(WORKER_API_DOMAIN);
@@ -110,6 +145,7 @@ mod benchmarks {
 		let ram = 5_000_000_000u64;
 		let storage = 100_000_000_000u64;
 		let cpu = 8u16;
+		let max_concurrent_tasks = 1u32;
 
 		// Benchmark the execution of registering the worker.
 		// The `#[block]` attribute indicates that the following block of code is being benchmarked.
@@ -124,16 +160,18 @@ mod benchmarks {
 				ram,
 				storage,
 				cpu,
+				max_concurrent_tasks,
 			)?;
 		}
 
 		// Verification code
 		// We will check the worker's details by looking them up in the worker cluster storage map.
-		let worker_id = 0;
+		// Worker IDs are global, so the new worker follows the ones created during setup.
+		let worker_id =
+			AccountWorkers::<T>::get(&caller).ok_or(BenchmarkError::Stop("Missing worker"))?;
 
 		// Match the result of querying the worker from the worker cluster storage.
 		// The key is (T::AccountId, WorkerId), where `caller` is the account and `worker_id` is the ID.
-		let worker_id = 0;
 		match ExecutableWorkers::<T>::get((caller.clone(), worker_id)) {
 			Some(worker) => {
 				let id = worker.id;
@@ -160,6 +198,7 @@ mod benchmarks {
 
 		// Assign a caller account that will act as the worker's owner.
 		let caller: T::AccountId = whitelisted_caller();
+		fund_bond::<T>(&caller);
 
 		// Define worker's location and specifications.
 		let domain = get_domain(WORKER_API_DOMAIN);
@@ -168,6 +207,7 @@ mod benchmarks {
 		let ram = 5_000_000_000u64;
 		let storage = 100_000_000_000u64;
 		let cpu = 8u16;
+		let max_concurrent_tasks = 1u32;
 
 		// Register the worker under the specified caller with the above configurations.
 		Pallet::<T>::register_worker(
@@ -179,9 +219,11 @@ mod benchmarks {
 			ram,
 			storage,
 			cpu,
+			max_concurrent_tasks,
 		)?;
 
-		let worker_id = 0;
+		let worker_id =
+			AccountWorkers::<T>::get(&caller).ok_or(BenchmarkError::Stop("Missing worker"))?;
 
 		// Benchmark the execution of removing the worker (block of code to measure).
 		#[block]
@@ -204,6 +246,268 @@ mod benchmarks {
 
 		Ok(())
 	}
+
+	#[benchmark]
+	fn set_encryption_key<T: Config>() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_bond::<T>(&caller);
+		Pallet::<T>::register_worker(
+			RawOrigin::Signed(caller.clone()).into(),
+			WorkerType::Docker,
+			get_domain(WORKER_API_DOMAIN),
+			1,
+			103,
+			5_000_000_000,
+			100_000_000_000,
+			8,
+			1,
+		)?;
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			WorkerType::Docker,
+			0,
+			Some([1u8; 32]),
+		);
+
+		let worker =
+			WorkerClusters::<T>::get((caller, 0)).ok_or(BenchmarkError::Stop("Missing worker"))?;
+		assert_eq!(worker.encryption_key, Some([1u8; 32]));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn withdraw_unbonded<T: Config>() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_bond::<T>(&caller);
+		Pallet::<T>::register_worker(
+			RawOrigin::Signed(caller.clone()).into(),
+			WorkerType::Executable,
+			get_domain(WORKER_API_DOMAIN),
+			1,
+			103,
+			5_000_000_000,
+			100_000_000_000,
+			8,
+			1,
+		)?;
+		let worker_id =
+			AccountWorkers::<T>::get(&caller).ok_or(BenchmarkError::Stop("Missing worker"))?;
+		Pallet::<T>::remove_worker(
+			RawOrigin::Signed(caller.clone()).into(),
+			WorkerType::Executable,
+			worker_id,
+		)?;
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number().saturating_add(T::UnbondingPeriod::get()),
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), worker_id);
+
+		assert!(UnbondingBonds::<T>::get((caller.clone(), worker_id)).is_none());
+		assert_eq!(T::BondCurrency::reserved_balance(&caller), 0u32.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn update_worker<T: Config>() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_bond::<T>(&caller);
+		Pallet::<T>::register_worker(
+			RawOrigin::Signed(caller.clone()).into(),
+			WorkerType::Docker,
+			get_domain(WORKER_API_DOMAIN),
+			1,
+			103,
+			5_000_000_000,
+			100_000_000_000,
+			8,
+			1,
+		)?;
+		let worker_id =
+			AccountWorkers::<T>::get(&caller).ok_or(BenchmarkError::Stop("Missing worker"))?;
+
+		// Change every field and upgrade the specs, so the worker also awaits attestation
+		let domain = get_domain("https://updated.worker.example.com");
+		let location = Location {
+			latitude: 2,
+			longitude: 104,
+		};
+		let specs = WorkerSpecs {
+			ram: 10_000_000_000,
+			storage: 200_000_000_000,
+			cpu: 16,
+		};
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			WorkerType::Docker,
+			worker_id,
+			Some(domain.clone()),
+			Some(location),
+			Some(specs.clone()),
+		);
+
+		let worker = WorkerClusters::<T>::get((caller, worker_id))
+			.ok_or(BenchmarkError::Stop("Missing worker"))?;
+		assert_eq!(worker.api.domain, domain);
+		assert_eq!(worker.specs, specs);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn toggle_worker_visibility<T: Config>() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let worker_id = register_benchmark_worker::<T>(&caller)?;
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			WorkerType::Executable,
+			worker_id,
+			true,
+		);
+
+		let worker = ExecutableWorkers::<T>::get((caller, worker_id))
+			.ok_or(BenchmarkError::Stop("Missing worker"))?;
+		assert_eq!(worker.status, WorkerStatusType::Active);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn penalize_worker<T: Config>() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let worker_id = register_benchmark_worker::<T>(&owner)?;
+
+		// A penalty heavy enough to suspend the worker
+		#[extrinsic_call]
+		_(
+			RawOrigin::Root,
+			owner.clone(),
+			worker_id,
+			WorkerType::Executable,
+			100,
+			PenaltyReason::FalseCompletion,
+		);
+
+		let worker = ExecutableWorkers::<T>::get((owner, worker_id))
+			.ok_or(BenchmarkError::Stop("Missing worker"))?;
+		assert_eq!(worker.status, WorkerStatusType::Suspended);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn suspend_worker<T: Config>() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let worker_id = register_benchmark_worker::<T>(&owner)?;
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Root,
+			owner.clone(),
+			worker_id,
+			WorkerType::Executable,
+			100u32.into(),
+			SuspensionReason::MaliciousActivity,
+		);
+
+		let worker = ExecutableWorkers::<T>::get((owner, worker_id))
+			.ok_or(BenchmarkError::Stop("Missing worker"))?;
+		assert_eq!(worker.status, WorkerStatusType::Suspended);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn unsuspend_worker<T: Config>() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let worker_id = register_benchmark_worker::<T>(&owner)?;
+		Pallet::<T>::suspend_worker(
+			RawOrigin::Root.into(),
+			owner.clone(),
+			worker_id,
+			WorkerType::Executable,
+			100u32.into(),
+			SuspensionReason::MaliciousActivity,
+		)?;
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Root,
+			owner.clone(),
+			worker_id,
+			WorkerType::Executable,
+		);
+
+		let worker = ExecutableWorkers::<T>::get((owner, worker_id))
+			.ok_or(BenchmarkError::Stop("Missing worker"))?;
+		assert_eq!(worker.status, WorkerStatusType::Inactive);
+		Ok(())
+	}
+
+	// Making the other workers of the account and domain inactive is accounted for by
+	// `ban_deactivation_weight`
+	#[benchmark]
+	fn ban_worker<T: Config>() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = whitelisted_caller();
+		let worker_id = register_benchmark_worker::<T>(&owner)?;
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Root,
+			owner.clone(),
+			worker_id,
+			WorkerType::Executable,
+			SuspensionReason::MaliciousActivity,
+		);
+
+		assert!(ExecutableWorkers::<T>::get((owner.clone(), worker_id)).is_none());
+		assert!(BannedAccounts::<T>::contains_key(&owner));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn unban_account<T: Config>() -> Result<(), BenchmarkError> {
+		let account: T::AccountId = whitelisted_caller();
+		BannedAccounts::<T>::insert(
+			&account,
+			(
+				frame_system::Pallet::<T>::block_number(),
+				SuspensionReason::MaliciousActivity,
+			),
+		);
+		let origin =
+			T::BanAppealOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, account.clone());
+
+		assert!(!BannedAccounts::<T>::contains_key(&account));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn unban_domain<T: Config>() -> Result<(), BenchmarkError> {
+		let domain = get_domain(WORKER_API_DOMAIN);
+		BannedDomains::<T>::insert(
+			&domain,
+			(
+				frame_system::Pallet::<T>::block_number(),
+				SuspensionReason::MaliciousActivity,
+			),
+		);
+		let origin =
+			T::BanAppealOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, domain.clone());
+
+		assert!(!BannedDomains::<T>::contains_key(&domain));
+		Ok(())
+	}
+
 	// Defines the benchmark test suite, linking it to the pallet and mock runtime
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -11,6 +11,7 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migrations;
 pub mod weights;
 pub use weights::*;
 
@@ -19,7 +20,11 @@ pub use cyborg_primitives::worker::*;
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
-	use frame_support::sp_runtime::Saturating;
+	use frame_support::sp_runtime::{
+		traits::{SaturatedConversion, Zero},
+		Perbill, Saturating,
+	};
+	use frame_support::traits::{Currency, OnUnbalanced, ReservableCurrency};
 	use frame_support::{dispatch::DispatchResultWithPostInfo, pallet_prelude::*};
 	use frame_system::pallet_prelude::*;
 	use pallet_timestamp as timestamp;
@@ -36,9 +41,82 @@ pub mod pallet {
 
 		/// A type representing the weights required by the dispatchables of this pallet.
 		type WeightInfo: WeightInfo;
+
+		/// The currency worker bonds are reserved in.
+		type BondCurrency: ReservableCurrency<Self::AccountId>;
+
+		/// Bond reserved from the owner of a worker when it is registered.
+		#[pallet::constant]
+		type WorkerBond: Get<BalanceOf<Self>>;
+
+		/// Number of blocks the bond of a removed or banned worker stays reserved before its owner
+		/// can withdraw it.
+		#[pallet::constant]
+		type UnbondingPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Part of the remaining bond slashed each time a worker is penalized or suspended by root.
+		/// A penalty that suspends the worker only slashes once.
+		#[pallet::constant]
+		type PenaltySlash: Get<Perbill>;
+
+		/// Part of the remaining bond slashed when a worker is banned.
+		#[pallet::constant]
+		type BanSlash: Get<Perbill>;
+
+		/// Handler for the slashed part of worker bonds, e.g. a treasury. `()` burns it.
+		type Slash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		/// Whether a worker that upgrades its specs must be attested by the oracle again before
+		/// it can be made schedulable.
+		#[pallet::constant]
+		type ReattestSpecUpgrades: Get<bool>;
+
+		/// Origin that can lift a ban on appeal, e.g. root or a council.
+		type BanAppealOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Reputation score a worker gains for each task whose result was accepted.
+		#[pallet::constant]
+		type SuccessReward: Get<i32>;
+
+		/// Reputation score rewards can't raise a worker above.
+		#[pallet::constant]
+		type MaxReputationScore: Get<i32>;
+
+		/// Number of blocks without a penalty after which one violation of a worker is forgiven
+		/// and its score recovers by `ReputationRecovery`. Zero disables the decay.
+		#[pallet::constant]
+		type ViolationDecayPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Score that the reputation of a penalized worker recovers to over time.
+		#[pallet::constant]
+		type ReputationBaseline: Get<i32>;
+
+		/// Reputation score a worker below `ReputationBaseline` regains for every
+		/// `ViolationDecayPeriod` blocks without a penalty.
+		#[pallet::constant]
+		type ReputationRecovery: Get<i32>;
+
+		/// Maximum number of workers an account can have registered at once. Bounds the weight
+		/// of banning an account.
+		#[pallet::constant]
+		type MaxWorkersPerAccount: Get<u32>;
+
+		/// Notified when a worker is made `Active` by its owner, e.g. to hand it queued tasks.
+		type WorkerStatusHandler: WorkerStatusHandler<Self::AccountId, WorkerId>;
 	}
 
+	pub type BalanceOf<T> =
+		<<T as Config>::BondCurrency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+	pub type NegativeImbalanceOf<T> = <<T as Config>::BondCurrency as Currency<
+		<T as frame_system::Config>::AccountId,
+	>>::NegativeImbalance;
+
+	/// The in-code storage version, see [`crate::migrations`].
+	pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	// A helper function providing a default value for worker IDs.
@@ -54,12 +132,64 @@ pub mod pallet {
 	}
 
 	/// AccountWorkers Information, Storage map for associating an account ID with a worker ID. If no worker exists, the query returns None.
-	/// Keeps track of workerIds per account if any
+	/// Keeps track of the ID of the last worker registered by an account, if any
 	#[pallet::storage]
 	#[pallet::getter(fn account_workers)]
 	pub type AccountWorkers<T: Config> =
 		StorageMap<_, Twox64Concat, T::AccountId, WorkerId, OptionQuery>;
 
+	/// The registered workers of each account.
+	#[pallet::storage]
+	pub type WorkersByAccount<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		BoundedVec<(WorkerType, WorkerId), T::MaxWorkersPerAccount>,
+		ValueQuery,
+	>;
+
+	/// The next worker ID to be assigned. IDs are unique across all accounts.
+	#[pallet::storage]
+	pub type NextWorkerId<T: Config> = StorageValue<_, WorkerId, ValueQuery, WorkerCountDefault>;
+
+	/// The worker registered under a domain, per worker type.
+	#[pallet::storage]
+	pub type WorkerDomains<T: Config> =
+		StorageMap<_, Blake2_128Concat, (WorkerType, Domain), (T::AccountId, WorkerId), OptionQuery>;
+
+	/// The bond reserved for a registered worker, less what was slashed.
+	#[pallet::storage]
+	pub type WorkerBonds<T: Config> =
+		StorageMap<_, Twox64Concat, (T::AccountId, WorkerId), BalanceOf<T>, OptionQuery>;
+
+	/// The bond of a removed or banned worker and the block from which its owner can withdraw it.
+	#[pallet::storage]
+	pub type UnbondingBonds<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		(T::AccountId, WorkerId),
+		(BalanceOf<T>, BlockNumberFor<T>),
+		OptionQuery,
+	>;
+
+	/// Workers that upgraded their specs and wait for the oracle to report them online again,
+	/// with the block of the upgrade.
+	#[pallet::storage]
+	pub type AwaitingAttestation<T: Config> =
+		StorageMap<_, Twox64Concat, (T::AccountId, WorkerId), BlockNumberFor<T>, OptionQuery>;
+
+	/// Accounts that own a banned worker and can't register workers, with the block and reason of
+	/// the ban.
+	#[pallet::storage]
+	pub type BannedAccounts<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, (BlockNumberFor<T>, SuspensionReason), OptionQuery>;
+
+	/// Domains of banned workers that can't be registered again, with the block and reason of the
+	/// ban.
+	#[pallet::storage]
+	pub type BannedDomains<T: Config> =
+		StorageMap<_, Blake2_128Concat, Domain, (BlockNumberFor<T>, SuspensionReason), OptionQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn suspended_workers)]
 	pub type SuspendedWorkers<T: Config> = StorageMap<
@@ -90,6 +220,29 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// The `Active` workers of each type, by position from 0 to `ActiveWorkerCount`. Kept in
+	/// line with the status of workers by `store_worker`, so that task-management can pick
+	/// workers without going through all of them.
+	#[pallet::storage]
+	pub type ActiveWorkers<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		WorkerType,
+		Twox64Concat,
+		u32,
+		(T::AccountId, WorkerId),
+		OptionQuery,
+	>;
+
+	/// Number of `Active` workers of each type.
+	#[pallet::storage]
+	pub type ActiveWorkerCount<T: Config> = StorageMap<_, Twox64Concat, WorkerType, u32, ValueQuery>;
+
+	/// Position of an `Active` worker in `ActiveWorkers`.
+	#[pallet::storage]
+	pub type ActiveWorkerPositions<T: Config> =
+		StorageMap<_, Twox64Concat, (WorkerType, (T::AccountId, WorkerId)), u32, OptionQuery>;
+
 	/// The `Event` enum contains the various events that can be emitted by this pallet.
 	/// Events are emitted when significant actions or state changes happen in the pallet.
 	#[pallet::event]
@@ -143,6 +296,12 @@ pub mod pallet {
 			reason: PenaltyReason,
 		},
 
+		/// Event emitted when a worker is rewarded for a task whose result was accepted
+		WorkerRewarded {
+			worker: (T::AccountId, WorkerId),
+			score: i32,
+		},
+
 		/// Event emitted when a worker is suspended
 		WorkerSuspended {
 			worker: (T::AccountId, WorkerId),
@@ -163,6 +322,47 @@ pub mod pallet {
 
 		/// Event emitted when a worker is unsuspended
 		WorkerUnsuspended { worker: (T::AccountId, WorkerId) },
+
+		/// Event emitted when a worker sets or clears the key confidential tasks are encrypted to
+		WorkerEncryptionKeySet {
+			worker: (T::AccountId, WorkerId),
+			encryption_key: Option<EncryptionKey>,
+		},
+
+		/// Event emitted when the owner of a worker changes its domain, location or specs
+		WorkerUpdated {
+			worker: (T::AccountId, WorkerId),
+			domain: Domain,
+			awaiting_attestation: bool,
+		},
+
+		/// Event emitted when the oracle reports a worker awaiting attestation online
+		WorkerAttested { worker: (T::AccountId, WorkerId) },
+
+		/// Event emitted when the ban of an account is lifted on appeal
+		AccountUnbanned { account: T::AccountId },
+
+		/// Event emitted when the ban of a domain is lifted on appeal
+		DomainUnbanned { domain: Domain },
+
+		/// Event emitted when part of the bond of a worker is slashed
+		WorkerBondSlashed {
+			worker: (T::AccountId, WorkerId),
+			amount: BalanceOf<T>,
+		},
+
+		/// Event emitted when the bond of a removed or banned worker starts unbonding
+		WorkerBondUnbonding {
+			worker: (T::AccountId, WorkerId),
+			amount: BalanceOf<T>,
+			until_block: BlockNumberFor<T>,
+		},
+
+		/// Event emitted when the owner of a worker withdraws its unbonded bond
+		WorkerBondWithdrawn {
+			worker: (T::AccountId, WorkerId),
+			amount: BalanceOf<T>,
+		},
 	}
 
 	#[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
@@ -188,6 +388,22 @@ pub mod pallet {
 		WorkerSuspended,
 		/// Worker reputation is too low
 		InsufficientReputation,
+		/// A worker must accept at least one task at a time.
+		InvalidMaxConcurrentTasks,
+		/// The worker has no bond that is unbonding.
+		NotUnbonding,
+		/// The unbonding period of the bond has not passed yet.
+		UnbondingPeriodNotOver,
+		/// The worker upgraded its specs and has not been attested by the oracle since.
+		AwaitingAttestation,
+		/// The account owned a banned worker.
+		AccountBanned,
+		/// The domain belonged to a banned worker.
+		DomainBanned,
+		/// The account or domain is not banned.
+		NotBanned,
+		/// The account has as many workers registered as it can.
+		TooManyWorkers,
 	}
 
 	// This block defines the dispatchable functions (calls) for the pallet.
@@ -197,6 +413,11 @@ pub mod pallet {
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Registers a Worker with either a domain and initialize it with an inactive status.
+		///
+		/// `max_concurrent_tasks` is the number of tasks the worker accepts at the same time; it
+		/// is reported `Busy` while it runs that many.
+		///
+		/// `T::WorkerBond` is reserved from the caller until the worker is removed.
 		#[pallet::call_index(0)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::register_worker())]
 		pub fn register_worker(
@@ -208,65 +429,50 @@ pub mod pallet {
 			ram: RamBytes,
 			storage: StorageBytes,
 			cpu: CpuCores,
+			max_concurrent_tasks: u32,
 		) -> DispatchResultWithPostInfo {
 			let creator = ensure_signed(origin)?;
+			ensure!(
+				max_concurrent_tasks > 0,
+				Error::<T>::InvalidMaxConcurrentTasks
+			);
 
 			let api = WorkerAPI { domain };
-			let worker_keys = AccountWorkers::<T>::get(creator.clone());
 			let worker_location = Location {
 				latitude,
 				longitude,
 			};
 			let worker_specs = WorkerSpecs { ram, storage, cpu };
 
-			//TODO: There needs to be a proper id mechanism to avoid loops and the increment id system
-			match worker_keys {
-				Some(keys) => {
-					for id in 0..=keys {
-						// Get the Worker associated with the creator and worker_id
-						if let Some(worker) = WorkerClusters::<T>::get((creator.clone(), id)) {
-							if worker_type == cyborg_primitives::worker::WorkerType::Docker {
-								// Check if the API matches and throw an error if it does
-								if api == worker.api {
-									// The event is necessary since the worker still needs it's data if it is already registered
-									Self::deposit_event(Event::WorkerAlreadyRegistered {
-										creator: creator.clone(),
-										worker: (creator.clone(), worker.id),
-										domain: worker.api.domain,
-									});
-									return Err(Error::<T>::WorkerExists.into());
-								}
-							}
-						}
-						if let Some(worker) = ExecutableWorkers::<T>::get((creator.clone(), id)) {
-							if worker_type == cyborg_primitives::worker::WorkerType::Executable {
-								// Check if the API matches and throw an error if it does
-								if api == worker.api {
-									// The event is necessary since the worker still needs it's data if it is already registered
-									Self::deposit_event(Event::WorkerAlreadyRegistered {
-										creator: creator.clone(),
-										worker: (creator.clone(), worker.id),
-										domain: worker.api.domain,
-									});
-									return Err(Error::<T>::WorkerExists.into());
-								}
-							}
-						}
-					}
-				}
-				None => {}
+			ensure!(
+				!BannedAccounts::<T>::contains_key(&creator),
+				Error::<T>::AccountBanned
+			);
+			ensure!(
+				!BannedDomains::<T>::contains_key(&api.domain),
+				Error::<T>::DomainBanned
+			);
+
+			let domain_key = (worker_type.clone(), api.domain.clone());
+			if let Some(worker) = WorkerDomains::<T>::get(&domain_key) {
+				// The event is necessary since the worker still needs it's data if it is already registered
+				Self::deposit_event(Event::WorkerAlreadyRegistered {
+					creator,
+					worker,
+					domain: api.domain,
+				});
+				return Err(Error::<T>::WorkerExists.into());
 			}
 
-			let worker_id: WorkerId = match AccountWorkers::<T>::get(creator.clone()) {
-				Some(id) => {
-					AccountWorkers::<T>::insert(creator.clone(), id + 1);
-					id + 1
-				}
-				None => {
-					AccountWorkers::<T>::insert(creator.clone(), 0);
-					0
-				}
-			};
+			let bond = T::WorkerBond::get();
+			T::BondCurrency::reserve(&creator, bond)?;
+
+			let worker_id = NextWorkerId::<T>::get();
+			NextWorkerId::<T>::put(worker_id.wrapping_add(1));
+			WorkersByAccount::<T>::try_mutate(&creator, |workers| {
+				workers.try_push((worker_type.clone(), worker_id))
+			})
+			.map_err(|_| Error::<T>::TooManyWorkers)?;
 
 			let blocknumber = <frame_system::Pallet<T>>::block_number();
 			let worker = Worker {
@@ -274,6 +480,8 @@ pub mod pallet {
 				owner: creator.clone(),
 				location: worker_location,
 				specs: worker_specs,
+				max_concurrent_tasks,
+				encryption_key: None,
 				reputation: WorkerReputation::<BlockNumberFor<T>>::default(),
 				start_block: blocknumber.clone(),
 				status: WorkerStatusType::Inactive,
@@ -284,15 +492,10 @@ pub mod pallet {
 
 			// update storage
 			AccountWorkers::<T>::insert(creator.clone(), worker_id.clone());
+			WorkerDomains::<T>::insert(domain_key, (creator.clone(), worker_id));
+			WorkerBonds::<T>::insert((creator.clone(), worker_id), bond);
 
-			match worker_type {
-				cyborg_primitives::worker::WorkerType::Docker => {
-					WorkerClusters::<T>::insert((creator.clone(), worker_id.clone()), worker.clone());
-				}
-				cyborg_primitives::worker::WorkerType::Executable => {
-					ExecutableWorkers::<T>::insert((creator.clone(), worker_id.clone()), worker.clone());
-				}
-			}
+			Self::store_worker(&(creator.clone(), worker_id), &worker_type, worker.clone());
 
 			// Emit an event.
 			Self::deposit_event(Event::WorkerRegistered {
@@ -306,6 +509,8 @@ pub mod pallet {
 		}
 
 		/// Remove a worker from storage an deactivates it
+		///
+		/// Its bond can be withdrawn with `withdraw_unbonded` after `T::UnbondingPeriod` blocks.
 		#[pallet::call_index(1)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_worker())]
 		pub fn remove_worker(
@@ -315,26 +520,13 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let creator = ensure_signed(origin)?;
 
-			match worker_type {
-				WorkerType::Docker => {
-					ensure!(
-						WorkerClusters::<T>::get((creator.clone(), worker_id)) != None,
-						Error::<T>::WorkerDoesNotExist
-					);
-
-					// update storage
-					WorkerClusters::<T>::remove((creator.clone(), worker_id));
-				}
-				WorkerType::Executable => {
-					ensure!(
-						ExecutableWorkers::<T>::get((creator.clone(), worker_id)) != None,
-						Error::<T>::WorkerDoesNotExist
-					);
+			let worker = Self::take_worker(&(creator.clone(), worker_id), &worker_type)
+				.ok_or(Error::<T>::WorkerDoesNotExist)?;
 
-					// update storage
-					ExecutableWorkers::<T>::remove((creator.clone(), worker_id));
-				}
-			}
+			// update storage
+			WorkerDomains::<T>::remove((worker_type, worker.api.domain));
+			AwaitingAttestation::<T>::remove((creator.clone(), worker_id));
+			Self::unbond(&(creator.clone(), worker_id));
 
 			// Emit an event.
 			Self::deposit_event(Event::WorkerRemoved { creator, worker_id });
@@ -343,9 +535,11 @@ pub mod pallet {
 			Ok(().into())
 		}
 
-		/// Switches the visibility of a worker between active and inactive.
+		/// Switches the visibility of a worker between active and inactive. A worker made active
+		/// is reported to `T::WorkerStatusHandler`.
 		#[pallet::call_index(2)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::toggle_worker_visibility())]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::toggle_worker_visibility()
+			.saturating_add(T::WorkerStatusHandler::on_worker_active_weight()))]
 		pub fn toggle_worker_visibility(
 			origin: OriginFor<T>,
 			worker_type: WorkerType,
@@ -353,48 +547,42 @@ pub mod pallet {
 			visibility: bool,
 		) -> DispatchResultWithPostInfo {
 			let creator = ensure_signed(origin)?;
+			ensure!(
+				!visibility || !AwaitingAttestation::<T>::contains_key((creator.clone(), worker_id)),
+				Error::<T>::AwaitingAttestation
+			);
+			ensure!(
+				!visibility || !BannedAccounts::<T>::contains_key(&creator),
+				Error::<T>::AccountBanned
+			);
 			let worker_status = if visibility {
 				WorkerStatusType::Active
 			} else {
 				WorkerStatusType::Inactive
 			};
 
-			match worker_type {
-				WorkerType::Docker => {
-					WorkerClusters::<T>::mutate((creator.clone(), worker_id), |worker_option| {
-						if let Some(worker) = worker_option {
-							worker.status = worker_status;
-							worker.last_status_check = timestamp::Pallet::<T>::get();
-
-							Self::deposit_event(Event::WorkerStatusUpdated {
-								creator,
-								worker_id,
-								worker_status: worker.status.clone(),
-							});
-							Ok(())
-						} else {
-							Err(Error::<T>::WorkerDoesNotExist)
-						}
-					})
-				}
-				WorkerType::Executable => {
-					ExecutableWorkers::<T>::mutate((creator.clone(), worker_id), |worker_option| {
-						if let Some(worker) = worker_option {
-							worker.status = worker_status;
-							worker.last_status_check = timestamp::Pallet::<T>::get();
-
-							Self::deposit_event(Event::WorkerStatusUpdated {
-								creator,
-								worker_id,
-								worker_status: worker.status.clone(),
-							});
-							Ok(())
-						} else {
-							Err(Error::<T>::WorkerDoesNotExist)
-						}
-					})
-				}
-			}?;
+			let worker_key = (creator.clone(), worker_id);
+			let mut worker = match worker_type {
+				WorkerType::Docker => WorkerClusters::<T>::get(&worker_key),
+				WorkerType::Executable => ExecutableWorkers::<T>::get(&worker_key),
+			}
+			.ok_or(Error::<T>::WorkerDoesNotExist)?;
+			ensure!(
+				!visibility || !BannedDomains::<T>::contains_key(&worker.api.domain),
+				Error::<T>::DomainBanned
+			);
+			worker.status = worker_status;
+			worker.last_status_check = timestamp::Pallet::<T>::get();
+
+			Self::deposit_event(Event::WorkerStatusUpdated {
+				creator,
+				worker_id,
+				worker_status: worker.status.clone(),
+			});
+			Self::store_worker(&worker_key, &worker_type, worker);
+			if visibility {
+				T::WorkerStatusHandler::on_worker_active(&worker_key, &worker_type);
+			}
 
 			Ok(().into())
 		}
@@ -429,12 +617,20 @@ pub mod pallet {
 		) -> DispatchResult {
 			ensure_root(origin)?;
 
-			Self::suspend_workers(&(worker_owner, worker_id), &worker_type, blocks, reason)
+			let worker_key = (worker_owner, worker_id);
+			Self::suspend_workers(&worker_key, &worker_type, blocks, reason)?;
+			Self::slash_bond(&worker_key, T::PenaltySlash::get());
+
+			Ok(())
 		}
 
 		/// Manually ban a worker (root only)
+		///
+		/// Its owner and domain can't register workers until `T::BanAppealOrigin` lifts the ban.
+		/// The other workers of its owner and under its domain are made `Inactive` meanwhile.
 		#[pallet::call_index(5)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::ban_worker())]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::ban_worker()
+			.saturating_add(Pallet::<T>::ban_deactivation_weight()))]
 		pub fn ban_worker(
 			origin: OriginFor<T>,
 			worker_owner: T::AccountId,
@@ -460,6 +656,154 @@ pub mod pallet {
 
 			Self::lift_suspension(&(worker_owner, worker_id), &worker_type)
 		}
+
+		/// Sets the public key confidential task payloads are encrypted to for a worker of the
+		/// caller, or clears it. Only workers with a key are handed confidential tasks.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_encryption_key())]
+		pub fn set_encryption_key(
+			origin: OriginFor<T>,
+			worker_type: WorkerType,
+			worker_id: WorkerId,
+			encryption_key: Option<EncryptionKey>,
+		) -> DispatchResult {
+			let creator = ensure_signed(origin)?;
+			let worker_key = (creator, worker_id);
+
+			let mut worker = match worker_type {
+				WorkerType::Docker => WorkerClusters::<T>::get(&worker_key),
+				WorkerType::Executable => ExecutableWorkers::<T>::get(&worker_key),
+			}
+			.ok_or(Error::<T>::WorkerDoesNotExist)?;
+			worker.encryption_key = encryption_key;
+			Self::store_worker(&worker_key, &worker_type, worker);
+
+			Self::deposit_event(Event::WorkerEncryptionKeySet {
+				worker: worker_key,
+				encryption_key,
+			});
+			Ok(())
+		}
+
+		/// Unreserves the bond of a removed or banned worker of the caller once its unbonding
+		/// period has passed.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::withdraw_unbonded())]
+		pub fn withdraw_unbonded(origin: OriginFor<T>, worker_id: WorkerId) -> DispatchResult {
+			let creator = ensure_signed(origin)?;
+			let worker_key = (creator, worker_id);
+
+			let (amount, until_block) =
+				UnbondingBonds::<T>::get(&worker_key).ok_or(Error::<T>::NotUnbonding)?;
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() >= until_block,
+				Error::<T>::UnbondingPeriodNotOver
+			);
+
+			UnbondingBonds::<T>::remove(&worker_key);
+			T::BondCurrency::unreserve(&worker_key.0, amount);
+
+			Self::deposit_event(Event::WorkerBondWithdrawn {
+				worker: worker_key,
+				amount,
+			});
+			Ok(())
+		}
+
+		/// Changes the domain, location or specs of a worker of the caller, keeping its ID and
+		/// reputation. Fields passed as `None` are left unchanged.
+		///
+		/// If `T::ReattestSpecUpgrades` is set and the specs grow, the worker is made inactive
+		/// until the oracle reports it online again.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::update_worker())]
+		pub fn update_worker(
+			origin: OriginFor<T>,
+			worker_type: WorkerType,
+			worker_id: WorkerId,
+			domain: Option<Domain>,
+			location: Option<Location>,
+			specs: Option<WorkerSpecs>,
+		) -> DispatchResult {
+			let creator = ensure_signed(origin)?;
+			let worker_key = (creator, worker_id);
+
+			let mut worker = match worker_type {
+				WorkerType::Docker => WorkerClusters::<T>::get(&worker_key),
+				WorkerType::Executable => ExecutableWorkers::<T>::get(&worker_key),
+			}
+			.ok_or(Error::<T>::WorkerDoesNotExist)?;
+
+			if let Some(domain) = domain {
+				if domain != worker.api.domain {
+					ensure!(
+						!BannedDomains::<T>::contains_key(&domain),
+						Error::<T>::DomainBanned
+					);
+					let domain_key = (worker_type.clone(), domain.clone());
+					ensure!(
+						!WorkerDomains::<T>::contains_key(&domain_key),
+						Error::<T>::WorkerExists
+					);
+					WorkerDomains::<T>::remove((worker_type.clone(), worker.api.domain));
+					WorkerDomains::<T>::insert(domain_key, worker_key.clone());
+					worker.api.domain = domain;
+				}
+			}
+			if let Some(location) = location {
+				worker.location = location;
+			}
+
+			let mut awaiting_attestation = false;
+			if let Some(specs) = specs {
+				let upgraded = specs.ram > worker.specs.ram
+					|| specs.storage > worker.specs.storage
+					|| specs.cpu > worker.specs.cpu;
+				if upgraded && T::ReattestSpecUpgrades::get() {
+					awaiting_attestation = true;
+					AwaitingAttestation::<T>::insert(&worker_key, <frame_system::Pallet<T>>::block_number());
+					// A suspended worker stays suspended, it is attested once the suspension ends
+					if worker.status != WorkerStatusType::Suspended {
+						worker.status = WorkerStatusType::Inactive;
+					}
+				}
+				worker.specs = specs;
+			}
+
+			let domain = worker.api.domain.clone();
+			Self::store_worker(&worker_key, &worker_type, worker);
+
+			Self::deposit_event(Event::WorkerUpdated {
+				worker: worker_key,
+				domain,
+				awaiting_attestation,
+			});
+			Ok(())
+		}
+
+		/// Lifts the ban of an account on appeal, so it can register workers again.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::unban_account())]
+		pub fn unban_account(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
+			T::BanAppealOrigin::ensure_origin(origin)?;
+
+			BannedAccounts::<T>::take(&account).ok_or(Error::<T>::NotBanned)?;
+
+			Self::deposit_event(Event::AccountUnbanned { account });
+			Ok(())
+		}
+
+		/// Lifts the ban of a domain on appeal, so a worker can be registered under it again.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::unban_domain())]
+		pub fn unban_domain(origin: OriginFor<T>, domain: Domain) -> DispatchResult {
+			T::BanAppealOrigin::ensure_origin(origin)?;
+
+			BannedDomains::<T>::take(&domain).ok_or(Error::<T>::NotBanned)?;
+
+			Self::deposit_event(Event::DomainUnbanned { domain });
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -486,8 +830,88 @@ pub mod pallet {
 			AccountWorkers::<T>::contains_key(account)
 		}
 
+		/// Stores `worker`, adding it to or removing it from `ActiveWorkers` when it became or
+		/// stopped being `Active`. Every change to the status of a worker goes through here.
+		pub fn store_worker(
+			worker_key: &(T::AccountId, WorkerId),
+			worker_type: &WorkerType,
+			worker: Worker<T::AccountId, BlockNumberFor<T>, T::Moment>,
+		) {
+			Self::set_active(
+				worker_key,
+				worker_type,
+				worker.status == WorkerStatusType::Active,
+			);
+			match worker_type {
+				WorkerType::Docker => WorkerClusters::<T>::insert(worker_key, worker),
+				WorkerType::Executable => ExecutableWorkers::<T>::insert(worker_key, worker),
+			}
+		}
+
+		/// Removes a worker from storage, and from `ActiveWorkers` if it was `Active`.
+		fn take_worker(
+			worker_key: &(T::AccountId, WorkerId),
+			worker_type: &WorkerType,
+		) -> Option<Worker<T::AccountId, BlockNumberFor<T>, T::Moment>> {
+			Self::set_active(worker_key, worker_type, false);
+			WorkersByAccount::<T>::mutate(&worker_key.0, |workers| {
+				workers.retain(|(_, worker_id)| *worker_id != worker_key.1)
+			});
+			match worker_type {
+				WorkerType::Docker => WorkerClusters::<T>::take(worker_key),
+				WorkerType::Executable => ExecutableWorkers::<T>::take(worker_key),
+			}
+		}
+
+		/// Adds a worker to the end of `ActiveWorkers`, or removes it by moving the last worker
+		/// into its position.
+		pub(crate) fn set_active(
+			worker_key: &(T::AccountId, WorkerId),
+			worker_type: &WorkerType,
+			active: bool,
+		) {
+			let position_key = (worker_type.clone(), worker_key.clone());
+			let position = ActiveWorkerPositions::<T>::get(&position_key);
+			if active == position.is_some() {
+				return;
+			}
+
+			let count = ActiveWorkerCount::<T>::get(worker_type);
+			match position {
+				None => {
+					ActiveWorkers::<T>::insert(worker_type, count, worker_key);
+					ActiveWorkerPositions::<T>::insert(&position_key, count);
+					ActiveWorkerCount::<T>::insert(worker_type, count.saturating_add(1));
+				}
+				Some(position) => {
+					let last = count.saturating_sub(1);
+					if let Some(moved) = ActiveWorkers::<T>::take(worker_type, last) {
+						if position != last {
+							ActiveWorkers::<T>::insert(worker_type, position, &moved);
+							ActiveWorkerPositions::<T>::insert((worker_type.clone(), moved), position);
+						}
+					}
+					ActiveWorkerPositions::<T>::remove(&position_key);
+					ActiveWorkerCount::<T>::insert(worker_type, last);
+				}
+			}
+		}
+
+		/// Number of `Active` workers of `worker_type`.
+		pub fn active_worker_count(worker_type: &WorkerType) -> u32 {
+			ActiveWorkerCount::<T>::get(worker_type)
+		}
+
+		/// The `Active` worker of `worker_type` at `position` in `ActiveWorkers`, if any.
+		pub fn active_worker_at(
+			worker_type: &WorkerType,
+			position: u32,
+		) -> Option<(T::AccountId, WorkerId)> {
+			ActiveWorkers::<T>::get(worker_type, position)
+		}
+
 		/// Apply penalty to a worker's reputation
-		fn apply_penalty(
+		pub fn apply_penalty(
 			worker_key: &(T::AccountId, WorkerId),
 			worker_type: &WorkerType,
 			penalty: i32,
@@ -500,10 +924,15 @@ pub mod pallet {
 			.ok_or(Error::<T>::WorkerDoesNotExist)?;
 
 			// Apply penalty
+			Self::decay_reputation(&mut worker.reputation);
 			worker.reputation.score = worker.reputation.score.saturating_sub(penalty);
 			worker.reputation.violations += 1;
 			worker.reputation.last_updated = Some(<frame_system::Pallet<T>>::block_number());
 
+			// Store the new reputation before the triggers below read the worker back
+			Self::store_worker(worker_key, worker_type, worker.clone());
+			Self::slash_bond(worker_key, T::PenaltySlash::get());
+
 			// Automatic suspension triggers
 			if worker.reputation.score < 30 {
 				// Severe penalty - suspend for 1000 blocks (~4 hours at 6s/block)
@@ -529,14 +958,6 @@ pub mod pallet {
 				)?;
 			}
 
-			// Update storage if not suspended
-			if worker.status != WorkerStatusType::Suspended {
-				match worker_type {
-					WorkerType::Docker => WorkerClusters::<T>::insert(worker_key, worker),
-					WorkerType::Executable => ExecutableWorkers::<T>::insert(worker_key, worker),
-				}
-			}
-
 			Self::deposit_event(Event::WorkerPenalized {
 				worker: worker_key.clone(),
 				penalty,
@@ -546,6 +967,67 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Rewards a worker for a task whose result was accepted: counts the task and raises its
+		/// score by `T::SuccessReward`, up to `T::MaxReputationScore`.
+		pub fn reward_reputation(
+			worker_key: &(T::AccountId, WorkerId),
+			worker_type: &WorkerType,
+		) -> DispatchResult {
+			let mut worker = match worker_type {
+				WorkerType::Docker => WorkerClusters::<T>::get(worker_key),
+				WorkerType::Executable => ExecutableWorkers::<T>::get(worker_key),
+			}
+			.ok_or(Error::<T>::WorkerDoesNotExist)?;
+
+			let reputation = &mut worker.reputation;
+			Self::decay_reputation(reputation);
+			reputation.successful_tasks = reputation.successful_tasks.saturating_add(1);
+			// A score above the ceiling, e.g. after lowering it, is kept rather than cut
+			reputation.score = reputation
+				.score
+				.saturating_add(T::SuccessReward::get())
+				.min(T::MaxReputationScore::get())
+				.max(reputation.score);
+			let score = reputation.score;
+
+			Self::store_worker(worker_key, worker_type, worker);
+
+			Self::deposit_event(Event::WorkerRewarded {
+				worker: worker_key.clone(),
+				score,
+			});
+			Ok(())
+		}
+
+		/// For every `T::ViolationDecayPeriod` blocks since `last_updated`, forgives one violation
+		/// and raises a score below `T::ReputationBaseline` by `T::ReputationRecovery`, up to the
+		/// baseline. `last_updated` is set by the last penalty and moved forward by each decay.
+		fn decay_reputation(reputation: &mut WorkerReputation<BlockNumberFor<T>>) {
+			let period = T::ViolationDecayPeriod::get();
+			let baseline = T::ReputationBaseline::get();
+			let Some(last_updated) = reputation.last_updated else {
+				return;
+			};
+			if period.is_zero() || (reputation.violations == 0 && reputation.score >= baseline) {
+				return;
+			}
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			let periods: u32 = (now.saturating_sub(last_updated) / period).saturated_into();
+			if periods == 0 {
+				return;
+			}
+
+			reputation.violations = reputation.violations.saturating_sub(periods);
+			if reputation.score < baseline {
+				let periods = i32::try_from(periods).unwrap_or(i32::MAX);
+				let recovery = T::ReputationRecovery::get().saturating_mul(periods);
+				reputation.score = reputation.score.saturating_add(recovery).min(baseline);
+			}
+			reputation.last_updated =
+				Some(last_updated.saturating_add(period.saturating_mul(periods.into())));
+		}
+
 		/// Check if worker can perform actions
 		pub fn check_worker_status(
 			worker_key: &(T::AccountId, WorkerId),
@@ -565,15 +1047,14 @@ pub mod pallet {
 					// Auto-unsuspend if suspension period is over
 					let mut worker = worker.clone();
 					worker.status = WorkerStatusType::Inactive;
-					match worker_type {
-						WorkerType::Docker => WorkerClusters::<T>::insert(worker_key, worker),
-						WorkerType::Executable => ExecutableWorkers::<T>::insert(worker_key, worker),
-					}
+					Self::store_worker(worker_key, &worker_type, worker);
 				}
 			}
 
-			// Check reputation
-			if worker.reputation.score < 50 {
+			// Check reputation, including what it recovered since the last penalty
+			let mut reputation = worker.reputation;
+			Self::decay_reputation(&mut reputation);
+			if reputation.score < 50 {
 				return Err(Error::<T>::InsufficientReputation.into());
 			}
 
@@ -602,10 +1083,7 @@ pub mod pallet {
 			worker.reputation.suspension_count += 1;
 
 			// Update storage
-			match worker_type {
-				WorkerType::Docker => WorkerClusters::<T>::insert(worker_key, worker),
-				WorkerType::Executable => ExecutableWorkers::<T>::insert(worker_key, worker),
-			}
+			Self::store_worker(worker_key, worker_type, worker);
 
 			// Record suspension
 			SuspendedWorkers::<T>::insert(worker_key, (suspension_end, reason.clone()));
@@ -634,10 +1112,7 @@ pub mod pallet {
 			worker.reputation.review_count += 1;
 
 			// Update storage
-			match worker_type {
-				WorkerType::Docker => WorkerClusters::<T>::insert(worker_key, worker),
-				WorkerType::Executable => ExecutableWorkers::<T>::insert(worker_key, worker),
-			}
+			Self::store_worker(worker_key, worker_type, worker);
 
 			Self::deposit_event(Event::WorkerUnderReview {
 				worker: worker_key.clone(),
@@ -648,16 +1123,35 @@ pub mod pallet {
 		}
 
 		/// Ban a worker permanently
+		///
+		/// The other workers of its owner, and the worker of the other type registered under its
+		/// domain, are made `Inactive` and can't be made `Active` until the ban is lifted.
 		fn ban_workers(
 			worker_key: &(T::AccountId, WorkerId),
 			worker_type: WorkerType,
 			reason: SuspensionReason,
 		) -> DispatchResult {
 			// Remove from active workers
-			match worker_type {
-				WorkerType::Docker => WorkerClusters::<T>::remove(worker_key),
-				WorkerType::Executable => ExecutableWorkers::<T>::remove(worker_key),
+			let worker = Self::take_worker(worker_key, &worker_type);
+			let ban = (<frame_system::Pallet<T>>::block_number(), reason.clone());
+			if let Some(worker) = worker {
+				WorkerDomains::<T>::remove((worker_type, worker.api.domain.clone()));
+				for worker_type in [WorkerType::Docker, WorkerType::Executable] {
+					if let Some(domain_worker) =
+						WorkerDomains::<T>::get((worker_type.clone(), worker.api.domain.clone()))
+					{
+						Self::deactivate_worker(&domain_worker, &worker_type);
+					}
+				}
+				BannedDomains::<T>::insert(worker.api.domain, ban.clone());
+			}
+			for (worker_type, worker_id) in WorkersByAccount::<T>::get(&worker_key.0) {
+				Self::deactivate_worker(&(worker_key.0.clone(), worker_id), &worker_type);
 			}
+			BannedAccounts::<T>::insert(&worker_key.0, ban);
+			AwaitingAttestation::<T>::remove(worker_key);
+			Self::slash_bond(worker_key, T::BanSlash::get());
+			Self::unbond(worker_key);
 
 			Self::deposit_event(Event::WorkerBanned {
 				worker: worker_key.clone(),
@@ -667,6 +1161,72 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Makes an `Active` or `Busy` worker `Inactive`.
+		fn deactivate_worker(worker_key: &(T::AccountId, WorkerId), worker_type: &WorkerType) {
+			let worker = match worker_type {
+				WorkerType::Docker => WorkerClusters::<T>::get(worker_key),
+				WorkerType::Executable => ExecutableWorkers::<T>::get(worker_key),
+			};
+			let Some(mut worker) = worker else {
+				return;
+			};
+			if matches!(
+				worker.status,
+				WorkerStatusType::Active | WorkerStatusType::Busy
+			) {
+				worker.status = WorkerStatusType::Inactive;
+				Self::store_worker(worker_key, worker_type, worker);
+			}
+		}
+
+		/// Weight of making the other workers of a banned account, and the workers registered
+		/// under its domain, `Inactive`.
+		pub fn ban_deactivation_weight() -> Weight {
+			let workers = u64::from(T::MaxWorkersPerAccount::get()).saturating_add(2);
+			// For every worker its domain entry or the workers of the account, the worker, its
+			// position, the number of active workers and the last of them
+			T::DbWeight::get()
+				.reads_writes(5, 5)
+				.saturating_mul(workers)
+		}
+
+		/// Slashes `fraction` of the remaining bond of a worker.
+		fn slash_bond(worker_key: &(T::AccountId, WorkerId), fraction: Perbill) {
+			let Some(bond) = WorkerBonds::<T>::get(worker_key) else {
+				return;
+			};
+			let amount = fraction * bond;
+			if amount.is_zero() {
+				return;
+			}
+
+			let (imbalance, _) = T::BondCurrency::slash_reserved(&worker_key.0, amount);
+			T::Slash::on_unbalanced(imbalance);
+			WorkerBonds::<T>::insert(worker_key, bond.saturating_sub(amount));
+
+			Self::deposit_event(Event::WorkerBondSlashed {
+				worker: worker_key.clone(),
+				amount,
+			});
+		}
+
+		/// Moves the bond of a removed or banned worker to `UnbondingBonds`, from where its owner
+		/// can withdraw it after `T::UnbondingPeriod` blocks.
+		fn unbond(worker_key: &(T::AccountId, WorkerId)) {
+			let Some(amount) = WorkerBonds::<T>::take(worker_key) else {
+				return;
+			};
+			let until_block =
+				<frame_system::Pallet<T>>::block_number().saturating_add(T::UnbondingPeriod::get());
+			UnbondingBonds::<T>::insert(worker_key, (amount, until_block));
+
+			Self::deposit_event(Event::WorkerBondUnbonding {
+				worker: worker_key.clone(),
+				amount,
+				until_block,
+			});
+		}
+
 		/// Lift suspension from a worker
 		fn lift_suspension(
 			worker_key: &(T::AccountId, WorkerId),
@@ -688,10 +1248,7 @@ pub mod pallet {
 			worker.status_last_updated = <frame_system::Pallet<T>>::block_number();
 
 			// Update storage
-			match worker_type {
-				WorkerType::Docker => WorkerClusters::<T>::insert(worker_key, worker),
-				WorkerType::Executable => ExecutableWorkers::<T>::insert(worker_key, worker),
-			}
+			Self::store_worker(worker_key, worker_type, worker);
 
 			// Remove from suspended workers
 			SuspendedWorkers::<T>::remove(worker_key);
@@ -724,14 +1281,25 @@ pub mod pallet {
 			worker_type: &WorkerType,
 			worker: Worker<T::AccountId, BlockNumberFor<T>, T::Moment>,
 		) {
-			match worker_type {
-				WorkerType::Docker => {
-					WorkerClusters::<T>::insert(worker_key, worker);
-				}
-				WorkerType::Executable => {
-					ExecutableWorkers::<T>::insert(worker_key, worker);
-				}
+			// The workers of a banned account or domain stay inactive whatever they report
+			let mut worker = worker;
+			if matches!(
+				worker.status,
+				WorkerStatusType::Active | WorkerStatusType::Busy
+			) && (BannedAccounts::<T>::contains_key(&worker_key.0)
+				|| BannedDomains::<T>::contains_key(&worker.api.domain))
+			{
+				worker.status = WorkerStatusType::Inactive;
+			}
+			// Only the oracle reports status updates, so an online report attests the worker
+			if worker.status != WorkerStatusType::Inactive
+				&& AwaitingAttestation::<T>::take(worker_key).is_some()
+			{
+				Self::deposit_event(Event::WorkerAttested {
+					worker: worker_key.clone(),
+				});
 			}
+			Self::store_worker(worker_key, worker_type, worker);
 		}
 	}
 }
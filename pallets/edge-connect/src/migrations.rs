@@ -0,0 +1,127 @@
+//! Storage migrations of the edge-connect pallet.
+
+use super::*;
+use core::marker::PhantomData;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{GetStorageVersion, OnRuntimeUpgrade},
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+
+pub mod v0 {
+	use super::*;
+
+	/// Layout of [`Worker`] in version 0, before workers had a number of concurrent tasks and
+	/// an encryption key.
+	#[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug)]
+	pub struct OldWorker<AccountId, BlockNumber, TimeStamp> {
+		pub id: WorkerId,
+		pub owner: AccountId,
+		pub location: Location,
+		pub specs: WorkerSpecs,
+		pub reputation: WorkerReputation<BlockNumber>,
+		pub start_block: BlockNumber,
+		pub status: WorkerStatusType,
+		pub status_last_updated: BlockNumber,
+		pub api: WorkerAPI,
+		pub last_status_check: TimeStamp,
+	}
+
+	impl<AccountId, BlockNumber, TimeStamp> OldWorker<AccountId, BlockNumber, TimeStamp> {
+		/// Workers registered in version 0 take one task at a time, as they did then, and
+		/// have no encryption key until their owner sets one.
+		pub fn upgrade(self) -> Worker<AccountId, BlockNumber, TimeStamp> {
+			Worker {
+				id: self.id,
+				owner: self.owner,
+				location: self.location,
+				specs: self.specs,
+				max_concurrent_tasks: 1,
+				encryption_key: None,
+				reputation: self.reputation,
+				start_block: self.start_block,
+				status: self.status,
+				status_last_updated: self.status_last_updated,
+				api: self.api,
+				last_status_check: self.last_status_check,
+			}
+		}
+	}
+}
+
+pub mod v1 {
+	use super::*;
+
+	/// Migrates the storage of edge-connect from version 0 to 1:
+	///
+	/// - Translates workers to the current layout of [`Worker`], see [`v0::OldWorker::upgrade`].
+	/// - Sets `NextWorkerId` above every worker id in use. Ids used to be allocated per
+	///   account, counting from 0 for every account.
+	/// - Adds every worker to `WorkerDomains`. Workers of different accounts could share a
+	///   domain before, in which case the first worker found keeps it.
+	/// - Adds every worker to `WorkersByAccount`, up to `MaxWorkersPerAccount` workers per
+	///   account.
+	/// - Adds the workers that are `Active` to `ActiveWorkers`.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() >= 1 {
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut reads = 2u64;
+			let mut writes = 1u64;
+
+			let mut upgrade =
+				|_: (T::AccountId, WorkerId),
+				 old: v0::OldWorker<T::AccountId, BlockNumberFor<T>, T::Moment>| {
+					reads += 1;
+					writes += 1;
+					Some(old.upgrade())
+				};
+			WorkerClusters::<T>::translate(&mut upgrade);
+			ExecutableWorkers::<T>::translate(&mut upgrade);
+
+			// `AccountWorkers` holds the last id allocated to each account, including the ids of
+			// removed workers whose bonds may still be unbonding
+			let mut next_id = NextWorkerId::<T>::get();
+			for last_id in AccountWorkers::<T>::iter_values() {
+				reads += 1;
+				next_id = next_id.max(last_id.saturating_add(1));
+			}
+
+			let workers = WorkerClusters::<T>::iter()
+				.map(|(worker_key, worker)| (WorkerType::Docker, worker_key, worker))
+				.chain(
+					ExecutableWorkers::<T>::iter()
+						.map(|(worker_key, worker)| (WorkerType::Executable, worker_key, worker)),
+				);
+			for (worker_type, worker_key, worker) in workers {
+				reads += 2;
+				next_id = next_id.max(worker_key.1.saturating_add(1));
+				let domain_key = (worker_type.clone(), worker.api.domain.clone());
+				if !WorkerDomains::<T>::contains_key(&domain_key) {
+					WorkerDomains::<T>::insert(domain_key, worker_key.clone());
+					writes += 1;
+				}
+				WorkersByAccount::<T>::mutate(&worker_key.0, |workers| {
+					let _ = workers.try_push((worker_type.clone(), worker_key.1));
+				});
+				reads += 1;
+				writes += 1;
+				if worker.status == WorkerStatusType::Active {
+					Pallet::<T>::set_active(&worker_key, &worker_type, true);
+					reads += 2;
+					writes += 3;
+				}
+			}
+
+			NextWorkerId::<T>::put(next_id);
+			writes += 1;
+
+			STORAGE_VERSION.put::<Pallet<T>>();
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+	}
+}
@@ -1,8 +1,12 @@
 pub use crate as pallet_edge_connect;
-use frame_support::{derive_impl, weights::constants::RocksDbWeight};
-use frame_system::{mocking::MockBlock, GenesisConfig};
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::{ConstBool, ConstI32, ConstU128, ConstU32},
+	weights::constants::RocksDbWeight,
+};
+use frame_system::{mocking::MockBlock, EnsureRoot, GenesisConfig};
 use pallet_timestamp;
-use sp_runtime::{traits::ConstU64, BuildStorage};
+use sp_runtime::{traits::ConstU64, BuildStorage, Perbill};
 
 // Configure a mock runtime to test the pallet.
 #[frame_support::runtime]
@@ -27,6 +31,8 @@ mod test_runtime {
 	pub type Timestamp = pallet_timestamp;
 	#[runtime::pallet_index(2)]
 	pub type EdgeConnectModule = pallet_edge_connect;
+	#[runtime::pallet_index(3)]
+	pub type Balances = pallet_balances;
 }
 
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
@@ -35,11 +41,48 @@ impl frame_system::Config for Test {
 	type Block = MockBlock<Test>;
 	type BlockHashCount = ConstU64<250>;
 	type DbWeight = RocksDbWeight;
+	type AccountData = pallet_balances::AccountData<u128>;
+}
+
+parameter_types! {
+	pub const PenaltySlash: Perbill = Perbill::from_percent(10);
+	pub const BanSlash: Perbill = Perbill::from_percent(50);
 }
 
 impl pallet_edge_connect::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
+	type BondCurrency = Balances;
+	type WorkerBond = ConstU128<100>;
+	type UnbondingPeriod = ConstU64<10>;
+	type PenaltySlash = PenaltySlash;
+	type BanSlash = BanSlash;
+	type Slash = ();
+	type ReattestSpecUpgrades = ConstBool<true>;
+	type BanAppealOrigin = EnsureRoot<u64>;
+	type SuccessReward = ConstI32<5>;
+	type MaxReputationScore = ConstI32<100>;
+	type ViolationDecayPeriod = ConstU64<100>;
+	type ReputationBaseline = ConstI32<60>;
+	type ReputationRecovery = ConstI32<5>;
+	type MaxWorkersPerAccount = ConstU32<10>;
+	type WorkerStatusHandler = ();
+}
+
+impl pallet_balances::Config for Test {
+	type Balance = u128;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ConstU128<10>;
+	type AccountStore = System;
+	type MaxLocks = ();
+	type MaxReserves = ConstU32<50>;
+	type ReserveIdentifier = ();
+	type WeightInfo = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type FreezeIdentifier = ();
+	type MaxFreezes = ConstU32<0>;
 }
 
 impl pallet_timestamp::Config for Test {
@@ -51,8 +94,11 @@ impl pallet_timestamp::Config for Test {
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	GenesisConfig::<Test>::default()
-		.build_storage()
-		.unwrap()
-		.into()
+	let mut storage = GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(0, 10_000), (1, 10_000), (2, 10_000)],
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+	storage.into()
 }
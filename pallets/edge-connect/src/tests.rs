@@ -38,6 +38,8 @@ fn it_works_for_inserting_worker_into_correct_storage() {
 			api: api_info.clone(),
 			location: worker_location.clone(),
 			specs: worker_specs.clone(),
+			max_concurrent_tasks: 1,
+			encryption_key: None,
 			reputation: WorkerReputation::<BlockNumberFor<Test>>::default(),
 			last_status_check: current_timestamp,
 		};
@@ -51,6 +53,8 @@ fn it_works_for_inserting_worker_into_correct_storage() {
 			api: api_info.clone(),
 			location: worker_location.clone(),
 			specs: worker_specs.clone(),
+			max_concurrent_tasks: 1,
+			encryption_key: None,
 			reputation: WorkerReputation::<BlockNumberFor<Test>>::default(),
 			last_status_check: current_timestamp,
 		};
@@ -64,7 +68,8 @@ fn it_works_for_inserting_worker_into_correct_storage() {
 			longitude,
 			ram,
 			storage,
-			cpu
+			cpu,
+			1
 		));
 
 		// Dispatch a signed extrinsic.
@@ -76,7 +81,8 @@ fn it_works_for_inserting_worker_into_correct_storage() {
 			longitude,
 			ram,
 			storage,
-			cpu
+			cpu,
+			1
 		));
 
 		// Read pallet storage and assert an expected result.
@@ -124,6 +130,8 @@ fn it_works_for_registering_domain() {
 			api: api_info.clone(),
 			location: worker_location.clone(),
 			specs: worker_specs.clone(),
+			max_concurrent_tasks: 1,
+			encryption_key: None,
 			reputation: WorkerReputation::<BlockNumberFor<Test>>::default(),
 			last_status_check: current_timestamp,
 		};
@@ -137,7 +145,8 @@ fn it_works_for_registering_domain() {
 			longitude,
 			ram,
 			storage,
-			cpu
+			cpu,
+			1
 		));
 		// Read pallet storage and assert an expected result.
 		assert_eq!(
@@ -174,7 +183,8 @@ fn it_fails_for_registering_duplicate_worker() {
 			longitude,
 			ram,
 			storage,
-			cpu
+			cpu,
+			1
 		));
 		// Try to register the same worker again
 		assert_noop!(
@@ -186,7 +196,8 @@ fn it_fails_for_registering_duplicate_worker() {
 				longitude,
 				ram,
 				storage,
-				cpu
+				cpu,
+				1
 			),
 			Error::<Test>::WorkerExists
 		);
@@ -200,7 +211,8 @@ fn it_fails_for_registering_duplicate_worker() {
 			longitude,
 			ram,
 			storage,
-			cpu
+			cpu,
+			1
 		));
 		// Try to register the same worker again
 		assert_noop!(
@@ -212,10 +224,96 @@ fn it_fails_for_registering_duplicate_worker() {
 				longitude,
 				ram,
 				storage,
-				cpu
+				cpu,
+				1
+			),
+			Error::<Test>::WorkerExists
+		);
+	});
+}
+
+#[test]
+fn it_fails_for_registering_domain_of_another_account() {
+	new_test_ext().execute_with(|| {
+		let alice = 0;
+		let bob = 1;
+		let domain: BoundedVec<u8, ConstU32<128>> =
+			BoundedVec::try_from(b"127.0.0.1:3001".to_vec()).unwrap();
+
+		System::set_block_number(10);
+		assert_ok!(EdgeConnectModule::register_worker(
+			RuntimeOrigin::signed(alice),
+			WorkerType::Docker,
+			domain.clone(),
+			590000,
+			120000,
+			100000000,
+			100000000,
+			12,
+			1
+		));
+
+		assert_noop!(
+			EdgeConnectModule::register_worker(
+				RuntimeOrigin::signed(bob),
+				WorkerType::Docker,
+				domain.clone(),
+				590000,
+				120000,
+				100000000,
+				100000000,
+				12,
+				1
 			),
 			Error::<Test>::WorkerExists
 		);
+
+		// The domain is only taken for the type of worker registered under it, and worker ids
+		// are allocated across all accounts
+		assert_ok!(EdgeConnectModule::register_worker(
+			RuntimeOrigin::signed(bob),
+			WorkerType::Executable,
+			domain.clone(),
+			590000,
+			120000,
+			100000000,
+			100000000,
+			12,
+			1
+		));
+		assert!(crate::ExecutableWorkers::<Test>::contains_key((bob, 1)));
+		assert_eq!(crate::AccountWorkers::<Test>::get(bob), Some(1));
+		assert_eq!(
+			crate::WorkerDomains::<Test>::get((WorkerType::Docker, domain.clone())),
+			Some((alice, 0))
+		);
+		assert_eq!(
+			crate::WorkerDomains::<Test>::get((WorkerType::Executable, domain)),
+			Some((bob, 1))
+		);
+	});
+}
+
+#[test]
+fn it_fails_for_registering_worker_without_capacity() {
+	new_test_ext().execute_with(|| {
+		let domain: BoundedVec<u8, ConstU32<128>> =
+			BoundedVec::try_from(b"127.0.0.1:3001".to_vec()).unwrap();
+
+		assert_noop!(
+			EdgeConnectModule::register_worker(
+				RuntimeOrigin::signed(0),
+				WorkerType::Executable,
+				domain,
+				590000,
+				120000,
+				100000000,
+				100000000,
+				12,
+				0
+			),
+			Error::<Test>::InvalidMaxConcurrentTasks
+		);
 	});
 }
 
@@ -246,7 +344,8 @@ fn it_works_for_removing_worker() {
 			longitude,
 			ram,
 			storage,
-			cpu
+			cpu,
+			1
 		));
 		// Register a worker first
 		assert_ok!(EdgeConnectModule::register_worker(
@@ -257,7 +356,8 @@ fn it_works_for_removing_worker() {
 			longitude,
 			ram,
 			storage,
-			cpu
+			cpu,
+			1
 		));
 
 		// Remove the worker
@@ -286,6 +386,59 @@ fn it_works_for_removing_worker() {
 	});
 }
 
+#[test]
+fn removing_worker_releases_its_domain() {
+	new_test_ext().execute_with(|| {
+		let alice = 0;
+		let bob = 1;
+		let domain: BoundedVec<u8, ConstU32<128>> =
+			BoundedVec::try_from(b"127.0.0.1:3001".to_vec()).unwrap();
+
+		System::set_block_number(10);
+		assert_ok!(EdgeConnectModule::register_worker(
+			RuntimeOrigin::signed(alice),
+			WorkerType::Executable,
+			domain.clone(),
+			590000,
+			120000,
+			100000000,
+			100000000,
+			12,
+			1
+		));
+		assert_ok!(EdgeConnectModule::remove_worker(
+			RuntimeOrigin::signed(alice),
+			WorkerType::Executable,
+			0
+		));
+		assert_eq!(
+			crate::WorkerDomains::<Test>::get((WorkerType::Executable, domain.clone())),
+			None
+		);
+
+		// Removed ids are not handed out again
+		assert_ok!(EdgeConnectModule::register_worker(
+			RuntimeOrigin::signed(bob),
+			WorkerType::Executable,
+			domain,
+			590000,
+			120000,
+			100000000,
+			100000000,
+			12,
+			1
+		));
+		System::assert_last_event(
+			Event::WorkerRegistered {
+				creator: bob,
+				worker: (bob, 1),
+				domain: BoundedVec::try_from(b"127.0.0.1:3001".to_vec()).unwrap(),
+			}
+			.into(),
+		);
+	});
+}
+
 #[test]
 fn it_fails_for_removing_non_existent_worker() {
 	new_test_ext().execute_with(|| {
@@ -329,7 +482,8 @@ fn emiting_proper_event_for_registering_worker() {
 			longitude,
 			ram,
 			storage,
-			cpu
+			cpu,
+			1
 		));
 		System::assert_last_event(RuntimeEvent::EdgeConnectModule(Event::WorkerRegistered {
 			creator: alice,
@@ -365,7 +519,8 @@ fn it_works_for_changing_visibility() {
 			longitude,
 			ram,
 			storage,
-			cpu
+			cpu,
+			1
 		));
 
 		assert_ok!(EdgeConnectModule::register_worker(
@@ -376,7 +531,8 @@ fn it_works_for_changing_visibility() {
 			longitude,
 			ram,
 			storage,
-			cpu
+			cpu,
+			1
 		));
 
 		let _ = EdgeConnectModule::toggle_worker_visibility(
@@ -466,6 +622,1070 @@ fn it_fails_for_changing_visibility_on_nonexistant_worker() {
 	})
 }
 
+#[test]
+fn worker_owner_can_set_and_clear_encryption_key() {
+	new_test_ext().execute_with(|| {
+		use sp_core::{sr25519, Pair};
+
+		let alice = 0;
+		let bob = 1;
+		let domain: BoundedVec<u8, ConstU32<128>> =
+			BoundedVec::try_from(b"confidential.com".to_vec()).unwrap();
+		let encryption_key = sr25519::Pair::from_string("//AliceWorker", None)
+			.unwrap()
+			.public()
+			.0;
+
+		System::set_block_number(10);
+		assert_ok!(EdgeConnectModule::register_worker(
+			RuntimeOrigin::signed(alice),
+			WorkerType::Docker,
+			domain,
+			590000,
+			120000,
+			100000000,
+			100000000,
+			12,
+			1
+		));
+
+		assert_noop!(
+			EdgeConnectModule::set_encryption_key(
+				RuntimeOrigin::signed(bob),
+				WorkerType::Docker,
+				0,
+				Some(encryption_key)
+			),
+			Error::<Test>::WorkerDoesNotExist
+		);
+
+		assert_ok!(EdgeConnectModule::set_encryption_key(
+			RuntimeOrigin::signed(alice),
+			WorkerType::Docker,
+			0,
+			Some(encryption_key)
+		));
+		assert_eq!(
+			crate::WorkerClusters::<Test>::get((alice, 0))
+				.unwrap()
+				.encryption_key,
+			Some(encryption_key)
+		);
+		System::assert_last_event(
+			Event::WorkerEncryptionKeySet {
+				worker: (alice, 0),
+				encryption_key: Some(encryption_key),
+			}
+			.into(),
+		);
+
+		assert_ok!(EdgeConnectModule::set_encryption_key(
+			RuntimeOrigin::signed(alice),
+			WorkerType::Docker,
+			0,
+			None
+		));
+		assert_eq!(
+			crate::WorkerClusters::<Test>::get((alice, 0))
+				.unwrap()
+				.encryption_key,
+			None
+		);
+	})
+}
+
+#[test]
+fn penalty_below_review_threshold_keeps_worker_inactive() {
+	new_test_ext().execute_with(|| {
+		let alice = 0;
+		let domain: BoundedVec<u8, ConstU32<128>> =
+			BoundedVec::try_from(b"penalized.com".to_vec()).unwrap();
+
+		System::set_block_number(10);
+		assert_ok!(EdgeConnectModule::register_worker(
+			RuntimeOrigin::signed(alice),
+			WorkerType::Executable,
+			domain,
+			590000,
+			120000,
+			100000000,
+			100000000,
+			12,
+			1
+		));
+		assert_ok!(EdgeConnectModule::toggle_worker_visibility(
+			RuntimeOrigin::signed(alice),
+			WorkerType::Executable,
+			0,
+			true
+		));
+
+		assert_ok!(EdgeConnectModule::penalize_worker(
+			RuntimeOrigin::root(),
+			alice,
+			0,
+			WorkerType::Executable,
+			60,
+			crate::PenaltyReason::FalseCompletion
+		));
+
+		// The review triggered by the penalty must not be overwritten by the penalty itself
+		let worker = crate::ExecutableWorkers::<Test>::get((alice, 0)).unwrap();
+		assert_eq!(worker.reputation.score, 40);
+		assert_eq!(worker.reputation.violations, 1);
+		assert_eq!(worker.reputation.review_count, 1);
+		assert_eq!(worker.status, WorkerStatusType::Inactive);
+	})
+}
+
+fn register_bonded_worker(owner: u64, domain: &[u8]) {
+	assert_ok!(EdgeConnectModule::register_worker(
+		RuntimeOrigin::signed(owner),
+		WorkerType::Executable,
+		BoundedVec::try_from(domain.to_vec()).unwrap(),
+		590000,
+		120000,
+		100000000,
+		100000000,
+		12,
+		1
+	));
+}
+
+#[test]
+fn registering_worker_reserves_bond() {
+	new_test_ext().execute_with(|| {
+		let alice = 0;
+
+		System::set_block_number(10);
+		register_bonded_worker(alice, b"bonded.com");
+
+		assert_eq!(Balances::reserved_balance(alice), 100);
+		assert_eq!(Balances::free_balance(alice), 9_900);
+		assert_eq!(crate::WorkerBonds::<Test>::get((alice, 0)), Some(100));
+	});
+}
+
+#[test]
+fn it_fails_for_registering_worker_without_bond() {
+	new_test_ext().execute_with(|| {
+		let poor = 7;
+
+		assert_noop!(
+			EdgeConnectModule::register_worker(
+				RuntimeOrigin::signed(poor),
+				WorkerType::Executable,
+				BoundedVec::try_from(b"unbonded.com".to_vec()).unwrap(),
+				590000,
+				120000,
+				100000000,
+				100000000,
+				12,
+				1
+			),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn removed_worker_bond_is_withdrawable_after_unbonding_period() {
+	new_test_ext().execute_with(|| {
+		let alice = 0;
+
+		System::set_block_number(10);
+		register_bonded_worker(alice, b"bonded.com");
+		assert_ok!(EdgeConnectModule::remove_worker(
+			RuntimeOrigin::signed(alice),
+			WorkerType::Executable,
+			0
+		));
+
+		assert_eq!(crate::WorkerBonds::<Test>::get((alice, 0)), None);
+		assert_eq!(
+			crate::UnbondingBonds::<Test>::get((alice, 0)),
+			Some((100, 20))
+		);
+		assert_eq!(Balances::reserved_balance(alice), 100);
+
+		System::set_block_number(19);
+		assert_noop!(
+			EdgeConnectModule::withdraw_unbonded(RuntimeOrigin::signed(alice), 0),
+			Error::<Test>::UnbondingPeriodNotOver
+		);
+
+		System::set_block_number(20);
+		assert_ok!(EdgeConnectModule::withdraw_unbonded(
+			RuntimeOrigin::signed(alice),
+			0
+		));
+		System::assert_last_event(
+			Event::WorkerBondWithdrawn {
+				worker: (alice, 0),
+				amount: 100,
+			}
+			.into(),
+		);
+		assert_eq!(Balances::reserved_balance(alice), 0);
+		assert_eq!(Balances::free_balance(alice), 10_000);
+
+		assert_noop!(
+			EdgeConnectModule::withdraw_unbonded(RuntimeOrigin::signed(alice), 0),
+			Error::<Test>::NotUnbonding
+		);
+	});
+}
+
+#[test]
+fn penalty_slashes_worker_bond() {
+	new_test_ext().execute_with(|| {
+		let alice = 0;
+
+		System::set_block_number(10);
+		register_bonded_worker(alice, b"bonded.com");
+		assert_ok!(EdgeConnectModule::penalize_worker(
+			RuntimeOrigin::root(),
+			alice,
+			0,
+			WorkerType::Executable,
+			10,
+			crate::PenaltyReason::LateResponse
+		));
+
+		System::assert_has_event(
+			Event::WorkerBondSlashed {
+				worker: (alice, 0),
+				amount: 10,
+			}
+			.into(),
+		);
+		assert_eq!(crate::WorkerBonds::<Test>::get((alice, 0)), Some(90));
+		assert_eq!(Balances::reserved_balance(alice), 90);
+		assert_eq!(Balances::free_balance(alice), 9_900);
+	});
+}
+
+#[test]
+fn penalty_that_suspends_worker_slashes_bond_once() {
+	new_test_ext().execute_with(|| {
+		let alice = 0;
+
+		System::set_block_number(10);
+		register_bonded_worker(alice, b"bonded.com");
+		assert_ok!(EdgeConnectModule::penalize_worker(
+			RuntimeOrigin::root(),
+			alice,
+			0,
+			WorkerType::Executable,
+			80,
+			crate::PenaltyReason::LateResponse
+		));
+
+		assert_eq!(
+			crate::ExecutableWorkers::<Test>::get((alice, 0))
+				.unwrap()
+				.status,
+			WorkerStatusType::Suspended
+		);
+		let slashes = System::events()
+			.into_iter()
+			.filter(|record| {
+				matches!(
+					record.event,
+					RuntimeEvent::EdgeConnectModule(Event::WorkerBondSlashed { .. })
+				)
+			})
+			.count();
+		assert_eq!(slashes, 1);
+		assert_eq!(crate::WorkerBonds::<Test>::get((alice, 0)), Some(90));
+		assert_eq!(Balances::reserved_balance(alice), 90);
+	});
+}
+
+#[test]
+fn suspension_by_root_slashes_worker_bond() {
+	new_test_ext().execute_with(|| {
+		let alice = 0;
+
+		System::set_block_number(10);
+		register_bonded_worker(alice, b"bonded.com");
+		assert_ok!(EdgeConnectModule::suspend_worker(
+			RuntimeOrigin::root(),
+			alice,
+			0,
+			WorkerType::Executable,
+			100,
+			SuspensionReason::MaliciousActivity
+		));
+
+		assert_eq!(crate::WorkerBonds::<Test>::get((alice, 0)), Some(90));
+		assert_eq!(Balances::reserved_balance(alice), 90);
+	});
+}
+
+#[test]
+fn ban_slashes_worker_bond_and_unbonds_the_rest() {
+	new_test_ext().execute_with(|| {
+		let alice = 0;
+
+		System::set_block_number(10);
+		register_bonded_worker(alice, b"bonded.com");
+		assert_ok!(EdgeConnectModule::ban_worker(
+			RuntimeOrigin::root(),
+			alice,
+			0,
+			WorkerType::Executable,
+			SuspensionReason::MaliciousActivity
+		));
+
+		assert_eq!(crate::WorkerBonds::<Test>::get((alice, 0)), None);
+		assert_eq!(
+			crate::UnbondingBonds::<Test>::get((alice, 0)),
+			Some((50, 20))
+		);
+		assert_eq!(Balances::reserved_balance(alice), 50);
+
+		System::set_block_number(20);
+		assert_ok!(EdgeConnectModule::withdraw_unbonded(
+			RuntimeOrigin::signed(alice),
+			0
+		));
+		assert_eq!(Balances::free_balance(alice), 9_950);
+	});
+}
+
+#[test]
+fn updating_worker_keeps_identity_and_reputation() {
+	new_test_ext().execute_with(|| {
+		let alice = 0;
+		let new_domain: Domain = BoundedVec::try_from(b"moved.com".to_vec()).unwrap();
+		let new_location = Location {
+			latitude: 1,
+			longitude: 2,
+		};
+
+		System::set_block_number(10);
+		register_bonded_worker(alice, b"bonded.com");
+		assert_ok!(EdgeConnectModule::penalize_worker(
+			RuntimeOrigin::root(),
+			alice,
+			0,
+			WorkerType::Executable,
+			10,
+			crate::PenaltyReason::LateResponse
+		));
+		let before = crate::ExecutableWorkers::<Test>::get((alice, 0)).unwrap();
+
+		assert_ok!(EdgeConnectModule::update_worker(
+			RuntimeOrigin::signed(alice),
+			WorkerType::Executable,
+			0,
+			Some(new_domain.clone()),
+			Some(new_location.clone()),
+			None
+		));
+		System::assert_last_event(
+			Event::WorkerUpdated {
+				worker: (alice, 0),
+				domain: new_domain.clone(),
+				awaiting_attestation: false,
+			}
+			.into(),
+		);
+
+		let worker = crate::ExecutableWorkers::<Test>::get((alice, 0)).unwrap();
+		assert_eq!(worker.id, before.id);
+		assert_eq!(worker.reputation, before.reputation);
+		assert_eq!(worker.specs, before.specs);
+		assert_eq!(worker.location, new_location);
+		assert_eq!(worker.api.domain, new_domain.clone());
+
+		let old_domain: Domain = BoundedVec::try_from(b"bonded.com".to_vec()).unwrap();
+		assert_eq!(
+			crate::WorkerDomains::<Test>::get((WorkerType::Executable, old_domain)),
+			None
+		);
+		assert_eq!(
+			crate::WorkerDomains::<Test>::get((WorkerType::Executable, new_domain)),
+			Some((alice, 0))
+		);
+	});
+}
+
+#[test]
+fn it_fails_for_updating_worker_to_a_taken_domain() {
+	new_test_ext().execute_with(|| {
+		let alice = 0;
+		let bob = 1;
+
+		register_bonded_worker(alice, b"alice.com");
+		register_bonded_worker(bob, b"bob.com");
+
+		assert_noop!(
+			EdgeConnectModule::update_worker(
+				RuntimeOrigin::signed(bob),
+				WorkerType::Executable,
+				1,
+				Some(BoundedVec::try_from(b"alice.com".to_vec()).unwrap()),
+				None,
+				None
+			),
+			Error::<Test>::WorkerExists
+		);
+		assert_noop!(
+			EdgeConnectModule::update_worker(
+				RuntimeOrigin::signed(bob),
+				WorkerType::Executable,
+				0,
+				None,
+				None,
+				None
+			),
+			Error::<Test>::WorkerDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn spec_upgrade_requires_attestation_before_worker_is_schedulable() {
+	new_test_ext().execute_with(|| {
+		let alice = 0;
+		let upgraded = WorkerSpecs {
+			ram: 200000000,
+			storage: 100000000,
+			cpu: 12,
+		};
+
+		System::set_block_number(10);
+		register_bonded_worker(alice, b"bonded.com");
+		assert_ok!(EdgeConnectModule::toggle_worker_visibility(
+			RuntimeOrigin::signed(alice),
+			WorkerType::Executable,
+			0,
+			true
+		));
+
+		assert_ok!(EdgeConnectModule::update_worker(
+			RuntimeOrigin::signed(alice),
+			WorkerType::Executable,
+			0,
+			None,
+			None,
+			Some(upgraded.clone())
+		));
+		let worker = crate::ExecutableWorkers::<Test>::get((alice, 0)).unwrap();
+		assert_eq!(worker.specs, upgraded);
+		assert_eq!(worker.status, WorkerStatusType::Inactive);
+		assert_eq!(
+			crate::AwaitingAttestation::<Test>::get((alice, 0)),
+			Some(10)
+		);
+
+		assert_noop!(
+			EdgeConnectModule::toggle_worker_visibility(
+				RuntimeOrigin::signed(alice),
+				WorkerType::Executable,
+				0,
+				true
+			),
+			Error::<Test>::AwaitingAttestation
+		);
+
+		// The oracle reporting the worker online attests it
+		let mut reported = worker.clone();
+		reported.status = WorkerStatusType::Active;
+		<EdgeConnectModule as WorkerInfoHandler<_, _, _, _>>::update_worker_cluster(
+			&(alice, 0),
+			&WorkerType::Executable,
+			reported,
+		);
+		System::assert_last_event(Event::WorkerAttested { worker: (alice, 0) }.into());
+		assert_eq!(crate::AwaitingAttestation::<Test>::get((alice, 0)), None);
+
+		// Downgrades take effect without attestation
+		assert_ok!(EdgeConnectModule::update_worker(
+			RuntimeOrigin::signed(alice),
+			WorkerType::Executable,
+			0,
+			None,
+			None,
+			Some(WorkerSpecs {
+				ram: 100000000,
+				storage: 100000000,
+				cpu: 12,
+			})
+		));
+		assert_eq!(crate::AwaitingAttestation::<Test>::get((alice, 0)), None);
+		assert_eq!(
+			crate::ExecutableWorkers::<Test>::get((alice, 0))
+				.unwrap()
+				.status,
+			WorkerStatusType::Active
+		);
+	});
+}
+
+#[test]
+fn banned_owner_and_domain_cannot_register_again() {
+	new_test_ext().execute_with(|| {
+		let alice = 0;
+		let bob = 1;
+
+		System::set_block_number(10);
+		register_bonded_worker(alice, b"banned.com");
+		assert_ok!(EdgeConnectModule::ban_worker(
+			RuntimeOrigin::root(),
+			alice,
+			0,
+			WorkerType::Executable,
+			SuspensionReason::MaliciousActivity
+		));
+
+		assert_eq!(
+			crate::BannedAccounts::<Test>::get(alice),
+			Some((10, SuspensionReason::MaliciousActivity))
+		);
+		let domain: Domain = BoundedVec::try_from(b"banned.com".to_vec()).unwrap();
+		assert_eq!(
+			crate::BannedDomains::<Test>::get(&domain),
+			Some((10, SuspensionReason::MaliciousActivity))
+		);
+
+		assert_noop!(
+			EdgeConnectModule::register_worker(
+				RuntimeOrigin::signed(alice),
+				WorkerType::Executable,
+				BoundedVec::try_from(b"fresh.com".to_vec()).unwrap(),
+				590000,
+				120000,
+				100000000,
+				100000000,
+				12,
+				1
+			),
+			Error::<Test>::AccountBanned
+		);
+		assert_noop!(
+			EdgeConnectModule::register_worker(
+				RuntimeOrigin::signed(bob),
+				WorkerType::Docker,
+				domain.clone(),
+				590000,
+				120000,
+				100000000,
+				100000000,
+				12,
+				1
+			),
+			Error::<Test>::DomainBanned
+		);
+
+		register_bonded_worker(bob, b"bob.com");
+		assert_noop!(
+			EdgeConnectModule::update_worker(
+				RuntimeOrigin::signed(bob),
+				WorkerType::Executable,
+				1,
+				Some(domain),
+				None,
+				None
+			),
+			Error::<Test>::DomainBanned
+		);
+	});
+}
+
+#[test]
+fn ban_can_be_lifted_on_appeal() {
+	new_test_ext().execute_with(|| {
+		let alice = 0;
+		let domain: Domain = BoundedVec::try_from(b"banned.com".to_vec()).unwrap();
+
+		System::set_block_number(10);
+		register_bonded_worker(alice, b"banned.com");
+		assert_ok!(EdgeConnectModule::ban_worker(
+			RuntimeOrigin::root(),
+			alice,
+			0,
+			WorkerType::Executable,
+			SuspensionReason::SpamBehavior
+		));
+
+		assert_noop!(
+			EdgeConnectModule::unban_account(RuntimeOrigin::signed(alice), alice),
+			frame_support::sp_runtime::DispatchError::BadOrigin
+		);
+
+		assert_ok!(EdgeConnectModule::unban_account(
+			RuntimeOrigin::root(),
+			alice
+		));
+		System::assert_last_event(Event::AccountUnbanned { account: alice }.into());
+		assert_ok!(EdgeConnectModule::unban_domain(
+			RuntimeOrigin::root(),
+			domain.clone()
+		));
+		System::assert_last_event(
+			Event::DomainUnbanned {
+				domain: domain.clone(),
+			}
+			.into(),
+		);
+		assert_noop!(
+			EdgeConnectModule::unban_account(RuntimeOrigin::root(), alice),
+			Error::<Test>::NotBanned
+		);
+
+		register_bonded_worker(alice, b"banned.com");
+		assert_eq!(
+			crate::WorkerDomains::<Test>::get((WorkerType::Executable, domain)),
+			Some((alice, 1))
+		);
+	});
+}
+
+#[test]
+fn banning_a_worker_deactivates_the_workers_of_its_owner_and_domain() {
+	new_test_ext().execute_with(|| {
+		let alice = 0;
+		let bob = 1;
+
+		System::set_block_number(10);
+		for domain in ["banned.com", "second.com", "third.com"] {
+			register_bonded_worker(alice, domain.as_bytes());
+		}
+		assert_ok!(EdgeConnectModule::register_worker(
+			RuntimeOrigin::signed(bob),
+			WorkerType::Docker,
+			BoundedVec::try_from(b"banned.com".to_vec()).unwrap(),
+			590000,
+			120000,
+			100000000,
+			100000000,
+			12,
+			1
+		));
+		for worker_id in 0..3 {
+			assert_ok!(EdgeConnectModule::toggle_worker_visibility(
+				RuntimeOrigin::signed(alice),
+				WorkerType::Executable,
+				worker_id,
+				true
+			));
+		}
+		assert_ok!(EdgeConnectModule::toggle_worker_visibility(
+			RuntimeOrigin::signed(bob),
+			WorkerType::Docker,
+			3,
+			true
+		));
+		let mut busy = crate::ExecutableWorkers::<Test>::get((alice, 2)).unwrap();
+		busy.status = WorkerStatusType::Busy;
+		<EdgeConnectModule as WorkerInfoHandler<_, _, _, _>>::update_worker_cluster(
+			&(alice, 2),
+			&WorkerType::Executable,
+			busy,
+		);
+
+		assert_ok!(EdgeConnectModule::ban_worker(
+			RuntimeOrigin::root(),
+			alice,
+			0,
+			WorkerType::Executable,
+			SuspensionReason::MaliciousActivity
+		));
+
+		assert_eq!(
+			EdgeConnectModule::active_worker_count(&WorkerType::Executable),
+			0
+		);
+		assert_eq!(
+			EdgeConnectModule::active_worker_count(&WorkerType::Docker),
+			0
+		);
+		for worker_id in 1..3 {
+			assert_eq!(
+				crate::ExecutableWorkers::<Test>::get((alice, worker_id))
+					.unwrap()
+					.status,
+				WorkerStatusType::Inactive
+			);
+		}
+		assert_eq!(
+			crate::WorkerClusters::<Test>::get((bob, 3)).unwrap().status,
+			WorkerStatusType::Inactive
+		);
+		assert_eq!(
+			crate::WorkersByAccount::<Test>::get(alice).into_inner(),
+			vec![(WorkerType::Executable, 1), (WorkerType::Executable, 2)]
+		);
+
+		// They stay inactive until the ban is lifted
+		assert_noop!(
+			EdgeConnectModule::toggle_worker_visibility(
+				RuntimeOrigin::signed(alice),
+				WorkerType::Executable,
+				1,
+				true
+			),
+			Error::<Test>::AccountBanned
+		);
+		assert_noop!(
+			EdgeConnectModule::toggle_worker_visibility(
+				RuntimeOrigin::signed(bob),
+				WorkerType::Docker,
+				3,
+				true
+			),
+			Error::<Test>::DomainBanned
+		);
+		let mut reported = crate::ExecutableWorkers::<Test>::get((alice, 1)).unwrap();
+		reported.status = WorkerStatusType::Active;
+		<EdgeConnectModule as WorkerInfoHandler<_, _, _, _>>::update_worker_cluster(
+			&(alice, 1),
+			&WorkerType::Executable,
+			reported,
+		);
+		assert_eq!(
+			crate::ExecutableWorkers::<Test>::get((alice, 1))
+				.unwrap()
+				.status,
+			WorkerStatusType::Inactive
+		);
+		assert_eq!(
+			EdgeConnectModule::active_worker_count(&WorkerType::Executable),
+			0
+		);
+	});
+}
+
+#[test]
+fn accounts_register_a_bounded_number_of_workers() {
+	new_test_ext().execute_with(|| {
+		let alice = 0;
+
+		System::set_block_number(10);
+		for worker in 0..10 {
+			register_bonded_worker(alice, format!("worker{}.com", worker).as_bytes());
+		}
+		assert_noop!(
+			EdgeConnectModule::register_worker(
+				RuntimeOrigin::signed(alice),
+				WorkerType::Executable,
+				BoundedVec::try_from(b"one-too-many.com".to_vec()).unwrap(),
+				590000,
+				120000,
+				100000000,
+				100000000,
+				12,
+				1
+			),
+			Error::<Test>::TooManyWorkers
+		);
+
+		// Removing a worker makes room for another one
+		assert_ok!(EdgeConnectModule::remove_worker(
+			RuntimeOrigin::signed(alice),
+			WorkerType::Executable,
+			0
+		));
+		register_bonded_worker(alice, b"one-too-many.com");
+	});
+}
+
+#[test]
+fn reward_raises_reputation_up_to_the_ceiling() {
+	new_test_ext().execute_with(|| {
+		let alice = 0;
+		let worker = (alice, 0);
+
+		System::set_block_number(10);
+		register_bonded_worker(alice, b"rewarded.com");
+		assert_ok!(EdgeConnectModule::apply_penalty(
+			&worker,
+			&WorkerType::Executable,
+			8,
+			crate::PenaltyReason::LateResponse
+		));
+
+		assert_ok!(EdgeConnectModule::reward_reputation(
+			&worker,
+			&WorkerType::Executable
+		));
+		System::assert_last_event(Event::WorkerRewarded { worker, score: 97 }.into());
+		assert_ok!(EdgeConnectModule::reward_reputation(
+			&worker,
+			&WorkerType::Executable
+		));
+
+		let reputation = crate::ExecutableWorkers::<Test>::get(worker)
+			.unwrap()
+			.reputation;
+		assert_eq!(reputation.score, 100);
+		assert_eq!(reputation.successful_tasks, 2);
+
+		assert_noop!(
+			EdgeConnectModule::reward_reputation(&(alice, 1), &WorkerType::Executable),
+			Error::<Test>::WorkerDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn violations_decay_over_time() {
+	new_test_ext().execute_with(|| {
+		let alice = 0;
+		let worker = (alice, 0);
+
+		System::set_block_number(10);
+		register_bonded_worker(alice, b"decaying.com");
+		for _ in 0..3 {
+			assert_ok!(EdgeConnectModule::apply_penalty(
+				&worker,
+				&WorkerType::Executable,
+				1,
+				crate::PenaltyReason::LateResponse
+			));
+		}
+
+		// Two decay periods passed since the last penalty, with a remainder of 50 blocks
+		System::set_block_number(260);
+		assert_ok!(EdgeConnectModule::reward_reputation(
+			&worker,
+			&WorkerType::Executable
+		));
+		let reputation = crate::ExecutableWorkers::<Test>::get(worker)
+			.unwrap()
+			.reputation;
+		assert_eq!(reputation.violations, 1);
+		assert_eq!(reputation.last_updated, Some(210));
+
+		// The remainder counts towards the next period
+		System::set_block_number(310);
+		assert_ok!(EdgeConnectModule::apply_penalty(
+			&worker,
+			&WorkerType::Executable,
+			1,
+			crate::PenaltyReason::LateResponse
+		));
+		let reputation = crate::ExecutableWorkers::<Test>::get(worker)
+			.unwrap()
+			.reputation;
+		assert_eq!(reputation.violations, 1);
+		assert_eq!(reputation.last_updated, Some(310));
+	});
+}
+
+#[test]
+fn reputation_recovers_towards_the_baseline() {
+	new_test_ext().execute_with(|| {
+		let alice = 0;
+		let worker = (alice, 0);
+
+		System::set_block_number(10);
+		register_bonded_worker(alice, b"recovering.com");
+		assert_ok!(EdgeConnectModule::apply_penalty(
+			&worker,
+			&WorkerType::Executable,
+			60,
+			crate::PenaltyReason::FalseCompletion
+		));
+
+		// One decay period raises the score from 40 to 45, still below the threshold
+		System::set_block_number(110);
+		assert_noop!(
+			EdgeConnectModule::check_worker_status(&worker, WorkerType::Executable),
+			Error::<Test>::InsufficientReputation
+		);
+
+		// A second period brings it back to 50
+		System::set_block_number(210);
+		assert_ok!(EdgeConnectModule::check_worker_status(
+			&worker,
+			WorkerType::Executable
+		));
+
+		// Recovery stops at the baseline, only accepted results raise the score above it
+		System::set_block_number(1010);
+		assert_ok!(EdgeConnectModule::reward_reputation(
+			&worker,
+			&WorkerType::Executable
+		));
+		let reputation = crate::ExecutableWorkers::<Test>::get(worker)
+			.unwrap()
+			.reputation;
+		assert_eq!(reputation.score, 65);
+		assert_eq!(reputation.violations, 0);
+	});
+}
+
+#[test]
+fn active_workers_are_indexed_while_active() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(10);
+		for owner in 0..3 {
+			register_bonded_worker(owner, format!("worker{}.com", owner).as_bytes());
+			assert_ok!(EdgeConnectModule::toggle_worker_visibility(
+				RuntimeOrigin::signed(owner),
+				WorkerType::Executable,
+				owner,
+				true
+			));
+		}
+		let active = |position| crate::ActiveWorkers::<Test>::get(WorkerType::Executable, position);
+		assert_eq!(
+			EdgeConnectModule::active_worker_count(&WorkerType::Executable),
+			3
+		);
+		assert_eq!(active(0), Some((0, 0)));
+		assert_eq!(active(2), Some((2, 2)));
+
+		// The last worker takes the position of a worker that is no longer active
+		assert_ok!(EdgeConnectModule::toggle_worker_visibility(
+			RuntimeOrigin::signed(0),
+			WorkerType::Executable,
+			0,
+			false
+		));
+		assert_eq!(
+			EdgeConnectModule::active_worker_count(&WorkerType::Executable),
+			2
+		);
+		assert_eq!(active(0), Some((2, 2)));
+		assert_eq!(active(1), Some((1, 1)));
+		assert_eq!(active(2), None);
+
+		// Removed and penalized workers leave the index as well
+		assert_ok!(EdgeConnectModule::remove_worker(
+			RuntimeOrigin::signed(1),
+			WorkerType::Executable,
+			1
+		));
+		assert_ok!(EdgeConnectModule::apply_penalty(
+			&(2, 2),
+			&WorkerType::Executable,
+			60,
+			crate::PenaltyReason::FalseCompletion
+		));
+		assert_eq!(
+			EdgeConnectModule::active_worker_count(&WorkerType::Executable),
+			0
+		);
+		assert_eq!(active(0), None);
+		assert_eq!(crate::ActiveWorkerPositions::<Test>::iter().count(), 0);
+	});
+}
+
+/// Stores a worker in the layout of version 0 of the pallet.
+fn put_v0_worker(worker: Worker<u64, BlockNumberFor<Test>, u64>) {
+	let old = crate::migrations::v0::OldWorker {
+		id: worker.id,
+		owner: worker.owner,
+		location: worker.location,
+		specs: worker.specs,
+		reputation: worker.reputation,
+		start_block: worker.start_block,
+		status: worker.status,
+		status_last_updated: worker.status_last_updated,
+		api: worker.api,
+		last_status_check: worker.last_status_check,
+	};
+	frame_support::storage::unhashed::put(
+		&crate::ExecutableWorkers::<Test>::hashed_key_for((worker.owner, worker.id)),
+		&old,
+	);
+}
+
+#[test]
+fn migration_to_v1_translates_and_indexes_workers() {
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(10);
+		register_bonded_worker(0, b"active.com");
+		register_bonded_worker(1, b"inactive.com");
+		// Workers stored before workers had concurrent tasks and encryption keys, one of them
+		// made active before the index existed
+		let mut active = crate::ExecutableWorkers::<Test>::get((0, 0)).unwrap();
+		active.status = WorkerStatusType::Active;
+		let inactive = crate::ExecutableWorkers::<Test>::get((1, 0)).unwrap();
+		put_v0_worker(active.clone());
+		put_v0_worker(inactive.clone());
+		StorageVersion::new(0).put::<EdgeConnectModule>();
+
+		crate::migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(EdgeConnectModule::on_chain_storage_version(), 1);
+		let migrated = crate::ExecutableWorkers::<Test>::get((0, 0)).unwrap();
+		assert_eq!(migrated.max_concurrent_tasks, 1);
+		assert_eq!(migrated.encryption_key, None);
+		assert_eq!(migrated, active);
+		assert_eq!(
+			crate::ExecutableWorkers::<Test>::get((1, 0)),
+			Some(inactive)
+		);
+		assert_eq!(
+			EdgeConnectModule::active_worker_count(&WorkerType::Executable),
+			1
+		);
+		assert_eq!(
+			EdgeConnectModule::active_worker_at(&WorkerType::Executable, 0),
+			Some((0, 0))
+		);
+	});
+}
+
+#[test]
+fn migration_to_v1_seeds_worker_ids_and_domains() {
+	use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(10);
+		register_bonded_worker(0, b"first.com");
+		register_bonded_worker(0, b"second.com");
+		register_bonded_worker(1, b"third.com");
+		// Storage as it was before ids were global and workers were indexed by domain
+		for worker_key in [(0, 0), (0, 1), (1, 2)] {
+			put_v0_worker(crate::ExecutableWorkers::<Test>::get(worker_key).unwrap());
+		}
+		let _ = crate::WorkerDomains::<Test>::clear(u32::MAX, None);
+		let _ = crate::WorkersByAccount::<Test>::clear(u32::MAX, None);
+		crate::NextWorkerId::<Test>::kill();
+		StorageVersion::new(0).put::<EdgeConnectModule>();
+
+		crate::migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(crate::NextWorkerId::<Test>::get(), 3);
+		assert_eq!(
+			crate::WorkerDomains::<Test>::get((
+				WorkerType::Executable,
+				Domain::try_from(b"third.com".to_vec()).unwrap()
+			)),
+			Some((1, 2))
+		);
+		assert_eq!(
+			crate::WorkersByAccount::<Test>::get(0).len(),
+			2
+		);
+		assert_noop!(
+			EdgeConnectModule::register_worker(
+				RuntimeOrigin::signed(2),
+				WorkerType::Executable,
+				BoundedVec::try_from(b"first.com".to_vec()).unwrap(),
+				590000,
+				120000,
+				100000000,
+				100000000,
+				12,
+				1
+			),
+			Error::<Test>::WorkerExists
+		);
+		register_bonded_worker(1, b"fourth.com");
+		assert!(crate::ExecutableWorkers::<Test>::get((1, 3)).is_some());
+	});
+}
+
 /*
 
 	let domain_str = "some_api_domain.com";
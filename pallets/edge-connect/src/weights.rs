@@ -6,6 +6,8 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `SGOWMBP3`, CPU: `<UNKNOWN>`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+//!
+//! Functions marked as placeholder estimates were written by hand and are still to be benchmarked.
 
 // Executed Command:
 // ./target/release/cyborg-node
@@ -38,135 +40,336 @@ pub trait WeightInfo {
 	fn suspend_worker() -> Weight;
     fn ban_worker() -> Weight;
     fn unsuspend_worker() -> Weight;
+	fn set_encryption_key() -> Weight;
+	fn withdraw_unbonded() -> Weight;
+	fn update_worker() -> Weight;
+	fn unban_account() -> Weight;
+	fn unban_domain() -> Weight;
 }
 
 /// Weights for `pallet_edge_connect` using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-	/// Storage: `EdgeConnect::AccountWorkers` (r:1 w:1)
+	/// Storage: `EdgeConnect::WorkerDomains` (r:1 w:1)
+	/// Proof: `EdgeConnect::WorkerDomains` (`max_values`: None, `max_size`: Some(187), added: 2662, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::NextWorkerId` (r:1 w:1)
+	/// Proof: `EdgeConnect::NextWorkerId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::AccountWorkers` (r:0 w:1)
 	/// Proof: `EdgeConnect::AccountWorkers` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `EdgeConnect::WorkerClusters` (r:0 w:1)
 	/// Proof: `EdgeConnect::WorkerClusters` (`max_values`: None, `max_size`: Some(223), added: 2698, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::WorkerBonds` (r:0 w:1)
+	/// Proof: `EdgeConnect::WorkerBonds` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::BannedAccounts` (r:1 w:0)
+	/// Proof: `EdgeConnect::BannedAccounts` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::BannedDomains` (r:1 w:0)
+	/// Proof: `EdgeConnect::BannedDomains` (`max_values`: None, `max_size`: Some(151), added: 2626, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::ActiveWorkerPositions` (r:1 w:0)
+	/// Proof: `EdgeConnect::ActiveWorkerPositions` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
 	fn register_worker() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `76`
-		//  Estimated: `3513`
-		// Minimum execution time: 11_000_000 picoseconds.
-		Weight::from_parts(12_000_000, 3513)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		//  Estimated: `3652`
+		// Minimum execution time: 13_000_000 picoseconds.
+		// Placeholder estimate: storage accesses adjusted by hand since the last benchmark.
+		Weight::from_parts(14_000_000, 3652)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: `EdgeConnect::WorkerClusters` (r:1 w:1)
 	/// Proof: `EdgeConnect::WorkerClusters` (`max_values`: None, `max_size`: Some(223), added: 2698, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::WorkerDomains` (r:0 w:1)
+	/// Proof: `EdgeConnect::WorkerDomains` (`max_values`: None, `max_size`: Some(187), added: 2662, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::WorkerBonds` (r:1 w:1)
+	/// Proof: `EdgeConnect::WorkerBonds` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::UnbondingBonds` (r:0 w:1)
+	/// Proof: `EdgeConnect::UnbondingBonds` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::ActiveWorkerPositions` (r:2 w:2)
+	/// Proof: `EdgeConnect::ActiveWorkerPositions` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::ActiveWorkerCount` (r:1 w:1)
+	/// Proof: `EdgeConnect::ActiveWorkerCount` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::ActiveWorkers` (r:1 w:2)
+	/// Proof: `EdgeConnect::ActiveWorkers` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
 	fn remove_worker() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `260`
 		//  Estimated: `3688`
 		// Minimum execution time: 9_000_000 picoseconds.
+		// Placeholder estimate: storage accesses adjusted by hand since the last benchmark.
 		Weight::from_parts(10_000_000, 3688)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
   /// Storage: `EdgeConnect::WorkerClusters` (r:1 w:1)
 	/// Proof: `EdgeConnect::WorkerClusters` (`max_values`: None, `max_size`: Some(223), added: 2698, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::AwaitingAttestation` (r:1 w:0)
+	/// Proof: `EdgeConnect::AwaitingAttestation` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::ActiveWorkerPositions` (r:2 w:2)
+	/// Proof: `EdgeConnect::ActiveWorkerPositions` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::ActiveWorkerCount` (r:1 w:1)
+	/// Proof: `EdgeConnect::ActiveWorkerCount` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::ActiveWorkers` (r:1 w:2)
+	/// Proof: `EdgeConnect::ActiveWorkers` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
 	fn toggle_worker_visibility() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `260`
 		//  Estimated: `3688`
 		// Minimum execution time: 9_000_000 picoseconds.
+		// Placeholder estimate: storage accesses adjusted by hand since the last benchmark.
 		Weight::from_parts(10_000_000, 3688)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 
 	fn penalize_worker() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
 		Weight::from_parts(10_000_000, 3688)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 
 	fn suspend_worker() -> Weight {
+        // Placeholder estimate written by hand, not benchmarked yet.
         Weight::from_parts(15_000_000, 3688)
-            .saturating_add(T::DbWeight::get().reads(1_u64))
-            .saturating_add(T::DbWeight::get().writes(2_u64))
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(8_u64))
     }
     
     fn ban_worker() -> Weight {
+        // Placeholder estimate written by hand, not benchmarked yet.
         Weight::from_parts(10_000_000, 3688)
-            .saturating_add(T::DbWeight::get().reads(1_u64))
-            .saturating_add(T::DbWeight::get().writes(1_u64))
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(10_u64))
     }
     
     fn unsuspend_worker() -> Weight {
+        // Placeholder estimate written by hand, not benchmarked yet.
         Weight::from_parts(10_000_000, 3688)
-            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().reads(2_u64))
             .saturating_add(T::DbWeight::get().writes(1_u64))
     }
 
+	/// Storage: `EdgeConnect::WorkerClusters` (r:1 w:1)
+	/// Proof: `EdgeConnect::WorkerClusters` (`max_values`: None, `max_size`: Some(256), added: 2731, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::ActiveWorkerPositions` (r:1 w:0)
+	/// Proof: `EdgeConnect::ActiveWorkerPositions` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	fn set_encryption_key() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(10_000_000, 3721)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EdgeConnect::UnbondingBonds` (r:1 w:1)
+	/// Proof: `EdgeConnect::UnbondingBonds` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn withdraw_unbonded() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(22_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `EdgeConnect::WorkerClusters` (r:1 w:1)
+	/// Proof: `EdgeConnect::WorkerClusters` (`max_values`: None, `max_size`: Some(256), added: 2731, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::WorkerDomains` (r:1 w:2)
+	/// Proof: `EdgeConnect::WorkerDomains` (`max_values`: None, `max_size`: Some(187), added: 2662, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::AwaitingAttestation` (r:0 w:1)
+	/// Proof: `EdgeConnect::AwaitingAttestation` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::BannedDomains` (r:1 w:0)
+	/// Proof: `EdgeConnect::BannedDomains` (`max_values`: None, `max_size`: Some(151), added: 2626, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::ActiveWorkerPositions` (r:2 w:2)
+	/// Proof: `EdgeConnect::ActiveWorkerPositions` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::ActiveWorkerCount` (r:1 w:1)
+	/// Proof: `EdgeConnect::ActiveWorkerCount` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::ActiveWorkers` (r:1 w:2)
+	/// Proof: `EdgeConnect::ActiveWorkers` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	fn update_worker() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(15_000_000, 3721)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `EdgeConnect::BannedAccounts` (r:1 w:1)
+	/// Proof: `EdgeConnect::BannedAccounts` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	fn unban_account() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(9_000_000, 3510)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EdgeConnect::BannedDomains` (r:1 w:1)
+	/// Proof: `EdgeConnect::BannedDomains` (`max_values`: None, `max_size`: Some(151), added: 2626, mode: `MaxEncodedLen`)
+	fn unban_domain() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(9_000_000, 3616)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
 
 }
 
 // For backwards compatibility and tests.
 impl WeightInfo for () {
-	/// Storage: `EdgeConnect::AccountWorkers` (r:1 w:1)
+	/// Storage: `EdgeConnect::WorkerDomains` (r:1 w:1)
+	/// Proof: `EdgeConnect::WorkerDomains` (`max_values`: None, `max_size`: Some(187), added: 2662, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::NextWorkerId` (r:1 w:1)
+	/// Proof: `EdgeConnect::NextWorkerId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::AccountWorkers` (r:0 w:1)
 	/// Proof: `EdgeConnect::AccountWorkers` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `EdgeConnect::WorkerClusters` (r:0 w:1)
 	/// Proof: `EdgeConnect::WorkerClusters` (`max_values`: None, `max_size`: Some(223), added: 2698, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::WorkerBonds` (r:0 w:1)
+	/// Proof: `EdgeConnect::WorkerBonds` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::BannedAccounts` (r:1 w:0)
+	/// Proof: `EdgeConnect::BannedAccounts` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::BannedDomains` (r:1 w:0)
+	/// Proof: `EdgeConnect::BannedDomains` (`max_values`: None, `max_size`: Some(151), added: 2626, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::ActiveWorkerPositions` (r:1 w:0)
+	/// Proof: `EdgeConnect::ActiveWorkerPositions` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
 	fn register_worker() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `76`
-		//  Estimated: `3513`
-		// Minimum execution time: 11_000_000 picoseconds.
-		Weight::from_parts(12_000_000, 3513)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		//  Estimated: `3652`
+		// Minimum execution time: 13_000_000 picoseconds.
+		// Placeholder estimate: storage accesses adjusted by hand since the last benchmark.
+		Weight::from_parts(14_000_000, 3652)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: `EdgeConnect::WorkerClusters` (r:1 w:1)
 	/// Proof: `EdgeConnect::WorkerClusters` (`max_values`: None, `max_size`: Some(223), added: 2698, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::WorkerDomains` (r:0 w:1)
+	/// Proof: `EdgeConnect::WorkerDomains` (`max_values`: None, `max_size`: Some(187), added: 2662, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::WorkerBonds` (r:1 w:1)
+	/// Proof: `EdgeConnect::WorkerBonds` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::UnbondingBonds` (r:0 w:1)
+	/// Proof: `EdgeConnect::UnbondingBonds` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::ActiveWorkerPositions` (r:2 w:2)
+	/// Proof: `EdgeConnect::ActiveWorkerPositions` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::ActiveWorkerCount` (r:1 w:1)
+	/// Proof: `EdgeConnect::ActiveWorkerCount` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::ActiveWorkers` (r:1 w:2)
+	/// Proof: `EdgeConnect::ActiveWorkers` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
 	fn remove_worker() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `260`
 		//  Estimated: `3688`
 		// Minimum execution time: 9_000_000 picoseconds.
+		// Placeholder estimate: storage accesses adjusted by hand since the last benchmark.
 		Weight::from_parts(10_000_000, 3688)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
   	/// Storage: `EdgeConnect::WorkerClusters` (r:1 w:1)
 	/// Proof: `EdgeConnect::WorkerClusters` (`max_values`: None, `max_size`: Some(223), added: 2698, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::AwaitingAttestation` (r:1 w:0)
+	/// Proof: `EdgeConnect::AwaitingAttestation` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::ActiveWorkerPositions` (r:2 w:2)
+	/// Proof: `EdgeConnect::ActiveWorkerPositions` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::ActiveWorkerCount` (r:1 w:1)
+	/// Proof: `EdgeConnect::ActiveWorkerCount` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::ActiveWorkers` (r:1 w:2)
+	/// Proof: `EdgeConnect::ActiveWorkers` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
 	fn toggle_worker_visibility() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `260`
 		//  Estimated: `3688`
 		// Minimum execution time: 9_000_000 picoseconds.
+		// Placeholder estimate: storage accesses adjusted by hand since the last benchmark.
 		Weight::from_parts(10_000_000, 3688)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	fn penalize_worker() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
 		Weight::from_parts(10_000_000, 3688)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 
 	fn suspend_worker() -> Weight {
+        // Placeholder estimate written by hand, not benchmarked yet.
         Weight::from_parts(15_000_000, 3688)
-            .saturating_add(RocksDbWeight::get().reads(1_u64))
-            .saturating_add(RocksDbWeight::get().writes(2_u64))
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().writes(8_u64))
     }
     
     fn ban_worker() -> Weight {
+        // Placeholder estimate written by hand, not benchmarked yet.
         Weight::from_parts(10_000_000, 3688)
-            .saturating_add(RocksDbWeight::get().reads(1_u64))
-            .saturating_add(RocksDbWeight::get().writes(1_u64))
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().writes(10_u64))
     }
     
     fn unsuspend_worker() -> Weight {
+        // Placeholder estimate written by hand, not benchmarked yet.
         Weight::from_parts(10_000_000, 3688)
-            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
             .saturating_add(RocksDbWeight::get().writes(1_u64))
     }
 
+	/// Storage: `EdgeConnect::WorkerClusters` (r:1 w:1)
+	/// Proof: `EdgeConnect::WorkerClusters` (`max_values`: None, `max_size`: Some(256), added: 2731, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::ActiveWorkerPositions` (r:1 w:0)
+	/// Proof: `EdgeConnect::ActiveWorkerPositions` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	fn set_encryption_key() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(10_000_000, 3721)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EdgeConnect::UnbondingBonds` (r:1 w:1)
+	/// Proof: `EdgeConnect::UnbondingBonds` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn withdraw_unbonded() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(22_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `EdgeConnect::WorkerClusters` (r:1 w:1)
+	/// Proof: `EdgeConnect::WorkerClusters` (`max_values`: None, `max_size`: Some(256), added: 2731, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::WorkerDomains` (r:1 w:2)
+	/// Proof: `EdgeConnect::WorkerDomains` (`max_values`: None, `max_size`: Some(187), added: 2662, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::AwaitingAttestation` (r:0 w:1)
+	/// Proof: `EdgeConnect::AwaitingAttestation` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::BannedDomains` (r:1 w:0)
+	/// Proof: `EdgeConnect::BannedDomains` (`max_values`: None, `max_size`: Some(151), added: 2626, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::ActiveWorkerPositions` (r:2 w:2)
+	/// Proof: `EdgeConnect::ActiveWorkerPositions` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::ActiveWorkerCount` (r:1 w:1)
+	/// Proof: `EdgeConnect::ActiveWorkerCount` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::ActiveWorkers` (r:1 w:2)
+	/// Proof: `EdgeConnect::ActiveWorkers` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	fn update_worker() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(15_000_000, 3721)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `EdgeConnect::BannedAccounts` (r:1 w:1)
+	/// Proof: `EdgeConnect::BannedAccounts` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	fn unban_account() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(9_000_000, 3510)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EdgeConnect::BannedDomains` (r:1 w:1)
+	/// Proof: `EdgeConnect::BannedDomains` (`max_values`: None, `max_size`: Some(151), added: 2626, mode: `MaxEncodedLen`)
+	fn unban_domain() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(9_000_000, 3616)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
 
 
 }
@@ -18,6 +18,7 @@ use codec::{Decode, Encode, MaxEncodedLen};
 pub use cyborg_primitives::{
 	oracle::OracleKey,
 	task::{NzkTaskInfoHandler, TaskId, TaskKind, ZkProof},
+	worker::WorkerType,
 	zkml::*,
 };
 use frame_support::traits::Get;
@@ -282,7 +283,8 @@ pub mod pallet {
 			)
 		}
 
-		/// Sends updated nzk task info to pallets that implement T::NzkTaskHandler and emits an event
+		/// Sends updated nzk task info to pallets that implement T::NzkTaskHandler and emits an event.
+		/// The worker whose proof was accepted is rewarded.
 		fn update_nzk_task(
 			task_id: TaskId,
 			is_accepted: bool,
@@ -295,6 +297,13 @@ pub mod pallet {
 					T::NzkTaskInfoHandler::update_nzk_task(task_id, task);
 
 					if is_accepted {
+						// Neuro-ZK tasks run on executable workers
+						if let Some(worker) = T::NzkTaskInfoHandler::get_nzk_task_worker(task_id) {
+							let _ = pallet_edge_connect::Pallet::<T>::reward_reputation(
+								&worker,
+								&WorkerType::Executable,
+							);
+						}
 						Self::deposit_event(Event::NzkProofVerified {
 							task_id,
 							last_block_processed,
@@ -1,11 +1,14 @@
 pub use crate as pallet_neuro_zk;
-use frame_support::{derive_impl, parameter_types, weights::constants::RocksDbWeight};
-use frame_system::{mocking::MockBlock, GenesisConfig};
+use frame_support::{
+	derive_impl, parameter_types, traits::Randomness, weights::constants::RocksDbWeight,
+};
+use frame_system::{mocking::MockBlock, pallet_prelude::BlockNumberFor, EnsureRoot, GenesisConfig};
 use pallet_edge_connect;
 use pallet_payment;
 use pallet_task_management;
+use sp_core::H256;
 use sp_runtime::{
-	traits::{ConstU32, ConstU64, ConstU8},
+	traits::{BlakeTwo256, ConstBool, ConstI32, ConstU32, ConstU64, ConstU8, Hash},
 	BuildStorage,
 };
 
@@ -79,11 +82,58 @@ impl pallet_timestamp::Config for Test {
 impl pallet_edge_connect::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
+	type BondCurrency = ();
+	type WorkerBond = ();
+	type UnbondingPeriod = ConstU64<0>;
+	type PenaltySlash = ();
+	type BanSlash = ();
+	type Slash = ();
+	type ReattestSpecUpgrades = ConstBool<false>;
+	type BanAppealOrigin = EnsureRoot<u64>;
+	type SuccessReward = ConstI32<5>;
+	type MaxReputationScore = ConstI32<100>;
+	type ViolationDecayPeriod = ConstU64<0>;
+	type ReputationBaseline = ConstI32<60>;
+	type ReputationRecovery = ConstI32<0>;
+	type MaxWorkersPerAccount = ConstU32<10>;
+	type WorkerStatusHandler = ();
+}
+
+pub struct TestRandomness;
+
+impl Randomness<H256, BlockNumberFor<Test>> for TestRandomness {
+	fn random(subject: &[u8]) -> (H256, BlockNumberFor<Test>) {
+		(
+			BlakeTwo256::hash_of(&(System::parent_hash(), subject)),
+			System::block_number(),
+		)
+	}
 }
 
 impl pallet_task_management::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
+	type Randomness = TestRandomness;
+	type BlocksPerHour = ConstU64<10>;
+	type FalseCompletionPenalty = ConstI32<20>;
+	type ReceptionTimeout = ConstU64<10>;
+	type CancellationCharge = ConstU32<0>;
+	type ExecutionTimeout = ConstU64<100>;
+	type LateResponsePenalty = ConstI32<10>;
+	type MaxExpiringTasksPerBlock = ConstU32<2>;
+	type RateLimitWindow = ConstU64<10>;
+	type MaxTasksPerWindow = ConstU32<5>;
+	type MaxPendingTasks = ConstU32<5>;
+	type MaxTasksPerDispatch = ConstU32<3>;
+	type MaxGraphNodes = ConstU32<5>;
+	type MaxScheduledTasksPerBlock = ConstU32<2>;
+	type TaskRetentionPeriod = ConstU64<100>;
+	type DisputeWindow = ConstU64<0>;
+	type DisputeBond = ();
+	type DisputeOrigin = EnsureRoot<u64>;
+	type ModelDeposit = ();
+	type ModelVersionDeposit = ();
+	type MaxWorkerCandidates = ConstU32<16>;
 }
 
 impl pallet_payment::Config for Test {
@@ -4,7 +4,7 @@ use crate::{RequestedProofs, SubmittedPerProof, VerificationResultsPerProof};
 use frame_support::{assert_noop, assert_ok, pallet_prelude::ConstU32, BoundedVec};
 use frame_system::pallet_prelude::BlockNumberFor;
 
-use cyborg_primitives::{task::*, zkml::*};
+use cyborg_primitives::{task::*, worker::WorkerType, zkml::*};
 
 fn create_neurozk_task(task_id: TaskId) {
 	let who: AccountId = 1;
@@ -273,3 +273,41 @@ fn finalize_verification_rejects_below_threshold() {
 		assert_eq!(task.nzk_data.unwrap().last_proof_accepted.unwrap().0, false);
 	});
 }
+
+#[test]
+fn accepted_proof_rewards_the_worker() {
+	new_test_ext().execute_with(|| {
+		let task_id = 1;
+		let miner = 2;
+		create_neurozk_task(task_id);
+		assert_ok!(EdgeConnectModule::register_worker(
+			RuntimeOrigin::signed(miner),
+			WorkerType::Executable,
+			BoundedVec::try_from(b"miner.com".to_vec()).unwrap(),
+			590000,
+			120000,
+			100000000,
+			100000000,
+			12,
+			1
+		));
+		let worker = (miner, 0);
+		pallet_task_management::TaskAllocations::<Test>::insert(task_id, worker);
+		assert_ok!(EdgeConnectModule::apply_penalty(
+			&worker,
+			&WorkerType::Executable,
+			20,
+			pallet_edge_connect::PenaltyReason::FalseCompletion
+		));
+
+		for acc in [10, 11, 12, 13, 14] {
+			NeuroZk::on_new_data(&acc, &task_id, &true);
+		}
+
+		let reputation = pallet_edge_connect::ExecutableWorkers::<Test>::get(worker)
+			.unwrap()
+			.reputation;
+		assert_eq!(reputation.score, 85);
+		assert_eq!(reputation.successful_tasks, 1);
+	});
+}
@@ -24,8 +24,11 @@ pub mod pallet {
 
 	use frame_support::{
 		pallet_prelude::*,
-		sp_runtime::{traits::CheckedMul, ArithmeticError},
-		traits::{Currency, ExistenceRequirement},
+		sp_runtime::{
+			traits::{CheckedMul, Saturating},
+			ArithmeticError,
+		},
+		traits::{Currency, ExistenceRequirement, ReservableCurrency},
 	};
 	use sp_std::vec::Vec;
 
@@ -82,7 +85,8 @@ pub mod pallet {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
 		/// Abstraction over the chain's currency system, allowing this pallet to interact with balances.
-		type Currency: Currency<Self::AccountId>;
+		/// Other pallets reserve their deposits in it too.
+		type Currency: ReservableCurrency<Self::AccountId>;
 
 		/// A type representing the weights required by the dispatchable functions of this pallet.
 		type WeightInfo: WeightInfo;
@@ -183,6 +187,11 @@ pub mod pallet {
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		HoursConsumed(T::AccountId, u32), // Emitted when compute hours are used.
+		HoursRefunded(T::AccountId, u32), // Emitted when unused compute hours are given back.
+		TipCollected(T::AccountId, BalanceOf<T>), // A task tip was moved to the provider.
+		TipRefunded(T::AccountId, BalanceOf<T>), // A task tip was given back to its payer.
+		BondCollected(T::AccountId, BalanceOf<T>), // A dispute bond was moved to the provider.
+		BondRefunded(T::AccountId, BalanceOf<T>), // A dispute bond was given back to its payer.
 		ServiceProviderAccountSet(T::AccountId), // When admin sets provider.
 		MinerUsageRecorded(T::AccountId, u8, u8, u8), // Usage data recorded.
 		MinerRewarded(T::AccountId, BalanceOf<T>), // Reward given to a miner.
@@ -652,4 +661,86 @@ pub mod pallet {
 			Ok(())
 		}
 	}
+
+	impl<T: Config> Pallet<T> {
+		/// What `hours` compute hours cost at the current subscription fee.
+		pub fn compute_hours_cost(hours: u32) -> BalanceOf<T> {
+			SubscriptionFee::<T>::get().saturating_mul(hours.into())
+		}
+
+		/// Credits `hours` back to the compute hours of `who`, e.g. the unused part of a task
+		/// deposit that was consumed up front.
+		pub fn refund_compute_hours(who: &T::AccountId, hours: u32) {
+			if hours == 0 {
+				return;
+			}
+
+			ComputeHours::<T>::mutate(who, |current| *current = current.saturating_add(hours));
+			Self::deposit_event(Event::HoursRefunded(who.clone(), hours));
+		}
+
+		/// Moves the tip `who` attached to a task to the service provider account, from which it
+		/// is paid out to the miner that runs the task with the other rewards.
+		pub fn collect_tip(who: &T::AccountId, tip: BalanceOf<T>) -> DispatchResult {
+			if tip.is_zero() {
+				return Ok(());
+			}
+
+			let provider =
+				ServiceProviderAccount::<T>::get().ok_or(Error::<T>::ServiceProviderAccountNotFound)?;
+			T::Currency::transfer(who, &provider, tip, ExistenceRequirement::KeepAlive)?;
+			Self::deposit_event(Event::TipCollected(who.clone(), tip));
+			Ok(())
+		}
+
+		/// Adds a tip collected with `collect_tip` to the pending rewards of `miner`.
+		pub fn reward_tip(miner: &T::AccountId, tip: BalanceOf<T>) {
+			if tip.is_zero() {
+				return;
+			}
+
+			MinerPendingRewards::<T>::mutate(miner, |pending| *pending = pending.saturating_add(tip));
+			Self::deposit_event(Event::MinerRewarded(miner.clone(), tip));
+		}
+
+		/// Gives a tip collected with `collect_tip` back to `who`.
+		pub fn refund_tip(who: &T::AccountId, tip: BalanceOf<T>) -> DispatchResult {
+			if tip.is_zero() {
+				return Ok(());
+			}
+
+			let provider =
+				ServiceProviderAccount::<T>::get().ok_or(Error::<T>::ServiceProviderAccountNotFound)?;
+			T::Currency::transfer(&provider, who, tip, ExistenceRequirement::AllowDeath)?;
+			Self::deposit_event(Event::TipRefunded(who.clone(), tip));
+			Ok(())
+		}
+
+		/// Moves the bond `who` puts up to dispute the result of a task to the service provider
+		/// account, where it is held until the dispute is decided. A forfeited bond stays there.
+		pub fn collect_bond(who: &T::AccountId, bond: BalanceOf<T>) -> DispatchResult {
+			if bond.is_zero() {
+				return Ok(());
+			}
+
+			let provider =
+				ServiceProviderAccount::<T>::get().ok_or(Error::<T>::ServiceProviderAccountNotFound)?;
+			T::Currency::transfer(who, &provider, bond, ExistenceRequirement::KeepAlive)?;
+			Self::deposit_event(Event::BondCollected(who.clone(), bond));
+			Ok(())
+		}
+
+		/// Gives a bond collected with `collect_bond` back to `who`.
+		pub fn refund_bond(who: &T::AccountId, bond: BalanceOf<T>) -> DispatchResult {
+			if bond.is_zero() {
+				return Ok(());
+			}
+
+			let provider =
+				ServiceProviderAccount::<T>::get().ok_or(Error::<T>::ServiceProviderAccountNotFound)?;
+			T::Currency::transfer(&provider, who, bond, ExistenceRequirement::AllowDeath)?;
+			Self::deposit_event(Event::BondRefunded(who.clone(), bond));
+			Ok(())
+		}
+	}
 }
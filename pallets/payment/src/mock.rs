@@ -1,9 +1,9 @@
 pub use crate as pallet_payment;
 use frame_support::{derive_impl, parameter_types, weights::constants::RocksDbWeight};
-use frame_system::{mocking::MockBlock, GenesisConfig};
+use frame_system::{mocking::MockBlock, EnsureRoot, GenesisConfig};
 use pallet_sudo;
 use sp_runtime::{
-	traits::{ConstU32, ConstU64},
+	traits::{ConstBool, ConstI32, ConstU128, ConstU32, ConstU64},
 	BuildStorage,
 };
 
@@ -71,6 +71,21 @@ impl pallet_payment::Config for Test {
 impl pallet_edge_connect::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
+	type BondCurrency = Balances;
+	type WorkerBond = ConstU128<0>;
+	type UnbondingPeriod = ConstU64<0>;
+	type PenaltySlash = ();
+	type BanSlash = ();
+	type Slash = ();
+	type ReattestSpecUpgrades = ConstBool<false>;
+	type BanAppealOrigin = EnsureRoot<AccountId>;
+	type SuccessReward = ConstI32<0>;
+	type MaxReputationScore = ConstI32<100>;
+	type ViolationDecayPeriod = ConstU64<0>;
+	type ReputationBaseline = ConstI32<60>;
+	type ReputationRecovery = ConstI32<0>;
+	type MaxWorkersPerAccount = ConstU32<10>;
+	type WorkerStatusHandler = ();
 }
 
 parameter_types! {
@@ -424,3 +424,73 @@ fn reward_miner_new_fails_when_rates_not_set() {
 		);
 	});
 }
+
+#[test]
+fn refund_compute_hours_credits_user() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let user = 1;
+
+		pallet_payment::ComputeHours::<Test>::insert(user, 5);
+
+		PaymentModule::refund_compute_hours(&user, 7);
+		assert_eq!(pallet_payment::ComputeHours::<Test>::get(user), 12);
+		System::assert_last_event(crate::Event::HoursRefunded(user, 7).into());
+
+		// Refunding nothing leaves the balance and events untouched
+		let events = System::events().len();
+		PaymentModule::refund_compute_hours(&user, 0);
+		assert_eq!(pallet_payment::ComputeHours::<Test>::get(user), 12);
+		assert_eq!(System::events().len(), events);
+	});
+}
+
+#[test]
+fn task_tips_are_collected_rewarded_and_refunded() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (user, provider, miner) = (1, 2, 3);
+		assert_ok!(PaymentModule::set_service_provider_account(RuntimeOrigin::root(), provider));
+
+		assert_ok!(PaymentModule::collect_tip(&user, 500));
+		assert_eq!(Balances::free_balance(user), 9_500);
+		assert_eq!(Balances::free_balance(provider), 50_500);
+
+		PaymentModule::reward_tip(&miner, 300);
+		assert_eq!(pallet_payment::MinerPendingRewards::<Test>::get(miner), 300);
+
+		// Pending rewards saturate instead of overflowing.
+		PaymentModule::reward_tip(&miner, u128::MAX);
+		assert_eq!(pallet_payment::MinerPendingRewards::<Test>::get(miner), u128::MAX);
+		pallet_payment::MinerPendingRewards::<Test>::insert(miner, 300);
+
+		assert_ok!(PaymentModule::refund_tip(&user, 200));
+		assert_eq!(Balances::free_balance(user), 9_700);
+		System::assert_last_event(crate::Event::TipRefunded(user, 200).into());
+	});
+}
+
+#[test]
+fn dispute_bonds_are_collected_and_refunded() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (user, provider) = (1, 2);
+		assert_noop!(
+			PaymentModule::collect_bond(&user, 500),
+			crate::Error::<Test>::ServiceProviderAccountNotFound
+		);
+		assert_ok!(PaymentModule::set_service_provider_account(
+			RuntimeOrigin::root(),
+			provider
+		));
+
+		assert_ok!(PaymentModule::collect_bond(&user, 500));
+		assert_eq!(Balances::free_balance(user), 9_500);
+		assert_eq!(Balances::free_balance(provider), 50_500);
+		System::assert_last_event(crate::Event::BondCollected(user, 500).into());
+
+		assert_ok!(PaymentModule::refund_bond(&user, 500));
+		assert_eq!(Balances::free_balance(user), 10_000);
+		System::assert_last_event(crate::Event::BondRefunded(user, 500).into());
+	});
+}
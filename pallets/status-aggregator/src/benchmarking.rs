@@ -4,9 +4,13 @@ use super::*;
 use frame_benchmarking::v2::*;
 
 use cyborg_primitives::{oracle::ProcessStatus, worker::*};
-use frame_support::{sp_runtime::traits::ConstU32, BoundedVec};
+use frame_support::{
+	sp_runtime::{traits::ConstU32, Saturating},
+	traits::{Currency, Get},
+	BoundedVec,
+};
 use frame_system::RawOrigin;
-use scale_info::prelude::vec;
+use scale_info::prelude::{format, vec};
 use sp_std::vec::Vec;
 
 // Define a constant for the worker API domain.
@@ -37,12 +41,19 @@ mod benchmarks {
 
 			// Generate a worker account (executor) using the `account` helper function.
 			let executor: T::AccountId = account("benchmark_account", index, seed);
+			<T as pallet_edge_connect::Config>::BondCurrency::make_free_balance_be(
+				&executor,
+				<T as pallet_edge_connect::Config>::BondCurrency::minimum_balance()
+					.saturating_add(<T as pallet_edge_connect::Config>::WorkerBond::get()),
+			);
 			let latitude: Latitude = 1;
 			let longitude: Longitude = 100;
 			let ram: RamBytes = 5_000_000_000u64;
 			let storage: StorageBytes = 100_000_000_000u64;
 			let cpu: CpuCores = 5u16;
-			let domain = get_domain(WORKER_API_DOMAIN1);
+			let max_concurrent_tasks = 1u32;
+			// Domains are unique across all accounts
+			let domain = get_domain(&format!("{}/{}", WORKER_API_DOMAIN1, i));
 			let worker_type = WorkerType::Docker;
 
 			// Register the worker by calling `register_worker` from the pallet_edge_connect pallet.
@@ -55,6 +66,7 @@ mod benchmarks {
 				ram,
 				storage,
 				cpu,
+				max_concurrent_tasks,
 			)?;
 		}
 
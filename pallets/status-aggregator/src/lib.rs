@@ -17,7 +17,7 @@ pub use weights::*;
 use codec::{Decode, Encode, MaxEncodedLen};
 use cyborg_primitives::{
 	oracle::{OracleWorkerFormat, ProcessStatus},
-	worker::{WorkerId, WorkerInfoHandler, WorkerStatusType, WorkerType},
+	worker::{WorkerId, WorkerInfoHandler, WorkerStatusHandler, WorkerStatusType, WorkerType},
 };
 use frame_support::{pallet_prelude::IsType, sp_runtime::RuntimeDebug, BoundedVec};
 use frame_support::{traits::Get, LOG_TARGET};
@@ -87,6 +87,9 @@ pub mod pallet {
 			BlockNumberFor<Self>,
 			Self::Moment,
 		>;
+
+		/// Notified of the workers whose aggregated status is `Active`
+		type WorkerStatusHandler: WorkerStatusHandler<Self::AccountId, WorkerId>;
 	}
 
 	#[pallet::pallet]
@@ -261,11 +264,21 @@ pub mod pallet {
 				} else {
 					WorkerStatusType::Inactive
 				};
+				let is_active = status == WorkerStatusType::Active;
 				worker_cluster.status = status;
 				worker_cluster.status_last_updated = last_block_processed;
 
 				T::WorkerInfoHandler::update_worker_cluster(&key_worker, &worker_type, worker_cluster);
 
+				if is_active {
+					// Handing queued tasks to the worker is accounted for once it happened
+					let weight = T::WorkerStatusHandler::on_worker_active(&key_worker, &worker_type);
+					frame_system::Pallet::<T>::register_extra_weight_unchecked(
+						weight,
+						DispatchClass::Mandatory,
+					);
+				}
+
 				Self::deposit_event(Event::UpdateFromAggregatedWorkerInfo {
 					worker: key_worker,
 					online,
@@ -1,9 +1,9 @@
 pub use crate as pallet_status_aggregator;
 use frame_support::{derive_impl, parameter_types, weights::constants::RocksDbWeight};
-use frame_system::{mocking::MockBlock, GenesisConfig};
+use frame_system::{mocking::MockBlock, EnsureRoot, GenesisConfig};
 use pallet_edge_connect;
 use sp_runtime::{
-	traits::{ConstU32, ConstU64, ConstU8},
+	traits::{ConstBool, ConstI32, ConstU32, ConstU64, ConstU8},
 	BuildStorage,
 };
 
@@ -59,6 +59,7 @@ impl pallet_status_aggregator::Config for Test {
 	type ThresholdUptimeStatus = ConstU8<75>;
 	type MaxAggregateParamLength = ConstU32<10>;
 	type WorkerInfoHandler = EdgeConnectModule;
+	type WorkerStatusHandler = ();
 }
 
 impl pallet_timestamp::Config for Test {
@@ -71,6 +72,21 @@ impl pallet_timestamp::Config for Test {
 impl pallet_edge_connect::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
+	type BondCurrency = ();
+	type WorkerBond = ();
+	type UnbondingPeriod = ConstU64<0>;
+	type PenaltySlash = ();
+	type BanSlash = ();
+	type Slash = ();
+	type ReattestSpecUpgrades = ConstBool<false>;
+	type BanAppealOrigin = EnsureRoot<u64>;
+	type SuccessReward = ConstI32<0>;
+	type MaxReputationScore = ConstI32<100>;
+	type ViolationDecayPeriod = ConstU64<0>;
+	type ReputationBaseline = ConstI32<60>;
+	type ReputationRecovery = ConstI32<0>;
+	type MaxWorkersPerAccount = ConstU32<10>;
+	type WorkerStatusHandler = ();
 }
 
 // Build genesis storage according to the mock runtime.
@@ -13,6 +13,17 @@ use cyborg_primitives::{
 	worker::*,
 };
 
+/// The ID of the `n`th worker registered by `account`, as worker IDs are allocated globally
+fn nth_worker_id(account: AccountId, n: usize) -> WorkerId {
+	let mut worker_ids: Vec<WorkerId> = pallet_edge_connect::WorkerClusters::<Test>::iter_keys()
+		.chain(pallet_edge_connect::ExecutableWorkers::<Test>::iter_keys())
+		.filter(|(owner, _)| *owner == account)
+		.map(|(_, worker_id)| worker_id)
+		.collect();
+	worker_ids.sort();
+	worker_ids[n]
+}
+
 #[test]
 fn prevents_nonexistent_worker_storage() {
 	new_test_ext().execute_with(|| {
@@ -78,7 +89,9 @@ fn on_new_data_works_as_expected() {
 		// register workers
 		for worker in worker_addrs.iter() {
 			for id in 0..worker_ids.len() {
-				let domain_str = "some_api_domain.".to_owned() + &id.to_string() + ".com";
+				// domains are unique across all accounts
+				let domain_str =
+					"some_api_domain.".to_owned() + &worker.to_string() + "." + &id.to_string() + ".com";
 				let domain_vec = domain_str.as_bytes().to_vec();
 				let domain: BoundedVec<u8, ConstU32<128>> = BoundedVec::try_from(domain_vec).unwrap();
 				assert_ok!(EdgeConnectModule::register_worker(
@@ -89,14 +102,15 @@ fn on_new_data_works_as_expected() {
 					worker_longitude,
 					worker_ram,
 					worker_storage,
-					worker_cpu
+					worker_cpu,
+					1
 				));
 			}
 		}
 
 		// worker for which status is to be updated
 		let key_1: OracleWorkerFormat<AccountId> = OracleWorkerFormat {
-			id: (worker_addrs[0], worker_ids[0]),
+			id: (worker_addrs[0], nth_worker_id(worker_addrs[0], 0)),
 			worker_type: WorkerType::Docker,
 		};
 
@@ -190,11 +204,11 @@ fn on_new_data_works_as_expected() {
 
 		// 5. Allow oracle feeders to submit for a new workers
 		let key_2: OracleWorkerFormat<AccountId> = OracleWorkerFormat {
-			id: (worker_addrs[1], worker_ids[2]),
+			id: (worker_addrs[1], nth_worker_id(worker_addrs[1], 2)),
 			worker_type: WorkerType::Docker,
 		};
 		let key_3: OracleWorkerFormat<AccountId> = OracleWorkerFormat {
-			id: (worker_addrs[2], worker_ids[1]),
+			id: (worker_addrs[2], nth_worker_id(worker_addrs[2], 1)),
 			worker_type: WorkerType::Docker,
 		};
 
@@ -274,7 +288,9 @@ fn on_finalize_works_as_expected_for_docker_workers() {
 		// register workers
 		for worker in worker_addrs.iter() {
 			for id in 0..worker_ids.len() {
-				let domain_str = "some_api_domain.".to_owned() + &id.to_string() + ".com";
+				// domains are unique across all accounts
+				let domain_str =
+					"some_api_domain.".to_owned() + &worker.to_string() + "." + &id.to_string() + ".com";
 				let domain_vec = domain_str.as_bytes().to_vec();
 				let domain: BoundedVec<u8, ConstU32<128>> = BoundedVec::try_from(domain_vec).unwrap();
 				assert_ok!(EdgeConnectModule::register_worker(
@@ -285,18 +301,19 @@ fn on_finalize_works_as_expected_for_docker_workers() {
 					worker_longitude,
 					worker_ram,
 					worker_storage,
-					worker_cpu
+					worker_cpu,
+					1
 				));
 			}
 		}
 
 		// worker for which status is to be updated
 		let key_1: OracleWorkerFormat<AccountId> = OracleWorkerFormat {
-			id: (worker_addrs[0], worker_ids[0]),
+			id: (worker_addrs[0], nth_worker_id(worker_addrs[0], 0)),
 			worker_type: WorkerType::Docker,
 		};
 		let key_2: OracleWorkerFormat<AccountId> = OracleWorkerFormat {
-			id: (worker_addrs[1], worker_ids[0]),
+			id: (worker_addrs[1], nth_worker_id(worker_addrs[1], 0)),
 			worker_type: WorkerType::Docker,
 		};
 
@@ -556,7 +573,9 @@ fn on_finalize_works_as_expected_for_executable_workers() {
 		// register workers
 		for worker in worker_addrs.iter() {
 			for id in 0..worker_ids.len() {
-				let domain_str = "some_api_domain.".to_owned() + &id.to_string() + ".com";
+				// domains are unique across all accounts
+				let domain_str =
+					"some_api_domain.".to_owned() + &worker.to_string() + "." + &id.to_string() + ".com";
 				let domain_vec = domain_str.as_bytes().to_vec();
 				let domain: BoundedVec<u8, ConstU32<128>> = BoundedVec::try_from(domain_vec).unwrap();
 				assert_ok!(EdgeConnectModule::register_worker(
@@ -567,18 +586,19 @@ fn on_finalize_works_as_expected_for_executable_workers() {
 					worker_longitude,
 					worker_ram,
 					worker_storage,
-					worker_cpu
+					worker_cpu,
+					1
 				));
 			}
 		}
 
 		// worker for which status is to be updated
 		let key_1: OracleWorkerFormat<AccountId> = OracleWorkerFormat {
-			id: (worker_addrs[0], worker_ids[0]),
+			id: (worker_addrs[0], nth_worker_id(worker_addrs[0], 0)),
 			worker_type: WorkerType::Executable,
 		};
 		let key_2: OracleWorkerFormat<AccountId> = OracleWorkerFormat {
-			id: (worker_addrs[1], worker_ids[0]),
+			id: (worker_addrs[1], nth_worker_id(worker_addrs[1], 0)),
 			worker_type: WorkerType::Executable,
 		};
 
@@ -4,7 +4,12 @@ use super::*;
 use frame_benchmarking::v2::*;
 
 use cyborg_primitives::worker::*;
-use frame_support::{sp_runtime::traits::ConstU32, BoundedVec};
+use frame_support::{
+	sp_runtime::traits::{ConstU32, Hash, Saturating},
+	traits::{Currency, EnsureOrigin, Hooks},
+	weights::Weight,
+	BoundedVec,
+};
 use frame_system::RawOrigin;
 use scale_info::prelude::vec;
 
@@ -13,6 +18,8 @@ const WORKER_API_DOMAIN1: &str = "https://api-worker1.testing";
 const WORKER_API_DOMAIN2: &str = "https://api-worker2.testing";
 const WORKER_API_DOMAIN3: &str = "https://api-worker3.testing";
 const DOCKER_IMAGE_TESTDATA: &str = "some-docker-imgv.0";
+const WORKER_ENCRYPTION_KEY: EncryptionKey = [1u8; 32];
+const OWNER_ENCRYPTION_KEY: EncryptionKey = [2u8; 32];
 
 // Helper function to convert the domain string into a BoundedVec with a maximum length of 128 bytes.
 // Convert the domain string into a vector of bytes and then into a BoundedVec with a maximum length of 128 bytes.
@@ -67,12 +74,9 @@ fn set_initial_benchmark_data<T: Config>() {
 			cpu: 5u16,
 		};
 
-		// Maximum number of worker IDs to generate
+		// Number of workers to generate for each creator
 		const MAX_WORKER_ID: u64 = 6;
 
-		// Insert the MAX_WORKER_ID into the AccountWorkers map for the creator
-		pallet_edge_connect::AccountWorkers::<T>::insert(creator.clone(), MAX_WORKER_ID);
-
 		let worker_reputation = WorkerReputation {
 			score: 0,
 			last_updated: None,
@@ -81,7 +85,12 @@ fn set_initial_benchmark_data<T: Config>() {
 		};
 
 		// Loop to create multiple workers for the same creator
-		for worker_id in 0..MAX_WORKER_ID {
+		for _ in 0..MAX_WORKER_ID {
+			// Worker IDs are allocated globally, so the first creator owns workers 0 to 5
+			let worker_id = pallet_edge_connect::NextWorkerId::<T>::get();
+			pallet_edge_connect::NextWorkerId::<T>::put(worker_id + 1);
+			pallet_edge_connect::AccountWorkers::<T>::insert(creator.clone(), worker_id);
+
 			// Get the current block number
 			let blocknumber = <frame_system::Pallet<T>>::block_number();
 
@@ -91,6 +100,8 @@ fn set_initial_benchmark_data<T: Config>() {
 				owner: creator.clone(),
 				location: worker_location.clone(),
 				specs: worker_specs.clone(),
+				max_concurrent_tasks: 100,
+				encryption_key: None,
 				reputation: worker_reputation,
 				start_block: blocknumber,
 				status: WorkerStatusType::Inactive,
@@ -108,6 +119,220 @@ fn set_initial_benchmark_data<T: Config>() {
 	}
 }
 
+/// Schedules an inference task and puts it straight into `status`, as if the executor had
+/// submitted `result` and `caller` had been assigned as the verifier or resolver.
+fn set_verification_benchmark_data<T: Config>(
+	caller: T::AccountId,
+	result: &BoundedVec<u8, ConstU32<500>>,
+	status: TaskStatusType,
+) -> Result<TaskId, BenchmarkError>
+where
+	<<T as pallet_payment::Config>::Currency as frame_support::traits::Currency<
+		<T as frame_system::Config>::AccountId,
+	>>::Balance: TryFrom<u64>,
+{
+	set_initial_benchmark_data::<T>();
+	let executor = account::<T::AccountId>("benchmark_account", 0, 0);
+
+	// Inference tasks run on executable workers in good standing
+	let worker = pallet_edge_connect::WorkerClusters::<T>::get((executor.clone(), 0))
+		.ok_or(BenchmarkError::Stop("Missing benchmark worker"))?;
+	pallet_edge_connect::ExecutableWorkers::<T>::insert(
+		(executor.clone(), 0),
+		Worker {
+			reputation: Default::default(),
+			..worker
+		},
+	);
+
+	pallet_payment::ComputeHours::<T>::insert(caller.clone(), 100);
+	GatekeeperAccount::<T>::put(caller.clone());
+	Pallet::<T>::task_scheduler(
+		RawOrigin::Signed(caller.clone()).into(),
+		TaskKind::OpenInference,
+		get_taskdata(DOCKER_IMAGE_TESTDATA),
+		None,
+		Some((executor.clone(), 0)),
+		Some(5),
+		TaskOptions::default(),
+	)
+	.map_err(|_| BenchmarkError::Stop("Failed to schedule task"))?;
+	let task_id = NextTaskId::<T>::get() - 1;
+
+	let executor_hash = T::Hashing::hash(result);
+	let other_hash = T::Hashing::hash(b"other result");
+	let (verifier, resolver) = match status {
+		TaskStatusType::PendingResolution => (
+			VerificationHashes {
+				worker: (executor.clone(), 1),
+				completed_hash: Some(other_hash),
+				result: None,
+			},
+			Some(VerificationHashes::assigned((caller, 0))),
+		),
+		_ => (VerificationHashes::assigned((caller, 0)), None),
+	};
+	TaskVerifications::<T>::insert(
+		task_id,
+		Verifications {
+			executor: VerificationHashes {
+				worker: (executor, 0),
+				completed_hash: Some(executor_hash),
+				result: Some(result.clone()),
+			},
+			verifier: Some(verifier),
+			resolver,
+		},
+	);
+
+	Tasks::<T>::mutate(task_id, |task| {
+		if let Some(task) = task {
+			task.task_status = status.clone();
+			task.result = Some(result.clone());
+		}
+	});
+	TaskStatus::<T>::insert(task_id, status);
+
+	Ok(task_id)
+}
+
+/// Gives `who` enough funds to reserve the deposit of a model with two versions.
+fn fund_model_deposit<T: Config>(who: &T::AccountId) {
+	let deposit = T::ModelDeposit::get()
+		.saturating_add(T::ModelVersionDeposit::get())
+		.saturating_add(T::ModelVersionDeposit::get());
+	<T as pallet_payment::Config>::Currency::make_free_balance_be(
+		who,
+		<T as pallet_payment::Config>::Currency::minimum_balance().saturating_add(deposit),
+	);
+}
+
+/// Registers a model of `owner` with the longest metadata and one version, and returns its id.
+fn register_benchmark_model<T: Config>(owner: T::AccountId) -> Result<ModelId, BenchmarkError>
+where
+	<<T as pallet_payment::Config>::Currency as frame_support::traits::Currency<
+		<T as frame_system::Config>::AccountId,
+	>>::Balance: TryFrom<u64>,
+{
+	fund_model_deposit::<T>(&owner);
+	Pallet::<T>::register_model(
+		RawOrigin::Signed(owner.clone()).into(),
+		BoundedVec::truncate_from(vec![b'm'; 128]),
+		BoundedVec::truncate_from(vec![b'f'; 64]),
+		BoundedVec::truncate_from(vec![b'l'; 256]),
+	)?;
+	let model_id = NextModelId::<T>::get() - 1;
+	Pallet::<T>::add_model_version(
+		RawOrigin::Signed(owner).into(),
+		model_id,
+		T::Hashing::hash(b"model.bin"),
+		1_000_000,
+	)?;
+	Ok(model_id)
+}
+
+/// Completes an inference task of `caller` with the longest result and opens its dispute
+/// window, as if `T::DisputeWindow` had not passed yet. Every benchmark account gets an active
+/// executable worker that can be picked to re-run the task, and `caller` the funds for the bond.
+fn set_dispute_benchmark_data<T: Config>(caller: T::AccountId) -> Result<TaskId, BenchmarkError>
+where
+	<<T as pallet_payment::Config>::Currency as frame_support::traits::Currency<
+		<T as frame_system::Config>::AccountId,
+	>>::Balance: TryFrom<u64>,
+{
+	set_initial_benchmark_data::<T>();
+	for (worker_key, worker) in pallet_edge_connect::WorkerClusters::<T>::iter() {
+		if worker_key.1 == 0 {
+			pallet_edge_connect::Pallet::<T>::store_worker(
+				&worker_key,
+				&WorkerType::Executable,
+				Worker {
+					status: WorkerStatusType::Active,
+					reputation: Default::default(),
+					..worker
+				},
+			);
+		}
+	}
+	let executor = account::<T::AccountId>("benchmark_account", 0, 0);
+
+	let funds = pallet_payment::BalanceOf::<T>::try_from(1_000_000_000_000_000_000u64)
+		.map_err(|_| BenchmarkError::Stop("Balance out of range"))?;
+	let provider = account::<T::AccountId>("provider", 0, 0);
+	<T as pallet_payment::Config>::Currency::make_free_balance_be(&provider, funds);
+	<T as pallet_payment::Config>::Currency::make_free_balance_be(&caller, funds);
+	pallet_payment::ServiceProviderAccount::<T>::put(provider);
+
+	pallet_payment::ComputeHours::<T>::insert(caller.clone(), 100);
+	GatekeeperAccount::<T>::put(caller.clone());
+	Pallet::<T>::task_scheduler(
+		RawOrigin::Signed(caller.clone()).into(),
+		TaskKind::OpenInference,
+		get_taskdata(DOCKER_IMAGE_TESTDATA),
+		None,
+		Some((executor.clone(), 0)),
+		Some(5),
+		TaskOptions::default(),
+	)
+	.map_err(|_| BenchmarkError::Stop("Failed to schedule task"))?;
+	let task_id = NextTaskId::<T>::get() - 1;
+
+	Tasks::<T>::mutate(task_id, |task| {
+		if let Some(task) = task {
+			task.task_status = TaskStatusType::Completed;
+			task.result = Some(BoundedVec::truncate_from(vec![1u8; 500]));
+			task.consume_compute_hours = Some(5);
+		}
+	});
+	TaskStatus::<T>::insert(task_id, TaskStatusType::Completed);
+	DisputableResults::<T>::insert(task_id, (executor, 0));
+
+	Ok(task_id)
+}
+
+/// Gives the worker `(caller, 1)` an encryption key and pins a confidential inference task of
+/// `caller` to it.
+fn schedule_confidential_benchmark_task<T: Config>(
+	caller: T::AccountId,
+) -> Result<TaskId, BenchmarkError>
+where
+	<<T as pallet_payment::Config>::Currency as frame_support::traits::Currency<
+		<T as frame_system::Config>::AccountId,
+	>>::Balance: TryFrom<u64>,
+{
+	pallet_payment::ComputeHours::<T>::insert(caller.clone(), 100);
+	GatekeeperAccount::<T>::put(caller.clone());
+	pallet_edge_connect::ExecutableWorkers::<T>::mutate((caller.clone(), 1), |worker| {
+		if let Some(worker) = worker {
+			worker.encryption_key = Some(WORKER_ENCRYPTION_KEY);
+		}
+	});
+
+	Pallet::<T>::task_scheduler(
+		RawOrigin::Signed(caller.clone()).into(),
+		TaskKind::OpenInference,
+		BoundedVec::new(),
+		None,
+		Some((caller, 1)),
+		Some(5),
+		TaskOptions {
+			confidential: Some(OWNER_ENCRYPTION_KEY),
+			..Default::default()
+		},
+	)?;
+	Ok(NextTaskId::<T>::get() - 1)
+}
+
+/// A payload of the largest size, encrypted to `recipient`.
+fn benchmark_encrypted_payload(recipient: EncryptionKey) -> EncryptedPayload {
+	EncryptedPayload {
+		recipient,
+		ephemeral_key: [7u8; 32],
+		nonce: [0u8; 24],
+		ciphertext: BoundedVec::try_from(vec![1u8; 1024]).expect("Ciphertext within bounds"),
+	}
+}
+
 #[benchmarks]
 mod benchmarks {
 	use super::*;
@@ -132,6 +357,9 @@ mod benchmarks {
 		// This ensures the account has sufficient compute hours for task operations during benchmarking.
 		pallet_payment::ComputeHours::<T>::insert(caller.clone(), 50);
 
+		// Pinning a worker is reserved to the gatekeeper.
+		GatekeeperAccount::<T>::put(caller.clone());
+
 		#[block]
 		{
 			Pallet::<T>::task_scheduler(
@@ -139,9 +367,9 @@ mod benchmarks {
 				TaskKind::OpenInference,
 				task_data,
 				None,
-				worker_account,
-				worker_id,
+				Some((worker_account, worker_id)),
 				Some(10),
+				TaskOptions::default(),
 			)
 			.expect("Failed to schedule task");
 		}
@@ -182,6 +410,9 @@ mod benchmarks {
 		// This ensures the account has sufficient compute hours for task operations during benchmarking.
 		pallet_payment::ComputeHours::<T>::insert(caller.clone(), 50);
 
+		// Pinning a worker is reserved to the gatekeeper.
+		GatekeeperAccount::<T>::put(caller.clone());
+
 		let dummy_bytes = vec![1u8; 1_000_000]; // 1MB each
 		let nzk_info = Some(NeuroZkTaskSubmissionDetails {
 			zk_input: BoundedVec::try_from(dummy_bytes.clone()).unwrap(),
@@ -196,9 +427,9 @@ mod benchmarks {
 				TaskKind::NeuroZK,
 				task_data,
 				nzk_info,
-				worker_account,
-				worker_id,
+				Some((worker_account, worker_id)),
 				Some(10),
+				TaskOptions::default(),
 			)
 			.expect("Failed to schedule task");
 		}
@@ -226,15 +457,16 @@ mod benchmarks {
 		let caller: T::AccountId = whitelisted_caller();
 
 		pallet_payment::ComputeHours::<T>::insert(caller.clone(), 100);
+		GatekeeperAccount::<T>::put(caller.clone());
 		let task_data = get_taskdata(DOCKER_IMAGE_TESTDATA);
 		Pallet::<T>::task_scheduler(
 			RawOrigin::Signed(caller.clone()).into(),
 			TaskKind::OpenInference,
 			task_data.clone(),
 			None,
-			caller.clone(),
-			1,
+			Some((caller.clone(), 1)),
 			Some(5),
+			TaskOptions::default(),
 		)?;
 		let task_id = NextTaskId::<T>::get() - 1;
 
@@ -252,21 +484,82 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn cancel_task<T: Config>() -> Result<(), BenchmarkError> {
+		set_initial_benchmark_data::<T>();
+		let caller: T::AccountId = whitelisted_caller();
+
+		pallet_payment::ComputeHours::<T>::insert(caller.clone(), 100);
+		GatekeeperAccount::<T>::put(caller.clone());
+		Pallet::<T>::task_scheduler(
+			RawOrigin::Signed(caller.clone()).into(),
+			TaskKind::OpenInference,
+			get_taskdata(DOCKER_IMAGE_TESTDATA),
+			None,
+			Some((caller.clone(), 1)),
+			Some(5),
+			TaskOptions::default(),
+		)?;
+		let task_id = NextTaskId::<T>::get() - 1;
+
+		Pallet::<T>::confirm_task_reception(RawOrigin::Signed(caller.clone()).into(), task_id)?;
+
+		#[block]
+		{
+			Pallet::<T>::cancel_task(RawOrigin::Signed(caller.clone()).into(), task_id)?;
+		}
+
+		assert_eq!(TaskStatus::<T>::get(task_id), Some(TaskStatusType::Stopped));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn submit_task_graph<T: Config>(
+		n: Linear<1, { T::MaxGraphNodes::get() }>,
+	) -> Result<(), BenchmarkError> {
+		set_initial_benchmark_data::<T>();
+		let caller: T::AccountId = whitelisted_caller();
+
+		pallet_payment::ComputeHours::<T>::insert(caller.clone(), 10 * n);
+		GatekeeperAccount::<T>::put(caller.clone());
+
+		// A chain of tasks, each depending on the previous one
+		let nodes: Vec<TaskGraphNode> = (0..n)
+			.map(|index| TaskGraphNode {
+				task_kind: TaskKind::OpenInference,
+				task_location: get_taskdata(DOCKER_IMAGE_TESTDATA),
+				nzk_info: None,
+				requirements: None,
+				compute_hours_deposit: 5,
+				parents: BoundedVec::truncate_from(index.checked_sub(1).into_iter().collect()),
+			})
+			.collect();
+		let nodes = BoundedVec::truncate_from(nodes);
+		let first_task_id = NextTaskId::<T>::get();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), nodes);
+
+		assert_eq!(NextTaskId::<T>::get(), first_task_id + n as TaskId);
+		Ok(())
+	}
+
 	#[benchmark]
 	fn confirm_miner_vacation<T: Config>() -> Result<(), BenchmarkError> {
 		set_initial_benchmark_data::<T>();
 		let caller: T::AccountId = whitelisted_caller();
 
 		pallet_payment::ComputeHours::<T>::insert(caller.clone(), 100);
+		GatekeeperAccount::<T>::put(caller.clone());
 		let task_data = get_taskdata(DOCKER_IMAGE_TESTDATA);
 		Pallet::<T>::task_scheduler(
 			RawOrigin::Signed(caller.clone()).into(),
 			TaskKind::OpenInference,
 			task_data.clone(),
 			None,
-			caller.clone(),
-			1,
+			Some((caller.clone(), 1)),
 			Some(5),
+			TaskOptions::default(),
 		)?;
 		let task_id = NextTaskId::<T>::get() - 1;
 
@@ -282,6 +575,561 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn submit_task_result<T: Config>(s: Linear<0, 500>) -> Result<(), BenchmarkError> {
+		set_initial_benchmark_data::<T>();
+		let caller: T::AccountId = whitelisted_caller();
+
+		pallet_payment::ComputeHours::<T>::insert(caller.clone(), 100);
+		GatekeeperAccount::<T>::put(caller.clone());
+		let task_data = get_taskdata(DOCKER_IMAGE_TESTDATA);
+		Pallet::<T>::task_scheduler(
+			RawOrigin::Signed(caller.clone()).into(),
+			TaskKind::OpenInference,
+			task_data.clone(),
+			None,
+			Some((caller.clone(), 1)),
+			Some(5),
+			TaskOptions::default(),
+		)?;
+		let task_id = NextTaskId::<T>::get() - 1;
+
+		Pallet::<T>::confirm_task_reception(RawOrigin::Signed(caller.clone()).into(), task_id)?;
+
+		let result: BoundedVec<u8, ConstU32<500>> =
+			BoundedVec::try_from(vec![1u8; s as usize]).expect("Result within bounds");
+
+		#[block]
+		{
+			Pallet::<T>::submit_task_result(
+				RawOrigin::Signed(caller.clone()).into(),
+				task_id,
+				result,
+				Some(50),
+			)?;
+		}
+
+		assert_eq!(TaskStatus::<T>::get(task_id), Some(TaskStatusType::Completed));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn submit_task_payload<T: Config>() -> Result<(), BenchmarkError> {
+		set_initial_benchmark_data::<T>();
+		let caller: T::AccountId = whitelisted_caller();
+		let task_id = schedule_confidential_benchmark_task::<T>(caller.clone())?;
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			task_id,
+			benchmark_encrypted_payload(WORKER_ENCRYPTION_KEY),
+		);
+
+		assert!(TaskPayloads::<T>::contains_key(task_id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn submit_encrypted_task_result<T: Config>() -> Result<(), BenchmarkError> {
+		set_initial_benchmark_data::<T>();
+		let caller: T::AccountId = whitelisted_caller();
+		let task_id = schedule_confidential_benchmark_task::<T>(caller.clone())?;
+
+		Pallet::<T>::submit_task_payload(
+			RawOrigin::Signed(caller.clone()).into(),
+			task_id,
+			benchmark_encrypted_payload(WORKER_ENCRYPTION_KEY),
+		)?;
+		Pallet::<T>::confirm_task_reception(RawOrigin::Signed(caller.clone()).into(), task_id)?;
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			task_id,
+			benchmark_encrypted_payload(OWNER_ENCRYPTION_KEY),
+			Some(50),
+		);
+
+		assert_eq!(
+			TaskStatus::<T>::get(task_id),
+			Some(TaskStatusType::Completed)
+		);
+		assert!(EncryptedResults::<T>::contains_key(task_id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn verify_completed_task<T: Config>(s: Linear<0, 500>) -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let result: BoundedVec<u8, ConstU32<500>> =
+			BoundedVec::try_from(vec![1u8; s as usize]).expect("Result within bounds");
+		let task_id = set_verification_benchmark_data::<T>(
+			caller.clone(),
+			&result,
+			TaskStatusType::PendingValidation,
+		)?;
+
+		#[block]
+		{
+			Pallet::<T>::verify_completed_task(
+				RawOrigin::Signed(caller.clone()).into(),
+				task_id,
+				result,
+			)?;
+		}
+
+		assert_eq!(TaskStatus::<T>::get(task_id), Some(TaskStatusType::Completed));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn resolve_completed_task<T: Config>(s: Linear<0, 500>) -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let result: BoundedVec<u8, ConstU32<500>> =
+			BoundedVec::try_from(vec![1u8; s as usize]).expect("Result within bounds");
+		let task_id = set_verification_benchmark_data::<T>(
+			caller.clone(),
+			&result,
+			TaskStatusType::PendingResolution,
+		)?;
+
+		#[block]
+		{
+			Pallet::<T>::resolve_completed_task(
+				RawOrigin::Signed(caller.clone()).into(),
+				task_id,
+				result,
+			)?;
+		}
+
+		assert_eq!(TaskStatus::<T>::get(task_id), Some(TaskStatusType::Completed));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn expire_task<T: Config>() -> Result<(), BenchmarkError> {
+		set_initial_benchmark_data::<T>();
+		let caller: T::AccountId = whitelisted_caller();
+
+		pallet_payment::ComputeHours::<T>::insert(caller.clone(), 100);
+		GatekeeperAccount::<T>::put(caller.clone());
+		Pallet::<T>::task_scheduler(
+			RawOrigin::Signed(caller.clone()).into(),
+			TaskKind::OpenInference,
+			get_taskdata(DOCKER_IMAGE_TESTDATA),
+			None,
+			Some((caller.clone(), 1)),
+			Some(5),
+			TaskOptions::default(),
+		)?;
+		let task_id = NextTaskId::<T>::get() - 1;
+		let deadline = TaskDeadlines::<T>::get(task_id)
+			.ok_or(BenchmarkError::Stop("Missing task deadline"))?;
+
+		#[block]
+		{
+			Pallet::<T>::on_initialize(deadline);
+		}
+
+		assert_ne!(TaskDeadlines::<T>::get(task_id), Some(deadline));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn schedule_task_at<T: Config>(s: Linear<0, 500>) -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+
+		pallet_payment::ComputeHours::<T>::insert(caller.clone(), 100);
+		let task_location = BoundedVec::truncate_from(vec![0u8; s as usize]);
+		let start = frame_system::Pallet::<T>::block_number() + 10u32.into();
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			TaskKind::OpenInference,
+			task_location,
+			None,
+			None,
+			5,
+			start,
+			Some(10u32.into()),
+			10,
+		);
+
+		assert!(ScheduledTasks::<T>::contains_key(
+			NextScheduleId::<T>::get() - 1
+		));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_scheduled_task<T: Config>() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+
+		pallet_payment::ComputeHours::<T>::insert(caller.clone(), 100);
+		Pallet::<T>::schedule_task_at(
+			RawOrigin::Signed(caller.clone()).into(),
+			TaskKind::OpenInference,
+			get_taskdata(DOCKER_IMAGE_TESTDATA),
+			None,
+			None,
+			5,
+			frame_system::Pallet::<T>::block_number() + 10u32.into(),
+			Some(10u32.into()),
+			10,
+		)?;
+		let schedule_id = NextScheduleId::<T>::get() - 1;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), schedule_id);
+
+		assert!(!ScheduledTasks::<T>::contains_key(schedule_id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn start_scheduled_task<T: Config>() -> Result<(), BenchmarkError> {
+		set_initial_benchmark_data::<T>();
+		let caller: T::AccountId = whitelisted_caller();
+
+		pallet_payment::ComputeHours::<T>::insert(caller.clone(), 100);
+		let start = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		Pallet::<T>::schedule_task_at(
+			RawOrigin::Signed(caller.clone()).into(),
+			TaskKind::OpenInference,
+			get_taskdata(DOCKER_IMAGE_TESTDATA),
+			None,
+			None,
+			5,
+			start,
+			Some(10u32.into()),
+			10,
+		)?;
+		let task_id = NextTaskId::<T>::get();
+
+		#[block]
+		{
+			Pallet::<T>::on_initialize(start);
+		}
+
+		assert!(Tasks::<T>::contains_key(task_id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn prune_task<T: Config>() -> Result<(), BenchmarkError> {
+		set_initial_benchmark_data::<T>();
+		let caller: T::AccountId = whitelisted_caller();
+
+		pallet_payment::ComputeHours::<T>::insert(caller.clone(), 100);
+		GatekeeperAccount::<T>::put(caller.clone());
+		Pallet::<T>::task_scheduler(
+			RawOrigin::Signed(caller.clone()).into(),
+			TaskKind::OpenInference,
+			get_taskdata(DOCKER_IMAGE_TESTDATA),
+			None,
+			Some((caller.clone(), 1)),
+			Some(5),
+			TaskOptions::default(),
+		)?;
+		let task_id = NextTaskId::<T>::get() - 1;
+		Pallet::<T>::confirm_task_reception(RawOrigin::Signed(caller.clone()).into(), task_id)?;
+		Pallet::<T>::stop_task_and_vacate_miner(RawOrigin::Signed(caller.clone()).into(), task_id)?;
+		Pallet::<T>::confirm_miner_vacation(RawOrigin::Signed(caller.clone()).into(), task_id)?;
+
+		let now = frame_system::Pallet::<T>::block_number() + T::TaskRetentionPeriod::get();
+
+		#[block]
+		{
+			Pallet::<T>::on_idle(now, Weight::MAX);
+		}
+
+		assert!(TaskSummaries::<T>::contains_key(task_id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn register_model<T: Config>() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_model_deposit::<T>(&caller);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			BoundedVec::truncate_from(vec![b'm'; 128]),
+			BoundedVec::truncate_from(vec![b'f'; 64]),
+			BoundedVec::truncate_from(vec![b'l'; 256]),
+		);
+
+		assert!(Models::<T>::contains_key(NextModelId::<T>::get() - 1));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn add_model_version<T: Config>() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let model_id = register_benchmark_model::<T>(caller.clone())?;
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			model_id,
+			T::Hashing::hash(b"model-v1.bin"),
+			1_000_000,
+		);
+
+		assert!(ModelVersions::<T>::contains_key(model_id, 1));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn deprecate_model_version<T: Config>() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = account("model_owner", 0, 0);
+		let caller: T::AccountId = whitelisted_caller();
+		let model_id = register_benchmark_model::<T>(owner)?;
+		GatekeeperAccount::<T>::put(caller.clone());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), model_id, 0);
+
+		assert!(ModelVersions::<T>::get(model_id, 0).is_some_and(|version| version.deprecated));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn transfer_model<T: Config>() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let new_owner: T::AccountId = account("model_owner", 0, 0);
+		let model_id = register_benchmark_model::<T>(caller.clone())?;
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			model_id,
+			new_owner.clone(),
+		);
+
+		assert_eq!(ModelTransferOffers::<T>::get(model_id), Some(new_owner));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn accept_model_transfer<T: Config>() -> Result<(), BenchmarkError> {
+		let owner: T::AccountId = account("model_owner", 0, 0);
+		let caller: T::AccountId = whitelisted_caller();
+		let model_id = register_benchmark_model::<T>(owner.clone())?;
+		Pallet::<T>::transfer_model(RawOrigin::Signed(owner).into(), model_id, caller.clone())?;
+		fund_model_deposit::<T>(&caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), model_id);
+
+		assert_eq!(
+			Models::<T>::get(model_id).map(|model| model.owner),
+			Some(caller)
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_model_version<T: Config>() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let model_id = register_benchmark_model::<T>(caller.clone())?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), model_id, 0);
+
+		assert!(!ModelVersions::<T>::contains_key(model_id, 0));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_model<T: Config>() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let model_id = register_benchmark_model::<T>(caller.clone())?;
+		Pallet::<T>::remove_model_version(RawOrigin::Signed(caller.clone()).into(), model_id, 0)?;
+		Pallet::<T>::transfer_model(
+			RawOrigin::Signed(caller.clone()).into(),
+			model_id,
+			account("model_owner", 0, 0),
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), model_id);
+
+		assert!(!Models::<T>::contains_key(model_id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn dispute_task_result<T: Config>() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let task_id = set_dispute_benchmark_data::<T>(caller.clone())?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), task_id);
+
+		assert_eq!(
+			TaskStatus::<T>::get(task_id),
+			Some(TaskStatusType::Disputed)
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn submit_dispute_verification<T: Config>(s: Linear<0, 500>) -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let task_id = set_dispute_benchmark_data::<T>(caller.clone())?;
+		Pallet::<T>::dispute_task_result(RawOrigin::Signed(caller.clone()).into(), task_id)?;
+		let verifier = TaskDisputes::<T>::get(task_id)
+			.and_then(|dispute| dispute.verifier)
+			.ok_or(BenchmarkError::Stop("Missing dispute verifier"))?;
+
+		// A different result puts the worker at fault, which refunds the owner
+		let result: BoundedVec<u8, ConstU32<500>> =
+			BoundedVec::try_from(vec![2u8; s as usize]).expect("Result within bounds");
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(verifier.0), task_id, result);
+
+		assert_eq!(TaskStatus::<T>::get(task_id), Some(TaskStatusType::Failed));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn resolve_dispute<T: Config>() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let task_id = set_dispute_benchmark_data::<T>(caller.clone())?;
+		Pallet::<T>::dispute_task_result(RawOrigin::Signed(caller.clone()).into(), task_id)?;
+		let origin =
+			T::DisputeOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, task_id, true);
+
+		assert_eq!(TaskStatus::<T>::get(task_id), Some(TaskStatusType::Failed));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn confirm_task_reception<T: Config>() -> Result<(), BenchmarkError> {
+		set_initial_benchmark_data::<T>();
+		let caller: T::AccountId = whitelisted_caller();
+
+		pallet_payment::ComputeHours::<T>::insert(caller.clone(), 100);
+		GatekeeperAccount::<T>::put(caller.clone());
+		Pallet::<T>::task_scheduler(
+			RawOrigin::Signed(caller.clone()).into(),
+			TaskKind::OpenInference,
+			get_taskdata(DOCKER_IMAGE_TESTDATA),
+			None,
+			Some((caller.clone(), 1)),
+			Some(5),
+			TaskOptions::default(),
+		)?;
+		let task_id = NextTaskId::<T>::get() - 1;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), task_id);
+
+		assert_eq!(TaskStatus::<T>::get(task_id), Some(TaskStatusType::Running));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn register_model_hash<T: Config>() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		GatekeeperAccount::<T>::put(caller.clone());
+		let model_id = [3u8; 32];
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			model_id.to_vec(),
+			T::Hashing::hash(b"model.bin"),
+		);
+
+		assert!(ModelHashes::<T>::contains_key(model_id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn get_model_hash<T: Config>() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let model_id = [3u8; 32];
+		ModelHashes::<T>::insert(model_id, T::Hashing::hash(b"model.bin"));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), model_id.to_vec());
+
+		Ok(())
+	}
+
+	/// Handing a queued task to a worker that became available, as done for every task taken by
+	/// `on_worker_active` and by the calls that free a worker. In the worst case every queue the
+	/// worker can take a task from is full, with confidential tasks it cannot run above the
+	/// lowest priority, and every task of the lowest priority is compared.
+	#[benchmark(pov_mode = Measured)]
+	fn dispatch_pending_task<T: Config>() -> Result<(), BenchmarkError> {
+		set_initial_benchmark_data::<T>();
+		let caller: T::AccountId = whitelisted_caller();
+
+		// No executable worker is registered yet, so the task is queued
+		pallet_payment::ComputeHours::<T>::insert(caller.clone(), 100);
+		GatekeeperAccount::<T>::put(caller.clone());
+		Pallet::<T>::task_scheduler(
+			RawOrigin::Signed(caller.clone()).into(),
+			TaskKind::OpenInference,
+			get_taskdata(DOCKER_IMAGE_TESTDATA),
+			None,
+			None,
+			Some(5),
+			TaskOptions {
+				priority: TaskPriority::Low,
+				..Default::default()
+			},
+		)?;
+		let task_id = NextTaskId::<T>::get() - 1;
+
+		// The queued task is the oldest one, so it is picked among the tasks of its priority
+		let mut filler = task_id + 1;
+		for task_class in [TaskClass::NeuroZK, TaskClass::OpenInference] {
+			for priority in TaskPriority::DESCENDING {
+				PendingTasks::<T>::mutate(task_class, priority, |queue| {
+					while queue.try_push(filler).is_ok() {
+						TaskRequirements::<T>::insert(filler, ResourceRequirements::default());
+						if priority != TaskPriority::Low {
+							ConfidentialTasks::<T>::insert(filler, OWNER_ENCRYPTION_KEY);
+						}
+						filler += 1;
+					}
+				});
+			}
+		}
+
+		let worker_key = (account::<T::AccountId>("benchmark_account", 0, 0), 0);
+		let worker = pallet_edge_connect::WorkerClusters::<T>::get(&worker_key)
+			.ok_or(BenchmarkError::Stop("Missing benchmark worker"))?;
+		pallet_edge_connect::Pallet::<T>::store_worker(
+			&worker_key,
+			&WorkerType::Executable,
+			Worker {
+				status: WorkerStatusType::Active,
+				reputation: Default::default(),
+				..worker
+			},
+		);
+
+		#[block]
+		{
+			Pallet::<T>::dispatch_pending_task(&worker_key, &WorkerType::Executable);
+		}
+
+		assert_eq!(TaskAllocations::<T>::get(task_id), Some(worker_key));
+		Ok(())
+	}
+
 	#[benchmark]
 	fn set_gatekeeper<T: Config>() -> Result<(), BenchmarkError> {
 		let caller: T::AccountId = whitelisted_caller();
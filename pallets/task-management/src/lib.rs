@@ -11,24 +11,42 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migrations;
 pub mod weights;
 pub use weights::*;
 
+pub use cyborg_primitives::model::*;
 pub use cyborg_primitives::task::*;
 use cyborg_primitives::worker::WorkerId;
-use cyborg_primitives::worker::WorkerType;
+use cyborg_primitives::worker::{
+	EncryptionKey, Worker, WorkerStatusHandler, WorkerStatusType, WorkerType,
+};
 use frame_support::{pallet_prelude::ConstU32, BoundedVec};
 
-use pallet_edge_connect::ExecutableWorkers;
+use pallet_edge_connect::{ExecutableWorkers, WorkerClusters};
 use scale_info::prelude::vec::Vec;
 
+/// Most tasks returned by a single page of [`Pallet::tasks_by_owner`] or
+/// [`Pallet::tasks_by_worker`], whatever `limit` is asked for.
+pub const MAX_TASKS_PER_QUERY: u32 = 100;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use frame_support::dispatch::PostDispatchInfo;
-	use frame_support::traits::Currency;
-	use frame_support::{dispatch::DispatchResult, pallet_prelude::*};
+	use frame_support::traits::{Currency, Randomness, ReservableCurrency};
+	use frame_support::{
+		dispatch::DispatchResult,
+		pallet_prelude::*,
+		sp_runtime::{
+			traits::{Hash, One, Zero},
+			ArithmeticError, SaturatedConversion,
+		},
+	};
 	use frame_system::pallet_prelude::{OriginFor, *};
+	use core::cmp::Reverse;
+	use pallet_edge_connect::PenaltyReason;
+	use pallet_payment::BalanceOf;
 	use pallet_timestamp as timestamp;
 	// use pallet_edge_connect::AccountWorkers;
 
@@ -43,9 +61,111 @@ pub mod pallet {
 
 		/// A type representing the weights required by the dispatchables of this pallet.
 		type WeightInfo: WeightInfo;
+
+		/// Source of randomness used to pick a worker among the eligible candidates when a task
+		/// is scheduled without a pinned worker.
+		type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
+
+		/// Number of blocks in one compute hour, used to convert the blocks a task ran for into
+		/// the compute hours it consumed.
+		#[pallet::constant]
+		type BlocksPerHour: Get<BlockNumberFor<Self>>;
+
+		/// Reputation deducted from a worker whose result was outvoted during verification.
+		#[pallet::constant]
+		type FalseCompletionPenalty: Get<i32>;
+
+		/// Number of blocks an assigned worker has to confirm the reception of a task before the
+		/// task is handed to another worker.
+		#[pallet::constant]
+		type ReceptionTimeout: Get<BlockNumberFor<Self>>;
+
+		/// Compute hours charged, at most the deposit, for cancelling a task whose worker has not
+		/// confirmed its reception yet. Keeps owners from cancelling and resubmitting a task for
+		/// free until it gets the worker they want.
+		#[pallet::constant]
+		type CancellationCharge: Get<u32>;
+
+		/// Maximum number of blocks a worker has to submit the result of a task it started running
+		/// before the task is handed to another worker. Tasks get as many blocks as their compute
+		/// hours deposit pays for, up to this cap.
+		#[pallet::constant]
+		type ExecutionTimeout: Get<BlockNumberFor<Self>>;
+
+		/// Reputation deducted from a worker that let one of its tasks time out.
+		#[pallet::constant]
+		type LateResponsePenalty: Get<i32>;
+
+		/// Maximum number of task deadlines that can expire in a single block. Deadlines that do
+		/// not fit are pushed to the following blocks.
+		#[pallet::constant]
+		type MaxExpiringTasksPerBlock: Get<u32>;
+
+		/// Length in blocks of the window over which task submissions of an account are counted.
+		#[pallet::constant]
+		type RateLimitWindow: Get<BlockNumberFor<Self>>;
+
+		/// Maximum number of tasks an account other than the gatekeeper can schedule within one
+		/// rate limit window.
+		#[pallet::constant]
+		type MaxTasksPerWindow: Get<u32>;
+
+		/// Maximum number of tasks of one kind and priority waiting for a worker.
+		#[pallet::constant]
+		type MaxPendingTasks: Get<u32>;
+
+		/// Maximum number of queued tasks handed to a worker at once, when it becomes available
+		/// or frees capacity. Bounds the weight of every call that can free a worker.
+		#[pallet::constant]
+		type MaxTasksPerDispatch: Get<u32>;
+
+		/// Maximum number of tasks in a task graph.
+		#[pallet::constant]
+		type MaxGraphNodes: Get<u32>;
+
+		/// Maximum number of scheduled tasks that can start in a single block. Runs that do not
+		/// fit are pushed to the following blocks.
+		#[pallet::constant]
+		type MaxScheduledTasksPerBlock: Get<u32>;
+
+		/// Number of blocks a finished task is kept in full before `on_idle` prunes it, leaving a
+		/// `TaskSummary` behind.
+		#[pallet::constant]
+		type TaskRetentionPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Number of blocks after a task completed during which its owner can dispute the result.
+		/// The tip of the task is only paid to the worker once the window closed. Tasks are not
+		/// pruned while their result can still be disputed.
+		#[pallet::constant]
+		type DisputeWindow: Get<BlockNumberFor<Self>>;
+
+		/// Bond the owner of a task puts up to dispute its result, forfeited if the result is
+		/// upheld.
+		#[pallet::constant]
+		type DisputeBond: Get<BalanceOf<Self>>;
+
+		/// Origin that can decide a dispute, e.g. root or a council.
+		type DisputeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Deposit reserved from the owner of a model when it is registered.
+		#[pallet::constant]
+		type ModelDeposit: Get<BalanceOf<Self>>;
+
+		/// Deposit reserved from the owner of a model for every version added to it.
+		#[pallet::constant]
+		type ModelVersionDeposit: Get<BalanceOf<Self>>;
+
+		/// Maximum number of active workers considered when picking the worker of a task. Bounds
+		/// the weight of every call that picks a worker, whatever the number of workers.
+		#[pallet::constant]
+		type MaxWorkerCandidates: Get<u32>;
 	}
 
+	/// The in-code storage version, see [`crate::migrations`].
+	pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// Status of tasks within the system.
@@ -70,15 +190,144 @@ pub mod pallet {
 	pub type Tasks<T: Config> =
 		StorageMap<_, Identity, TaskId, TaskInfo<T::AccountId, BlockNumberFor<T>>, OptionQuery>;
 
+	/// Hardware requirements the task owner set for a task.
+	#[pallet::storage]
+	pub type TaskRequirements<T: Config> =
+		StorageMap<_, Twox64Concat, TaskId, ResourceRequirements, OptionQuery>;
+
+	/// Results of the executor, verifier and resolver of inference tasks under verification.
+	#[pallet::storage]
+	pub type TaskVerifications<T: Config> =
+		StorageMap<_, Twox64Concat, TaskId, Verifications<T::AccountId, T::Hash>, OptionQuery>;
+
+	/// Tips task owners attached to their tasks, paid to the worker whose result is accepted.
+	#[pallet::storage]
+	pub type TaskTips<T: Config> = StorageMap<_, Twox64Concat, TaskId, BalanceOf<T>, OptionQuery>;
+
+	/// Tasks waiting for a worker to become available, oldest first.
+	#[pallet::storage]
+	pub type PendingTasks<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		TaskClass,
+		Twox64Concat,
+		TaskPriority,
+		BoundedVec<TaskId, T::MaxPendingTasks>,
+		ValueQuery,
+	>;
+
+	/// Block at which the worker currently in charge of a task times out, or at which the dispute
+	/// window of a completed task closes.
+	#[pallet::storage]
+	pub type TaskDeadlines<T: Config> =
+		StorageMap<_, Twox64Concat, TaskId, BlockNumberFor<T>, OptionQuery>;
+
+	/// Tasks whose deadline expires at a given block, processed in `on_initialize`.
+	#[pallet::storage]
+	pub type ExpiringTasks<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<TaskId, T::MaxExpiringTasksPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Tasks of a task graph that a task depends on.
+	#[pallet::storage]
+	pub type TaskParents<T: Config> =
+		StorageMap<_, Twox64Concat, TaskId, BoundedVec<TaskId, MaxTaskParents>, ValueQuery>;
+
+	/// Tasks of a task graph waiting for a task to complete.
+	#[pallet::storage]
+	pub type TaskChildren<T: Config> =
+		StorageMap<_, Twox64Concat, TaskId, BoundedVec<TaskId, T::MaxGraphNodes>, ValueQuery>;
+
+	/// The next schedule ID to be assigned.
+	#[pallet::storage]
+	pub type NextScheduleId<T: Config> = StorageValue<_, ScheduleId, ValueQuery>;
+
+	/// Tasks to start at a future block, possibly repeatedly.
+	#[pallet::storage]
+	pub type ScheduledTasks<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		ScheduleId,
+		ScheduledTask<T::AccountId, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// Scheduled tasks whose next run starts at a given block, processed in `on_initialize`.
+	#[pallet::storage]
+	pub type TaskAgenda<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<ScheduleId, T::MaxScheduledTasksPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Finished tasks in the order they finished, with the block they finished at, waiting to be
+	/// pruned.
+	#[pallet::storage]
+	pub type PruningQueue<T: Config> =
+		StorageMap<_, Twox64Concat, u64, (TaskId, BlockNumberFor<T>), OptionQuery>;
+
+	/// Index of the oldest entry of the pruning queue and index of its next entry.
+	#[pallet::storage]
+	pub type PruningQueueRange<T: Config> = StorageValue<_, (u64, u64), ValueQuery>;
+
+	/// What is kept of the tasks whose history has been pruned.
+	#[pallet::storage]
+	pub type TaskSummaries<T: Config> =
+		StorageMap<_, Twox64Concat, TaskId, TaskSummary<T::AccountId, T::Hash>, OptionQuery>;
+
+	/// Tasks of an account with their current status.
+	#[pallet::storage]
+	pub type TasksByOwner<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		TaskId,
+		TaskStatusType,
+		OptionQuery,
+	>;
+
+	/// Tasks a worker is currently involved in, as executor, verifier or resolver, with their
+	/// status. Entries are removed once the task no longer needs the worker.
+	#[pallet::storage]
+	pub type TasksByWorker<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		(T::AccountId, WorkerId),
+		Twox64Concat,
+		TaskId,
+		TaskStatusType,
+		OptionQuery,
+	>;
+
+	/// Number of tasks each worker is currently running, counted against the number of tasks it
+	/// accepts at the same time.
+	#[pallet::storage]
+	pub type ActiveAllocations<T: Config> =
+		StorageMap<_, Twox64Concat, (T::AccountId, WorkerId), u32, ValueQuery>;
+
+	/// Worker whose capacity each task currently takes up: its executor while it runs, or its
+	/// verifier or resolver while they re-run it.
+	#[pallet::storage]
+	pub type OccupiedWorkers<T: Config> =
+		StorageMap<_, Twox64Concat, TaskId, (T::AccountId, WorkerId), OptionQuery>;
+
 	#[pallet::storage]
 	pub type GatekeeperAccount<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
 
+	/// Start of the current rate limit window of an account and the tasks it scheduled in it.
 	#[pallet::storage]
 	pub type TaskRateLimits<T: Config> = StorageMap<
 		_,
 		Twox64Concat,
 		T::AccountId,
-		(BlockNumberFor<T>, u32), // (last_block, count)
+		(BlockNumberFor<T>, u32), // (window_start, count)
 		ValueQuery,
 	>;
 
@@ -96,6 +345,68 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type ModelHashes<T: Config> = StorageMap<_, Blake2_128Concat, [u8; 32], T::Hash, OptionQuery>;
 
+	/// The next model ID to be assigned.
+	#[pallet::storage]
+	pub type NextModelId<T: Config> = StorageValue<_, ModelId, ValueQuery>;
+
+	/// Models of the model registry.
+	#[pallet::storage]
+	pub type Models<T: Config> =
+		StorageMap<_, Twox64Concat, ModelId, ModelInfo<T::AccountId, BlockNumberFor<T>>, OptionQuery>;
+
+	/// Versions of the models of the model registry.
+	#[pallet::storage]
+	pub type ModelVersions<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ModelId,
+		Twox64Concat,
+		ModelVersionId,
+		ModelVersion<T::Hash, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// Deposit reserved from the owner of a model for the model and its versions.
+	#[pallet::storage]
+	pub type ModelDeposits<T: Config> =
+		StorageMap<_, Twox64Concat, ModelId, BalanceOf<T>, ValueQuery>;
+
+	/// Account a model was offered to with `transfer_model`, until it accepts the transfer.
+	#[pallet::storage]
+	pub type ModelTransferOffers<T: Config> =
+		StorageMap<_, Twox64Concat, ModelId, T::AccountId, OptionQuery>;
+
+	/// Model version run by the tasks that reference one.
+	#[pallet::storage]
+	pub type TaskModels<T: Config> =
+		StorageMap<_, Twox64Concat, TaskId, TaskModel<T::Hash>, OptionQuery>;
+
+	/// Confidential tasks, with the key of the owner their result is encrypted to.
+	#[pallet::storage]
+	pub type ConfidentialTasks<T: Config> =
+		StorageMap<_, Twox64Concat, TaskId, EncryptionKey, OptionQuery>;
+
+	/// Metadata of confidential tasks, encrypted to the key of the worker they are assigned to.
+	#[pallet::storage]
+	pub type TaskPayloads<T: Config> =
+		StorageMap<_, Twox64Concat, TaskId, EncryptedPayload, OptionQuery>;
+
+	/// Results of confidential tasks, encrypted to the key of their owner.
+	#[pallet::storage]
+	pub type EncryptedResults<T: Config> =
+		StorageMap<_, Twox64Concat, TaskId, EncryptedPayload, OptionQuery>;
+
+	/// Completed tasks whose result can still be disputed, with the worker that delivered it.
+	/// The dispute window closes at the block recorded in `TaskDeadlines`.
+	#[pallet::storage]
+	pub type DisputableResults<T: Config> =
+		StorageMap<_, Twox64Concat, TaskId, (T::AccountId, WorkerId), OptionQuery>;
+
+	/// Open disputes against the result of a task.
+	#[pallet::storage]
+	pub type TaskDisputes<T: Config> =
+		StorageMap<_, Twox64Concat, TaskId, TaskDispute<T::AccountId, BalanceOf<T>>, OptionQuery>;
+
 	/// Pallets use events to inform users when important changes are made.
 	/// <https://paritytech.github.io/polkadot-sdk/master/polkadot_sdk_docs/guides/your_first_pallet/index.html#event-and-error>
 	#[pallet::event]
@@ -110,6 +421,49 @@ pub mod pallet {
 			task: BoundedVec<u8, ConstU32<500>>,
 		},
 
+		/// No worker could take a new task, it was put in the pending queue.
+		TaskQueued {
+			task_id: TaskId,
+			task_kind: TaskKind,
+			priority: TaskPriority,
+			task_owner: T::AccountId,
+		},
+
+		/// A task graph was submitted. Its tasks got the ids `first_task_id` onwards, in the
+		/// order of the nodes.
+		TaskGraphSubmitted {
+			task_owner: T::AccountId,
+			first_task_id: TaskId,
+			tasks: u32,
+		},
+
+		/// All parents of a task of a task graph completed and it was scheduled. Lists the
+		/// results of its parents.
+		TaskDependenciesMet {
+			task_id: TaskId,
+			parent_results: Vec<(TaskId, BoundedVec<u8, ConstU32<500>>)>,
+		},
+
+		/// A task was scheduled to start at block `next_run`, `runs` times in total.
+		ScheduledTaskCreated {
+			schedule_id: ScheduleId,
+			task_owner: T::AccountId,
+			next_run: BlockNumberFor<T>,
+			runs: u32,
+		},
+
+		/// A run of a scheduled task started as task `task_id`.
+		ScheduledTaskStarted {
+			schedule_id: ScheduleId,
+			task_id: TaskId,
+		},
+
+		/// The remaining runs of a scheduled task were cancelled and their deposits refunded.
+		ScheduledTaskCancelled {
+			schedule_id: ScheduleId,
+			refunded_hours: u32,
+		},
+
 		/// A worker confirmed reception of task data and started execution.
 		TaskReceptionConfirmed {
 			task_id: TaskId,
@@ -125,8 +479,131 @@ pub mod pallet {
 		MinerVacated {
 			task_id: TaskId,
 		},
+
+		/// A task was cancelled before it finished; the compute hours it used were charged.
+		TaskCancelled {
+			task_id: TaskId,
+			consumed_compute_hours: u32,
+		},
+
+		/// The unused part of a task's compute hour deposit was returned to its owner.
+		ComputeHoursRefunded {
+			task_id: TaskId,
+			task_owner: T::AccountId,
+			refunded_hours: u32,
+		},
+
+		/// A worker taking part in the verification of a task submitted its result.
+		TaskResultSubmitted {
+			task_id: TaskId,
+			worker: (T::AccountId, WorkerId),
+			completed_hash: T::Hash,
+		},
+
+		/// A worker was assigned to re-run a task and verify the executor's result.
+		VerifierAssigned {
+			task_id: TaskId,
+			verifier: (T::AccountId, WorkerId),
+		},
+
+		/// Executor and verifier disagreed, a worker was assigned to settle the dispute.
+		ResolverAssigned {
+			task_id: TaskId,
+			resolver: (T::AccountId, WorkerId),
+		},
+
+		/// No result could be agreed upon and the task was handed to a new executor.
+		TaskReassigned {
+			task_id: TaskId,
+			assigned_worker: (T::AccountId, WorkerId),
+		},
+
+		/// The worker in charge of a task missed its deadline and was penalized.
+		TaskTimedOut {
+			task_id: TaskId,
+			worker: (T::AccountId, WorkerId),
+			task_status: TaskStatusType,
+		},
+
+		/// The task could not be completed and its deposit was refunded.
+		TaskFailed {
+			task_id: TaskId,
+		},
+
+		/// A task completed; `worker` is the worker whose result was accepted.
+		TaskCompleted {
+			task_id: TaskId,
+			worker: (T::AccountId, WorkerId),
+			result: BoundedVec<u8, ConstU32<500>>,
+			time_elapsed: BlockNumberFor<T>,
+			consumed_compute_hours: u32,
+		},
+		/// The history of a finished task was pruned, leaving `summary` behind. Indexers archive
+		/// the full task from the state of the previous block.
+		TaskPruned {
+			task_id: TaskId,
+			summary: TaskSummary<T::AccountId, T::Hash>,
+		},
+		/// The owner of a task disputed its result and put up `bond`. `verifier` re-runs the task
+		/// to decide the dispute, if one could be found.
+		TaskDisputed {
+			task_id: TaskId,
+			disputer: T::AccountId,
+			bond: BalanceOf<T>,
+			verifier: Option<(T::AccountId, WorkerId)>,
+		},
+		/// A dispute was decided. A worker at fault was penalized and lost its reward, otherwise
+		/// the disputer forfeited its bond.
+		DisputeResolved {
+			task_id: TaskId,
+			worker_at_fault: bool,
+		},
+		/// The result of a task can no longer be disputed and its tip was paid to the worker.
+		DisputeWindowClosed {
+			task_id: TaskId,
+		},
+		/// The owner of a confidential task sent its metadata, encrypted to the key of `worker`.
+		TaskPayloadSubmitted {
+			task_id: TaskId,
+			worker: (T::AccountId, WorkerId),
+		},
 		ModelHashRegistered(Vec<u8>, T::Hash),
 		ModelHashQueried(Vec<u8>, T::Hash),
+		/// A model was added to the model registry.
+		ModelRegistered {
+			model_id: ModelId,
+			owner: T::AccountId,
+		},
+		/// A new version of a model was added.
+		ModelVersionAdded {
+			model_id: ModelId,
+			version: ModelVersionId,
+			artifact_hash: T::Hash,
+		},
+		/// A version of a model was deprecated and can no longer be used by new tasks.
+		ModelVersionDeprecated {
+			model_id: ModelId,
+			version: ModelVersionId,
+		},
+		/// The owner of a model offered to hand it over to `new_owner`.
+		ModelTransferOffered {
+			model_id: ModelId,
+			new_owner: T::AccountId,
+		},
+		/// A model was handed over to a new owner.
+		ModelTransferred {
+			model_id: ModelId,
+			new_owner: T::AccountId,
+		},
+		/// A version of a model was removed and its deposit returned to the owner.
+		ModelVersionRemoved {
+			model_id: ModelId,
+			version: ModelVersionId,
+		},
+		/// A model was removed from the model registry and its deposit returned to the owner.
+		ModelRemoved {
+			model_id: ModelId,
+		},
 	}
 
 	/// Errors inform users that something went wrong.
@@ -153,14 +630,93 @@ pub mod pallet {
 		// Status transition errors
 		RequireAssignedTask, // A task must be assigned before it can proceed to the next step.
 
+		// Completion errors
+		InvalidCpuUsage, // The reported average CPU usage is above 100 percent.
+
 		// Verification-specific errors
 		RequireAssignedVerifier, // A verifier must be assigned to the task.
+		RequireAssignedResolver, // A resolver must be assigned to the task.
 
 		/// Account has exceeded task submission rate limit
 		RateLimitExceeded,
 		WorkerDoesNotExist,
+		/// The pinned worker does not satisfy the resource requirements of the task.
+		WorkerRequirementsNotMet,
+		/// The pinned worker already runs as many tasks as it accepts.
+		WorkerAtCapacity,
 		ModelAlreadyRegistered,
 		ModelNotFound,
+		/// The model has no such version.
+		ModelVersionNotFound,
+		/// The model version is deprecated and cannot be used by new tasks.
+		ModelVersionDeprecated,
+		/// Only the owner of the model can do this.
+		NotModelOwner,
+		/// The model was not offered to the caller.
+		NoModelTransferOffer,
+		/// The versions of the model have to be removed first.
+		ModelHasVersions,
+		/// The task was not scheduled as a confidential task.
+		TaskNotConfidential,
+		/// The payload is not encrypted to the expected key.
+		WrongEncryptionKey,
+		/// The owner of the confidential task has not sent its encrypted metadata yet.
+		TaskPayloadMissing,
+		/// The result of a confidential task must be submitted encrypted.
+		EncryptedResultRequired,
+		/// The pinned worker has no encryption key and cannot run confidential tasks.
+		WorkerEncryptionKeyMissing,
+		/// The result of the task can no longer be disputed.
+		NotDisputable,
+		/// The task is not disputed.
+		TaskNotDisputed,
+		/// Only the worker re-running a disputed task can do this.
+		RequireDisputeVerifier,
+		/// The pending queue for this kind and priority of task is full.
+		PendingQueueFull,
+		/// The task graph is empty or a node depends on a node that does not come before it.
+		InvalidTaskGraph,
+		/// A scheduled task must start in the future and repeated runs need a non-zero interval.
+		InvalidSchedule,
+		ScheduleNotFound,
+		/// The metadata of a confidential task must be sent encrypted, not in `task_location`.
+		ConfidentialMetadataInClear,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let expiring = ExpiringTasks::<T>::take(now);
+			let due = TaskAgenda::<T>::take(now);
+			let weight = T::DbWeight::get().reads_writes(2, 2);
+
+			let expired = expiring.len() as u64;
+			let mut released = 0u32;
+			for task_id in expiring {
+				released = released.saturating_add(Self::expire_task(task_id, now));
+			}
+
+			let started = due.len() as u64;
+			for schedule_id in due {
+				Self::start_scheduled_task(schedule_id, now);
+			}
+
+			// An expired task picks up to two workers and frees the late one, a released child of
+			// a task graph picks one
+			let expire_weight = <T as pallet::Config>::WeightInfo::expire_task()
+				.saturating_add(Self::selection_weight(2))
+				.saturating_add(Self::dispatch_weight());
+			let start_weight = <T as pallet::Config>::WeightInfo::start_scheduled_task()
+				.saturating_add(Self::selection_weight(1));
+			weight
+				.saturating_add(expire_weight.saturating_mul(expired))
+				.saturating_add(Self::selection_weight(released))
+				.saturating_add(start_weight.saturating_mul(started))
+		}
+
+		fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::prune_finished_tasks(now, remaining_weight)
+		}
 	}
 
 	#[pallet::call]
@@ -170,13 +726,39 @@ where
         TryFrom<u64>, {
 		/// Creates a new task and assigns it to a randomly selected worker.
 		/// None -> Assigned
+		/// None -> Pending, when no worker can take the task
+		///
+		/// When `worker` is `None` the pallet picks the worker itself among the active workers of
+		/// the required type that are in good standing. Pinning a specific worker is reserved for
+		/// the gatekeeper, which still matches some tasks off-chain.
+		///
+		/// The optional parameters of the task come in `options`:
+		///
+		/// - `requirements` restricts the task to workers whose registered specs (and optionally
+		///   location) can accommodate it; workers that fall short are never selected.
+		/// - `priority` orders the task in the pending queue, where a task no worker can take waits
+		///   with its deposit locked until the next suitable worker is reported `Active`. Queued
+		///   tasks of a higher priority go first; `TaskPriority::High` is reserved for the
+		///   gatekeeper.
+		/// - `tip` is taken from the caller right away. It moves the task ahead of the queued tasks
+		///   of the same priority with lower tips, and is credited to the pending rewards of the
+		///   worker whose result is accepted. It is refunded if the task is stopped or fails.
+		/// - `model` binds the task to a version of a model of the model registry, which must not
+		///   be deprecated. Workers check the artifact they download against the hash recorded in
+		///   `TaskModels` before running it.
+		/// - `confidential` makes the task confidential, with the result encrypted to the given key
+		///   of the owner. It is only handed to workers with an encryption key; once assigned, the
+		///   owner sends the metadata encrypted to the key of the worker with
+		///   `submit_task_payload`. `task_location` must then be left empty. Confidential results
+		///   are not verified by re-running the task, since no other worker can read it.
 		#[pallet::call_index(0)]
 		#[pallet::weight({
-    		if nzk_info.is_some() {
+    		let scheduler_weight = if nzk_info.is_some() {
         		<T as pallet::Config>::WeightInfo::task_scheduler_nzk(task_location.len() as u32)
     		} else {
         		<T as pallet::Config>::WeightInfo::task_scheduler_no_nzk(task_location.len() as u32)
-    		}
+    		};
+			scheduler_weight.saturating_add(Pallet::<T>::selection_weight(1))
 		})]
 		pub fn task_scheduler(
 			origin: OriginFor<T>,
@@ -185,119 +767,112 @@ where
 			task_kind: TaskKind,
 			task_location: BoundedVec<u8, ConstU32<500>>,
 			nzk_info: Option<NeuroZkTaskSubmissionDetails>,
-			worker_owner: T::AccountId,
-			worker_id: WorkerId,
+			worker: Option<(T::AccountId, WorkerId)>,
 			compute_hours_deposit: Option<u32>,
+			options: TaskOptions<BalanceOf<T>>,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin.clone())?;
+			let TaskOptions { requirements, priority, tip, model, confidential } = options;
 
 			// Determine worker type based on task kind
-			let worker_type = match task_kind {
-				TaskKind::NeuroZK => WorkerType::Executable,
-				TaskKind::OpenInference => WorkerType::Executable,
-			};
+			let worker_type = Self::worker_type_for(task_kind.class());
 
-			// Check if any workers exist for the task_kind first
-			let any_workers_exist = match task_kind {
-				TaskKind::NeuroZK => ExecutableWorkers::<T>::iter().next().is_some(),
-				TaskKind::OpenInference => ExecutableWorkers::<T>::iter().next().is_some(),
-			};
-			ensure!(any_workers_exist, Error::<T>::NoWorkersAvailable);
+			let is_gatekeeper = GatekeeperAccount::<T>::get().as_ref() == Some(&who);
 
-			// Check worker status and reputation
-			pallet_edge_connect::Pallet::<T>::check_worker_status(
-				&(worker_owner.clone(), worker_id),
-				worker_type,
-			)
-			.map_err(|_| Error::<T>::WorkerDoesNotExist)?;
+			if !is_gatekeeper {
+				Self::check_rate_limit(&who, 1)?;
+			}
 
-			// Then check if the specific worker exists
-			let worker_exists = match task_kind {
-				TaskKind::NeuroZK => {
-					ExecutableWorkers::<T>::contains_key((worker_owner.clone(), worker_id))
-				}
-				TaskKind::OpenInference => {
-					ExecutableWorkers::<T>::contains_key((worker_owner.clone(), worker_id))
-				}
-			};
-			ensure!(worker_exists, Error::<T>::WorkerDoesNotExist);
+			ensure!(
+				priority != TaskPriority::High || is_gatekeeper,
+				Error::<T>::NotGatekeeper
+			);
+
+			let pinned_worker = match worker {
+				Some((worker_owner, worker_id)) => {
+					ensure!(is_gatekeeper, Error::<T>::NotGatekeeper);
+
+					// Check worker status and reputation
+					pallet_edge_connect::Pallet::<T>::check_worker_status(
+						&(worker_owner.clone(), worker_id),
+						worker_type.clone(),
+					)
+					.map_err(|_| Error::<T>::WorkerDoesNotExist)?;
+
+					// Then check if the specific worker exists
+					let pinned_worker =
+						Self::worker(&(worker_owner.clone(), worker_id), &worker_type)
+							.ok_or(Error::<T>::WorkerDoesNotExist)?;
+
+					// Even the gatekeeper cannot hand a task to a worker that cannot run it
+					if let Some(requirements) = &requirements {
+						ensure!(
+							requirements
+								.is_satisfied_by(&pinned_worker.specs, &pinned_worker.location),
+							Error::<T>::WorkerRequirementsNotMet
+						);
+					}
+
+					ensure!(
+						confidential.is_none() || pinned_worker.encryption_key.is_some(),
+						Error::<T>::WorkerEncryptionKeyMissing
+					);
+
+					let worker_key = (worker_owner, worker_id);
+					ensure!(
+						Self::has_capacity(&worker_key, &pinned_worker),
+						Error::<T>::WorkerAtCapacity
+					);
 
-			let pays_fee = if let Some(gatekeeper) = GatekeeperAccount::<T>::get() {
-				if who == gatekeeper {
-					Pays::No
-				} else {
-					Pays::Yes
+					Some(worker_key)
 				}
-			} else {
-				Pays::Yes
+				None => None,
 			};
 
+			// Anything in `task_location` would be published in the clear with `TaskScheduled`
+			ensure!(
+				confidential.is_none() || task_location.is_empty(),
+				Error::<T>::ConfidentialMetadataInClear
+			);
+
+			let pays_fee = if is_gatekeeper { Pays::No } else { Pays::Yes };
+
 			// Validate deposit
 			let deposit = compute_hours_deposit.ok_or(Error::<T>::RequireComputeHoursDeposit)?;
 			ensure!(deposit > 0, Error::<T>::RequireComputeHoursDeposit);
 
-			let mut nzk_data = None;
-
-			match task_kind {
-				TaskKind::NeuroZK => {
-					// For NeuroZK, zk_info must be present
-					match nzk_info {
-						Some(data) => {
-							nzk_data = Some(NzkData {
-								zk_input: data.zk_input,
-								zk_settings: data.zk_settings,
-								zk_verifying_key: data.zk_verifying_key,
-								zk_proof: None,
-								last_proof_accepted: None,
-							});
-						}
-						None => {
-							return Err(Error::<T>::ZkFilesMissing.into());
-						}
-					}
-				}
-				TaskKind::OpenInference => {
-					ensure!(nzk_info.is_none(), Error::<T>::UnexpectedZkFiles);
-				}
-			}
-
-			let deposit = compute_hours_deposit.ok_or(Error::<T>::RequireComputeHoursDeposit)?;
+			let task_info =
+				Self::new_task_info(&who, task_kind, task_location, nzk_info, deposit)?;
+			let task_model = model
+				.map(|(model_id, version)| Self::task_model_for(model_id, version))
+				.transpose()?;
 
-            // Consume compute hours from payment pallet
+			// Consume compute hours from payment pallet
 			pallet_payment::Pallet::<T>::consume_compute_hours(origin.clone(), deposit)?;
 
 			// Generate task ID
 			let task_id = NextTaskId::<T>::get();
 			NextTaskId::<T>::put(task_id.wrapping_add(1));
 
-			let selected_worker = (worker_owner, worker_id);
-
-			let task_info = TaskInfo::<T::AccountId, BlockNumberFor<T>> {
-				task_owner: who.clone(),
-				create_block: <frame_system::Pallet<T>>::block_number(),
-				metadata: task_location.clone(),
-				nzk_data,
-				time_elapsed: None,
-				average_cpu_percentage_use: None,
-				task_kind: task_kind.clone(),
-				result: None,
-				compute_hours_deposit: Some(deposit),
-				consume_compute_hours: None,
-				task_status: TaskStatusType::Assigned,
-			};
-
-			TaskAllocations::<T>::insert(task_id, selected_worker.clone());
 			TaskOwners::<T>::insert(task_id, who.clone());
-			Tasks::<T>::insert(task_id, task_info);
-			TaskStatus::<T>::insert(task_id, TaskStatusType::Assigned);
+			if let Some(requirements) = requirements {
+				TaskRequirements::<T>::insert(task_id, requirements);
+			}
+			if let Some(task_model) = task_model {
+				TaskModels::<T>::insert(task_id, task_model);
+			}
+			if let Some(owner_key) = confidential {
+				ConfidentialTasks::<T>::insert(task_id, owner_key);
+			}
+			if let Some(tip) = tip.filter(|tip| !tip.is_zero()) {
+				pallet_payment::Pallet::<T>::collect_tip(&who, tip)?;
+				TaskTips::<T>::insert(task_id, tip);
+			}
 
-			Self::deposit_event(Event::TaskScheduled {
-				assigned_worker: selected_worker,
-				task_kind,
-				task_owner: who,
-				task_id,
-				task: task_location,
-			});
+			match pinned_worker {
+				Some(pinned_worker) => Self::assign_task(task_id, task_info, pinned_worker),
+				None => Self::schedule_task(task_id, task_info, priority)?,
+			}
 
 			Ok(PostDispatchInfo {
 				actual_weight: None,
@@ -305,35 +880,235 @@ where
 			})
 		}
 
-		/// Miner confirms that it has gathered the data and is starting task execution.
+		/// Schedules a graph of tasks that depend on each other, such as the preprocessing,
+		/// inference and proof steps of a pipeline.
+		/// None -> Assigned, or Pending, for nodes without parents
+		/// None -> WaitingForDependencies, for the other nodes
 		///
-		/// Allowed only if task is still `Assigned`.
-		/// Changes task state to `Running` and starts aggregation of resource usage.
-		#[pallet::call_index(1)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::confirm_task_reception())]
-		pub fn confirm_task_reception(origin: OriginFor<T>, task_id: TaskId) -> DispatchResult {
-			let who = ensure_signed(origin)?;
+		/// Each node lists the positions in `nodes` of its parents, which must come before it so
+		/// that the graph has no cycles. Its task is scheduled like any other once all of its
+		/// parents completed, and the results of the parents are announced in
+		/// `TaskDependenciesMet`. When a task of the graph fails or is stopped, the tasks that
+		/// depend on it fail and their deposits are refunded.
+		///
+		/// Every node counts against the rate limit of the caller.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::submit_task_graph(nodes.len() as u32)
+			.saturating_add(Pallet::<T>::selection_weight(nodes.len() as u32)))]
+		pub fn submit_task_graph(
+			origin: OriginFor<T>,
+			nodes: BoundedVec<TaskGraphNode, T::MaxGraphNodes>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin.clone())?;
+			ensure!(!nodes.is_empty(), Error::<T>::InvalidTaskGraph);
 
-			// Load task
-			let mut task_info = Tasks::<T>::get(task_id).ok_or(Error::<T>::UnassignedTaskId)?;
+			let is_gatekeeper = GatekeeperAccount::<T>::get().as_ref() == Some(&who);
 
-			// Check that caller is the assigned worker
-			let assigned_worker =
-				TaskAllocations::<T>::get(task_id).ok_or(Error::<T>::UnassignedTaskId)?;
-			ensure!(assigned_worker.0 == who, Error::<T>::InvalidTaskOwner);
+			if !is_gatekeeper {
+				Self::check_rate_limit(&who, nodes.len() as u32)?;
+			}
 
-			// Task must currently be `Assigned`
-			ensure!(
-				task_info.task_status == TaskStatusType::Assigned,
-				Error::<T>::RequireAssignedTask
-			);
+			for (index, node) in nodes.iter().enumerate() {
+				ensure!(
+					node.parents.iter().all(|parent| (*parent as usize) < index),
+					Error::<T>::InvalidTaskGraph
+				);
+				ensure!(node.compute_hours_deposit > 0, Error::<T>::RequireComputeHoursDeposit);
+			}
 
-			// Transition task to `Running`
-			task_info.task_status = TaskStatusType::Running;
-			TaskStatus::<T>::insert(task_id, TaskStatusType::Running);
+			let first_task_id = NextTaskId::<T>::get();
+			let tasks = nodes.len() as u32;
+			NextTaskId::<T>::put(first_task_id.wrapping_add(tasks as TaskId));
+
+			for (task_id, node) in (first_task_id..).zip(nodes) {
+				let mut task_info = Self::new_task_info(
+					&who,
+					node.task_kind,
+					node.task_location,
+					node.nzk_info,
+					node.compute_hours_deposit,
+				)?;
+				pallet_payment::Pallet::<T>::consume_compute_hours(
+					origin.clone(),
+					node.compute_hours_deposit,
+				)?;
+
+				TaskOwners::<T>::insert(task_id, who.clone());
+				if let Some(requirements) = node.requirements {
+					TaskRequirements::<T>::insert(task_id, requirements);
+				}
 
-			// Store back the updated task info
-			Tasks::<T>::insert(task_id, task_info);
+				if node.parents.is_empty() {
+					Self::schedule_task(task_id, task_info, TaskPriority::default())?;
+					continue;
+				}
+
+				let mut parents = node.parents.into_inner();
+				parents.sort_unstable();
+				parents.dedup();
+				let parents: Vec<TaskId> = parents
+					.into_iter()
+					.map(|parent| first_task_id.wrapping_add(parent as TaskId))
+					.collect();
+				for parent in &parents {
+					TaskChildren::<T>::try_mutate(parent, |children| children.try_push(task_id))
+						.map_err(|_| Error::<T>::InvalidTaskGraph)?;
+				}
+				TaskParents::<T>::insert(task_id, BoundedVec::truncate_from(parents));
+
+				task_info.task_status = TaskStatusType::WaitingForDependencies;
+				Self::store_task(task_id, task_info);
+			}
+
+			Self::deposit_event(Event::TaskGraphSubmitted {
+				task_owner: who,
+				first_task_id,
+				tasks,
+			});
+
+			Ok(().into())
+		}
+
+		/// Schedules a task to start at block `start`, and then every `interval` blocks until it
+		/// ran `runs` times, e.g. for nightly batch inference.
+		///
+		/// The compute hours deposit of every run is taken right away; each run then starts as a
+		/// regular task from `on_initialize`, assigned to a worker or queued, and refunds what it
+		/// did not use. A scheduled task counts once against the rate limit of the caller.
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::schedule_task_at(task_location.len() as u32))]
+		pub fn schedule_task_at(
+			origin: OriginFor<T>,
+			task_kind: TaskKind,
+			task_location: BoundedVec<u8, ConstU32<500>>,
+			nzk_info: Option<NeuroZkTaskSubmissionDetails>,
+			requirements: Option<ResourceRequirements>,
+			compute_hours_deposit: u32,
+			start: BlockNumberFor<T>,
+			interval: Option<BlockNumberFor<T>>,
+			runs: u32,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin.clone())?;
+
+			let is_gatekeeper = GatekeeperAccount::<T>::get().as_ref() == Some(&who);
+
+			if !is_gatekeeper {
+				Self::check_rate_limit(&who, 1)?;
+			}
+
+			ensure!(compute_hours_deposit > 0, Error::<T>::RequireComputeHoursDeposit);
+			ensure!(
+				start > <frame_system::Pallet<T>>::block_number(),
+				Error::<T>::InvalidSchedule
+			);
+			ensure!(runs > 0, Error::<T>::InvalidSchedule);
+			ensure!(
+				runs == 1 || interval.is_some_and(|interval| !interval.is_zero()),
+				Error::<T>::InvalidSchedule
+			);
+
+			// Validate the zk files now rather than when the first run starts
+			Self::nzk_data_for(&task_kind, nzk_info.clone())?;
+
+			let total_deposit =
+				compute_hours_deposit.checked_mul(runs).ok_or(Error::<T>::InvalidSchedule)?;
+			pallet_payment::Pallet::<T>::consume_compute_hours(origin, total_deposit)?;
+
+			let schedule_id = NextScheduleId::<T>::get();
+			NextScheduleId::<T>::put(schedule_id.wrapping_add(1));
+
+			let next_run = Self::add_to_agenda(schedule_id, start);
+			ScheduledTasks::<T>::insert(
+				schedule_id,
+				ScheduledTask {
+					task_owner: who.clone(),
+					task_kind,
+					task_location,
+					nzk_info,
+					requirements,
+					compute_hours_deposit,
+					next_run,
+					interval,
+					remaining_runs: runs,
+				},
+			);
+
+			Self::deposit_event(Event::ScheduledTaskCreated {
+				schedule_id,
+				task_owner: who,
+				next_run,
+				runs,
+			});
+
+			Ok(().into())
+		}
+
+		/// Cancels the remaining runs of a scheduled task and refunds their deposits. Runs that
+		/// already started are regular tasks and are cancelled with `cancel_task`.
+		///
+		/// Callable by the task owner, the gatekeeper or root.
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_scheduled_task())]
+		pub fn cancel_scheduled_task(
+			origin: OriginFor<T>,
+			schedule_id: ScheduleId,
+		) -> DispatchResult {
+			let scheduled =
+				ScheduledTasks::<T>::get(schedule_id).ok_or(Error::<T>::ScheduleNotFound)?;
+			Self::ensure_task_controller(origin, &scheduled.task_owner)?;
+
+			ScheduledTasks::<T>::remove(schedule_id);
+			TaskAgenda::<T>::mutate(scheduled.next_run, |due| {
+				due.retain(|queued| *queued != schedule_id)
+			});
+
+			let refunded_hours =
+				scheduled.compute_hours_deposit.saturating_mul(scheduled.remaining_runs);
+			pallet_payment::Pallet::<T>::refund_compute_hours(
+				&scheduled.task_owner,
+				refunded_hours,
+			);
+
+			Self::deposit_event(Event::ScheduledTaskCancelled { schedule_id, refunded_hours });
+
+			Ok(())
+		}
+
+		/// Miner confirms that it has gathered the data and is starting task execution.
+		///
+		/// Allowed only if task is still `Assigned`.
+		/// Changes task state to `Running` and starts aggregation of resource usage.
+		///
+		/// The worker then has as many blocks as the compute hours deposit of the task pays for,
+		/// at most `T::ExecutionTimeout`, to submit the result.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::confirm_task_reception())]
+		pub fn confirm_task_reception(origin: OriginFor<T>, task_id: TaskId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// Load task
+			let mut task_info = Tasks::<T>::get(task_id).ok_or(Error::<T>::UnassignedTaskId)?;
+
+			// Check that caller is the assigned worker
+			let assigned_worker =
+				TaskAllocations::<T>::get(task_id).ok_or(Error::<T>::UnassignedTaskId)?;
+			ensure!(assigned_worker.0 == who, Error::<T>::InvalidTaskOwner);
+
+			// Task must currently be `Assigned`
+			ensure!(
+				task_info.task_status == TaskStatusType::Assigned,
+				Error::<T>::RequireAssignedTask
+			);
+			ensure!(
+				!ConfidentialTasks::<T>::contains_key(task_id)
+					|| TaskPayloads::<T>::contains_key(task_id),
+				Error::<T>::TaskPayloadMissing
+			);
+
+			// Transition task to `Running`
+			task_info.task_status = TaskStatusType::Running;
+			let execution_timeout = Self::execution_timeout(&task_info);
+			Self::store_task(task_id, task_info);
 
 			// Start compute aggregation: record starting block
 			ComputeAggregations::<T>::insert(
@@ -343,6 +1118,7 @@ where
 					None::<BlockNumberFor<T>>,
 				),
 			);
+			Self::set_deadline(task_id, execution_timeout);
 
 			// Emit event (you can define a new event like TaskReceptionConfirmed if needed)
 			Self::deposit_event(Event::TaskReceptionConfirmed { task_id, who });
@@ -354,12 +1130,15 @@ where
 		/// signals the miner to exit task execution and reset itself
 		/// Admin will make status to stopped
 		/// RUnning -> Stopped
+		///
+		/// Callable by the task owner, the gatekeeper or root. The compute hours used so far are
+		/// charged and the rest of the deposit is refunded.
 		#[pallet::call_index(5)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::stop_task_and_vacate_miner())]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::stop_task_and_vacate_miner()
+			.saturating_add(Pallet::<T>::dispatch_weight()))]
 		pub fn stop_task_and_vacate_miner(origin: OriginFor<T>, task_id: TaskId) -> DispatchResult {
-			ensure_signed(origin)?; // anyone controlling can request stop
-
-			let mut task = Tasks::<T>::get(task_id).ok_or(Error::<T>::TaskNotFound)?;
+			let task = Tasks::<T>::get(task_id).ok_or(Error::<T>::TaskNotFound)?;
+			Self::ensure_task_controller(origin, &task.task_owner)?;
 
 			// Ensure task is running.
 			ensure!(
@@ -367,16 +1146,7 @@ where
 				Error::<T>::InvalidTaskState
 			);
 
-			// Change task state to Stopped.
-			task.task_status = TaskStatusType::Stopped;
-			Tasks::<T>::insert(task_id, task);
-
-			// Mark end of compute aggregation.
-			ComputeAggregations::<T>::mutate(task_id, |record| {
-				if let Some((start, _)) = record {
-					*record = Some((*start, Some(<frame_system::Pallet<T>>::block_number())));
-				}
-			});
+			Self::stop_task(task_id, task);
 
 			// Emit event.
 			Self::deposit_event(Event::TaskStopRequested { task_id });
@@ -384,17 +1154,75 @@ where
 			Ok(())
 		}
 
+		/// Cancels a task that has not finished yet.
+		/// WaitingForDependencies -> Stopped
+		/// Pending -> Stopped
+		/// Assigned -> Stopped
+		/// Running -> Stopped
+		/// PendingValidation -> Stopped
+		/// PendingResolution -> Stopped
+		///
+		/// Callable by the task owner, the gatekeeper or root. The compute hours used so far are
+		/// charged and the rest of the deposit is refunded; the assigned miner is then expected to
+		/// confirm it vacated the task. A task no miner was assigned yet is finished right away. A
+		/// task whose result is being verified is charged the compute hours recorded with the
+		/// result, and its verifier or resolver is released. A task whose worker has not confirmed
+		/// its reception yet is charged `T::CancellationCharge`.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_task()
+			.saturating_add(Pallet::<T>::dispatch_weight()))]
+		pub fn cancel_task(origin: OriginFor<T>, task_id: TaskId) -> DispatchResult {
+			let task = Tasks::<T>::get(task_id).ok_or(Error::<T>::TaskNotFound)?;
+			Self::ensure_task_controller(origin, &task.task_owner)?;
+
+			ensure!(
+				matches!(
+					task.task_status,
+					TaskStatusType::WaitingForDependencies
+						| TaskStatusType::Pending
+						| TaskStatusType::Assigned
+						| TaskStatusType::Running
+						| TaskStatusType::PendingValidation
+						| TaskStatusType::PendingResolution
+				),
+				Error::<T>::InvalidTaskState
+			);
+
+			if task.task_status == TaskStatusType::Pending {
+				Self::remove_pending_task(task_id, task.task_kind.class());
+			}
+			// No miner will confirm it vacated a task that was never assigned
+			let unassigned = matches!(
+				task.task_status,
+				TaskStatusType::WaitingForDependencies | TaskStatusType::Pending
+			);
+
+			let consumed_compute_hours = Self::stop_task(task_id, task);
+			if unassigned {
+				Self::queue_for_pruning(task_id);
+			}
+
+			Self::deposit_event(Event::TaskCancelled { task_id, consumed_compute_hours });
+
+			Ok(())
+		}
+
 		/// miner confirms that it has reset itself
 		/// Stopped to vacated
+		///
+		/// Callable by the assigned miner or the task owner.
 		#[pallet::call_index(6)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::confirm_miner_vacation())]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::confirm_miner_vacation()
+			.saturating_add(Pallet::<T>::dispatch_weight()))]
 		pub fn confirm_miner_vacation(origin: OriginFor<T>, task_id: TaskId) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
 			let mut task = Tasks::<T>::get(task_id).ok_or(Error::<T>::TaskNotFound)?;
 
-			// Ensure task owner is confirming.
-			ensure!(task.task_owner == who, Error::<T>::NotTaskOwner);
+			// Ensure the assigned miner or the task owner is confirming.
+			let is_assigned_miner =
+				TaskAllocations::<T>::get(task_id).is_some_and(|(miner, _)| miner == who);
+			ensure!(task.task_owner == who || is_assigned_miner, Error::<T>::NotTaskOwner);
 
 			// Ensure task is stopped.
 			ensure!(
@@ -404,7 +1232,8 @@ where
 
 			// Move to Vacated state.
 			task.task_status = TaskStatusType::Vacated;
-			Tasks::<T>::insert(task_id, task);
+			Self::store_task(task_id, task);
+			Self::queue_for_pruning(task_id);
 
 			// Emit event.
 			Self::deposit_event(Event::MinerVacated { task_id });
@@ -412,6 +1241,213 @@ where
 			Ok(())
 		}
 
+		/// The assigned miner submits the result of a task it finished running.
+		/// Running -> Completed
+		/// Running -> PendingValidation (inference tasks)
+		///
+		/// `result` is typically a hash or URI of the output. Closes the compute aggregation of the
+		/// task, records the blocks and compute hours it consumed and refunds the unused part of
+		/// the deposit to the task owner.
+		///
+		/// The result of an inference task is only accepted once a verifier, a worker of another
+		/// account, reproduced it. When no such worker is available the result is accepted as is.
+		/// The verifier has as long as the executor had to submit its result.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::submit_task_result(result.len() as u32)
+			.saturating_add(Pallet::<T>::completion_weight()))]
+		pub fn submit_task_result(
+			origin: OriginFor<T>,
+			task_id: TaskId,
+			result: BoundedVec<u8, ConstU32<500>>,
+			average_cpu_percentage_use: Option<u8>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				!ConfidentialTasks::<T>::contains_key(task_id),
+				Error::<T>::EncryptedResultRequired
+			);
+
+			let (mut task, assigned_worker) =
+				Self::record_task_result(&who, task_id, result.clone(), average_cpu_percentage_use)?;
+
+			if task.task_kind == TaskKind::OpenInference {
+				let verifier = Self::select_worker(
+					&WorkerType::Executable,
+					TaskRequirements::<T>::get(task_id).as_ref(),
+					&[assigned_worker.0.clone()],
+					false,
+					(b"verifier", task_id),
+				);
+
+				if let Ok(verifier) = verifier {
+					let completed_hash = T::Hashing::hash(&result);
+					TaskVerifications::<T>::insert(
+						task_id,
+						Verifications {
+							executor: VerificationHashes {
+								worker: assigned_worker.clone(),
+								completed_hash: Some(completed_hash),
+								result: Some(result),
+							},
+							verifier: Some(VerificationHashes::assigned(verifier.clone())),
+							resolver: None,
+						},
+					);
+
+					task.task_status = TaskStatusType::PendingValidation;
+					let execution_timeout = Self::execution_timeout(&task);
+					Self::store_task(task_id, task);
+					Self::set_deadline(task_id, execution_timeout);
+
+					Self::deposit_event(Event::TaskResultSubmitted {
+						task_id,
+						worker: assigned_worker,
+						completed_hash,
+					});
+					Self::deposit_event(Event::VerifierAssigned { task_id, verifier });
+
+					return Ok(());
+				}
+			}
+
+			Self::complete_task(task_id, task, assigned_worker);
+
+			Ok(())
+		}
+
+		/// The assigned verifier submits the result it obtained by re-running the task.
+		/// PendingValidation -> Completed, if the result matches the executor's
+		/// PendingValidation -> PendingResolution, otherwise
+		///
+		/// The resolver assigned when the results differ has as long as the executor had to submit
+		/// its result.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::verify_completed_task(result.len() as u32)
+			.saturating_add(Pallet::<T>::completion_weight()))]
+		pub fn verify_completed_task(
+			origin: OriginFor<T>,
+			task_id: TaskId,
+			result: BoundedVec<u8, ConstU32<500>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut task = Tasks::<T>::get(task_id).ok_or(Error::<T>::TaskNotFound)?;
+			ensure!(
+				task.task_status == TaskStatusType::PendingValidation,
+				Error::<T>::InvalidTaskState
+			);
+
+			let mut verifications =
+				TaskVerifications::<T>::get(task_id).ok_or(Error::<T>::TaskVerificationNotFound)?;
+			let verifier =
+				verifications.verifier.as_mut().ok_or(Error::<T>::RequireAssignedVerifier)?;
+			ensure!(verifier.worker.0 == who, Error::<T>::RequireAssignedVerifier);
+
+			let completed_hash = T::Hashing::hash(&result);
+			verifier.completed_hash = Some(completed_hash);
+			verifier.result = Some(result);
+			let verifier_worker = verifier.worker.clone();
+
+			Self::deposit_event(Event::TaskResultSubmitted {
+				task_id,
+				worker: verifier_worker.clone(),
+				completed_hash,
+			});
+
+			let executor_worker = verifications.executor.worker.clone();
+			if verifications.executor.completed_hash == Some(completed_hash) {
+				TaskVerifications::<T>::insert(task_id, verifications);
+				Self::complete_task(task_id, task, executor_worker);
+				return Ok(());
+			}
+
+			let involved = [executor_worker.0, verifier_worker.0];
+			let resolver = Self::select_worker(
+				&WorkerType::Executable,
+				TaskRequirements::<T>::get(task_id).as_ref(),
+				&involved,
+				false,
+				(b"resolver", task_id),
+			);
+
+			match resolver {
+				Ok(resolver) => {
+					verifications.resolver = Some(VerificationHashes::assigned(resolver.clone()));
+					TaskVerifications::<T>::insert(task_id, verifications);
+
+					task.task_status = TaskStatusType::PendingResolution;
+					let execution_timeout = Self::execution_timeout(&task);
+					Self::store_task(task_id, task);
+					Self::set_deadline(task_id, execution_timeout);
+
+					Self::deposit_event(Event::ResolverAssigned { task_id, resolver });
+				}
+				// Without a resolver there is no majority to be found
+				Err(_) => Self::reassign_task(task_id, task, &involved),
+			}
+
+			Ok(())
+		}
+
+		/// The assigned resolver submits the result it obtained by re-running the task.
+		/// PendingResolution -> Completed, if the result matches the executor's or verifier's
+		/// PendingResolution -> Assigned, if all three results differ
+		///
+		/// The worker outvoted by the other two is penalized. When all results differ, the task
+		/// is handed to a worker of an account not involved so far, or fails if there is none.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::resolve_completed_task(result.len() as u32)
+			.saturating_add(Pallet::<T>::completion_weight()))]
+		pub fn resolve_completed_task(
+			origin: OriginFor<T>,
+			task_id: TaskId,
+			result: BoundedVec<u8, ConstU32<500>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut task = Tasks::<T>::get(task_id).ok_or(Error::<T>::TaskNotFound)?;
+			ensure!(
+				task.task_status == TaskStatusType::PendingResolution,
+				Error::<T>::InvalidTaskState
+			);
+
+			let mut verifications =
+				TaskVerifications::<T>::get(task_id).ok_or(Error::<T>::TaskVerificationNotFound)?;
+			let verifier =
+				verifications.verifier.clone().ok_or(Error::<T>::RequireAssignedVerifier)?;
+			let resolver =
+				verifications.resolver.as_mut().ok_or(Error::<T>::RequireAssignedResolver)?;
+			ensure!(resolver.worker.0 == who, Error::<T>::RequireAssignedResolver);
+
+			let completed_hash = T::Hashing::hash(&result);
+			resolver.completed_hash = Some(completed_hash);
+			resolver.result = Some(result);
+			let resolver_worker = resolver.worker.clone();
+
+			Self::deposit_event(Event::TaskResultSubmitted {
+				task_id,
+				worker: resolver_worker.clone(),
+				completed_hash,
+			});
+
+			let executor = verifications.executor.clone();
+			TaskVerifications::<T>::insert(task_id, verifications);
+
+			if executor.completed_hash == Some(completed_hash) {
+				Self::penalize_false_completion(&verifier.worker, &WorkerType::Executable);
+				Self::complete_task(task_id, task, executor.worker);
+			} else if verifier.completed_hash == Some(completed_hash) {
+				Self::penalize_false_completion(&executor.worker, &WorkerType::Executable);
+				task.result = verifier.result;
+				Self::complete_task(task_id, task, verifier.worker);
+			} else {
+				let involved = [executor.worker.0, verifier.worker.0, resolver_worker.0];
+				Self::reassign_task(task_id, task, &involved);
+			}
+
+			Ok(())
+		}
+
 		#[pallet::call_index(4)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_gatekeeper())]
 		pub fn set_gatekeeper(
@@ -475,44 +1511,1783 @@ where
 			Self::deposit_event(Event::ModelHashQueried(model_id_fixed.to_vec(), model_hash));
 			Ok(())
 		}
-	}
 
-	impl<T: Config> Pallet<T> {
-		#[allow(dead_code)]
-		fn check_rate_limit(who: &T::AccountId) -> DispatchResult {
-			let current_block = <frame_system::Pallet<T>>::block_number();
-			let (last_block, count) = TaskRateLimits::<T>::get(who);
+		/// Adds a model owned by the caller to the model registry. Its versions are added with
+		/// `add_model_version`.
+		///
+		/// `T::ModelDeposit` is reserved from the caller in the currency of the payment pallet.
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::register_model())]
+		pub fn register_model(
+			origin: OriginFor<T>,
+			name: ModelName,
+			framework: ModelFramework,
+			license_uri: LicenseUri,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+
+			let deposit = T::ModelDeposit::get();
+			<T as pallet_payment::Config>::Currency::reserve(&owner, deposit)?;
+
+			let model_id = NextModelId::<T>::get();
+			NextModelId::<T>::put(model_id.wrapping_add(1));
+			ModelDeposits::<T>::insert(model_id, deposit);
+
+			Models::<T>::insert(
+				model_id,
+				ModelInfo {
+					owner: owner.clone(),
+					name,
+					framework,
+					license_uri,
+					created_block: <frame_system::Pallet<T>>::block_number(),
+					version_count: 0,
+				},
+			);
 
-			// Reset counter if it's a new block
-			let new_count = if current_block == last_block {
-				count + 1
-			} else {
-				1
-			};
+			Self::deposit_event(Event::ModelRegistered { model_id, owner });
+			Ok(())
+		}
 
-			// Update storage
-			TaskRateLimits::<T>::insert(who, (current_block, new_count));
+		/// Adds a version of the artifact with `artifact_hash` and `size` bytes to a model of
+		/// the caller. Versions are numbered from 0 in the order they are added.
+		///
+		/// `T::ModelVersionDeposit` is reserved from the caller on top of the model deposit.
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_model_version())]
+		pub fn add_model_version(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			artifact_hash: T::Hash,
+			size: u64,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut model = Models::<T>::get(model_id).ok_or(Error::<T>::ModelNotFound)?;
+			ensure!(model.owner == who, Error::<T>::NotModelOwner);
+
+			let version = model.version_count;
+			model.version_count = version.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+
+			let deposit = T::ModelVersionDeposit::get();
+			<T as pallet_payment::Config>::Currency::reserve(&who, deposit)?;
+			ModelDeposits::<T>::mutate(model_id, |reserved| *reserved = reserved.saturating_add(deposit));
+			ModelVersions::<T>::insert(
+				model_id,
+				version,
+				ModelVersion {
+					artifact_hash,
+					size,
+					created_block: <frame_system::Pallet<T>>::block_number(),
+					deprecated: false,
+				},
+			);
+			Models::<T>::insert(model_id, model);
 
-			// Allow up to 5 tasks per block per account
-			if new_count > 5 {
-				Err(Error::<T>::RateLimitExceeded.into())
-			} else {
-				Ok(())
+			Self::deposit_event(Event::ModelVersionAdded { model_id, version, artifact_hash });
+			Ok(())
+		}
+
+		/// Deprecates a version of a model. Tasks already running it are not affected, but new
+		/// tasks can no longer reference it.
+		///
+		/// Callable by the model owner, the gatekeeper or root.
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::deprecate_model_version())]
+		pub fn deprecate_model_version(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			version: ModelVersionId,
+		) -> DispatchResult {
+			let model = Models::<T>::get(model_id).ok_or(Error::<T>::ModelNotFound)?;
+			if let Some(who) = ensure_signed_or_root(origin)? {
+				let is_gatekeeper = GatekeeperAccount::<T>::get().as_ref() == Some(&who);
+				ensure!(model.owner == who || is_gatekeeper, Error::<T>::NotModelOwner);
 			}
+
+			ModelVersions::<T>::try_mutate(model_id, version, |model_version| {
+				let model_version =
+					model_version.as_mut().ok_or(Error::<T>::ModelVersionNotFound)?;
+				model_version.deprecated = true;
+				Ok::<_, Error<T>>(())
+			})?;
+
+			Self::deposit_event(Event::ModelVersionDeprecated { model_id, version });
+			Ok(())
 		}
-	}
 
-	impl<T: Config + timestamp::Config> NzkTaskInfoHandler<T::AccountId, TaskId, BlockNumberFor<T>>
-		for Pallet<T>
-	{
-		// Implementation of the NzkTaskInfoHandler trait, which provides methods for accessing NZK task information.
-		fn get_nzk_task(task_key: TaskId) -> Option<TaskInfo<T::AccountId, BlockNumberFor<T>>> {
-			Tasks::<T>::get(task_key)
+		/// Offers to hand a model of the caller over to `new_owner`, which takes it over with
+		/// `accept_model_transfer`. A new offer replaces the previous one.
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::transfer_model())]
+		pub fn transfer_model(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			new_owner: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let model = Models::<T>::get(model_id).ok_or(Error::<T>::ModelNotFound)?;
+			ensure!(model.owner == who, Error::<T>::NotModelOwner);
+
+			ModelTransferOffers::<T>::insert(model_id, new_owner.clone());
+
+			Self::deposit_event(Event::ModelTransferOffered { model_id, new_owner });
+			Ok(())
 		}
 
-		// Implementation of the NzkTaskInfoHandler trait, which provides methods for NZK task information.
-		fn update_nzk_task(task_key: TaskId, task: TaskInfo<T::AccountId, BlockNumberFor<T>>) {
-			Tasks::<T>::insert(task_key, task);
+		/// The owner of a confidential task sends its metadata, encrypted to the key of the
+		/// worker it is assigned to.
+		///
+		/// The worker cannot confirm the reception of the task before, and gets a new
+		/// `T::ReceptionTimeout` blocks from now on to do so. When the task is reassigned, the
+		/// payload has to be sent again for the new worker.
+		#[pallet::call_index(18)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::submit_task_payload())]
+		pub fn submit_task_payload(
+			origin: OriginFor<T>,
+			task_id: TaskId,
+			payload: EncryptedPayload,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let task = Tasks::<T>::get(task_id).ok_or(Error::<T>::TaskNotFound)?;
+			ensure!(task.task_owner == who, Error::<T>::NotTaskOwner);
+			ensure!(
+				ConfidentialTasks::<T>::contains_key(task_id),
+				Error::<T>::TaskNotConfidential
+			);
+			ensure!(
+				task.task_status == TaskStatusType::Assigned,
+				Error::<T>::RequireAssignedTask
+			);
+
+			let worker_key =
+				TaskAllocations::<T>::get(task_id).ok_or(Error::<T>::UnassignedTaskId)?;
+			let worker =
+				Self::worker(&worker_key, &Self::worker_type_for(task.task_kind.class()))
+					.ok_or(Error::<T>::WorkerDoesNotExist)?;
+			ensure!(
+				worker.encryption_key == Some(payload.recipient),
+				Error::<T>::WrongEncryptionKey
+			);
+
+			TaskPayloads::<T>::insert(task_id, payload);
+			Self::set_deadline(task_id, T::ReceptionTimeout::get());
+
+			Self::deposit_event(Event::TaskPayloadSubmitted { task_id, worker: worker_key });
+			Ok(())
+		}
+
+		/// The assigned worker submits the result of a confidential task, encrypted to the key
+		/// of its owner.
+		/// Running -> Completed
+		///
+		/// The result is accepted without verification. The `result` of the task becomes the
+		/// hash of the encrypted result, which is stored in `EncryptedResults`.
+		#[pallet::call_index(19)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::submit_encrypted_task_result()
+			.saturating_add(Pallet::<T>::completion_weight()))]
+		pub fn submit_encrypted_task_result(
+			origin: OriginFor<T>,
+			task_id: TaskId,
+			result: EncryptedPayload,
+			average_cpu_percentage_use: Option<u8>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let owner_key =
+				ConfidentialTasks::<T>::get(task_id).ok_or(Error::<T>::TaskNotConfidential)?;
+			ensure!(result.recipient == owner_key, Error::<T>::WrongEncryptionKey);
+
+			let result_hash = T::Hashing::hash_of(&result);
+			let (task, assigned_worker) = Self::record_task_result(
+				&who,
+				task_id,
+				BoundedVec::truncate_from(result_hash.as_ref().to_vec()),
+				average_cpu_percentage_use,
+			)?;
+			EncryptedResults::<T>::insert(task_id, result);
+
+			Self::complete_task(task_id, task, assigned_worker);
+
+			Ok(())
+		}
+
+		/// The owner of a completed task disputes its result within `T::DisputeWindow` blocks,
+		/// putting up `T::DisputeBond`.
+		/// Completed -> Disputed
+		///
+		/// A worker of another account is picked to re-run the task, unless the task is
+		/// confidential. `T::DisputeOrigin` can decide the dispute at any time.
+		#[pallet::call_index(20)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::dispute_task_result()
+			.saturating_add(Pallet::<T>::selection_weight(1)))]
+		pub fn dispute_task_result(origin: OriginFor<T>, task_id: TaskId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut task = Tasks::<T>::get(task_id).ok_or(Error::<T>::TaskNotFound)?;
+			ensure!(task.task_owner == who, Error::<T>::NotTaskOwner);
+			ensure!(
+				task.task_status == TaskStatusType::Completed,
+				Error::<T>::InvalidTaskState
+			);
+			let worker = DisputableResults::<T>::get(task_id).ok_or(Error::<T>::NotDisputable)?;
+
+			let bond = T::DisputeBond::get();
+			pallet_payment::Pallet::<T>::collect_bond(&who, bond)?;
+
+			let verifier = if ConfidentialTasks::<T>::contains_key(task_id) {
+				None
+			} else {
+				Self::select_worker(
+					&Self::worker_type_for(task.task_kind.class()),
+					TaskRequirements::<T>::get(task_id).as_ref(),
+					&[worker.0.clone(), who.clone()],
+					false,
+					(b"dispute", task_id),
+				)
+				.ok()
+			};
+
+			DisputableResults::<T>::remove(task_id);
+			TaskDeadlines::<T>::remove(task_id);
+			TaskDisputes::<T>::insert(
+				task_id,
+				TaskDispute {
+					disputer: who.clone(),
+					bond,
+					worker,
+					verifier: verifier.clone(),
+				},
+			);
+			task.task_status = TaskStatusType::Disputed;
+			Self::store_task(task_id, task);
+
+			Self::deposit_event(Event::TaskDisputed {
+				task_id,
+				disputer: who,
+				bond,
+				verifier,
+			});
+			Ok(())
+		}
+
+		/// The worker picked to re-run a disputed task submits the result it obtained.
+		/// Disputed -> Completed, if the result matches the disputed one
+		/// Disputed -> Failed, otherwise
+		#[pallet::call_index(21)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::submit_dispute_verification(
+			result.len() as u32,
+		)
+		.saturating_add(Pallet::<T>::completion_weight()))]
+		pub fn submit_dispute_verification(
+			origin: OriginFor<T>,
+			task_id: TaskId,
+			result: BoundedVec<u8, ConstU32<500>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let task = Tasks::<T>::get(task_id).ok_or(Error::<T>::TaskNotFound)?;
+			let dispute = TaskDisputes::<T>::get(task_id).ok_or(Error::<T>::TaskNotDisputed)?;
+			ensure!(
+				dispute.verifier.as_ref().is_some_and(|verifier| verifier.0 == who),
+				Error::<T>::RequireDisputeVerifier
+			);
+
+			let disputed_hash = T::Hashing::hash(&task.result.clone().unwrap_or_default());
+			let worker_at_fault = T::Hashing::hash(&result) != disputed_hash;
+			Self::resolve_task_dispute(task_id, task, dispute, worker_at_fault);
+
+			Ok(())
+		}
+
+		/// `T::DisputeOrigin` decides a dispute, whether or not a worker re-ran the task.
+		/// Disputed -> Failed, if `worker_at_fault`
+		/// Disputed -> Completed, otherwise
+		#[pallet::call_index(22)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::resolve_dispute()
+			.saturating_add(Pallet::<T>::completion_weight()))]
+		pub fn resolve_dispute(
+			origin: OriginFor<T>,
+			task_id: TaskId,
+			worker_at_fault: bool,
+		) -> DispatchResult {
+			T::DisputeOrigin::ensure_origin(origin)?;
+
+			let task = Tasks::<T>::get(task_id).ok_or(Error::<T>::TaskNotFound)?;
+			let dispute = TaskDisputes::<T>::get(task_id).ok_or(Error::<T>::TaskNotDisputed)?;
+			Self::resolve_task_dispute(task_id, task, dispute, worker_at_fault);
+
+			Ok(())
+		}
+
+		/// Takes over a model offered to the caller with `transfer_model`.
+		///
+		/// The deposit of the model and its versions is reserved from the caller and the
+		/// previous owner gets its own back.
+		#[pallet::call_index(23)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::accept_model_transfer())]
+		pub fn accept_model_transfer(origin: OriginFor<T>, model_id: ModelId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				ModelTransferOffers::<T>::get(model_id).as_ref() == Some(&who),
+				Error::<T>::NoModelTransferOffer
+			);
+			let mut model = Models::<T>::get(model_id).ok_or(Error::<T>::ModelNotFound)?;
+
+			let deposit = ModelDeposits::<T>::get(model_id);
+			<T as pallet_payment::Config>::Currency::reserve(&who, deposit)?;
+			<T as pallet_payment::Config>::Currency::unreserve(&model.owner, deposit);
+
+			ModelTransferOffers::<T>::remove(model_id);
+			model.owner = who.clone();
+			Models::<T>::insert(model_id, model);
+
+			Self::deposit_event(Event::ModelTransferred { model_id, new_owner: who });
+			Ok(())
+		}
+
+		/// Removes a version of a model of the caller and returns its deposit. Tasks already
+		/// running it are not affected, and its number is not reused.
+		#[pallet::call_index(24)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_model_version())]
+		pub fn remove_model_version(
+			origin: OriginFor<T>,
+			model_id: ModelId,
+			version: ModelVersionId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let model = Models::<T>::get(model_id).ok_or(Error::<T>::ModelNotFound)?;
+			ensure!(model.owner == who, Error::<T>::NotModelOwner);
+			ensure!(
+				ModelVersions::<T>::contains_key(model_id, version),
+				Error::<T>::ModelVersionNotFound
+			);
+
+			ModelVersions::<T>::remove(model_id, version);
+			let deposit = ModelDeposits::<T>::mutate(model_id, |reserved| {
+				let deposit = T::ModelVersionDeposit::get().min(*reserved);
+				*reserved = reserved.saturating_sub(deposit);
+				deposit
+			});
+			<T as pallet_payment::Config>::Currency::unreserve(&who, deposit);
+
+			Self::deposit_event(Event::ModelVersionRemoved { model_id, version });
+			Ok(())
+		}
+
+		/// Removes a model of the caller without versions from the model registry and returns
+		/// its deposit. A pending transfer offer is withdrawn.
+		#[pallet::call_index(25)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_model())]
+		pub fn remove_model(origin: OriginFor<T>, model_id: ModelId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let model = Models::<T>::get(model_id).ok_or(Error::<T>::ModelNotFound)?;
+			ensure!(model.owner == who, Error::<T>::NotModelOwner);
+			ensure!(
+				ModelVersions::<T>::iter_prefix(model_id).next().is_none(),
+				Error::<T>::ModelHasVersions
+			);
+
+			let deposit = ModelDeposits::<T>::take(model_id);
+			<T as pallet_payment::Config>::Currency::unreserve(&who, deposit);
+			Models::<T>::remove(model_id);
+			ModelTransferOffers::<T>::remove(model_id);
+
+			Self::deposit_event(Event::ModelRemoved { model_id });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Returns the workers of `worker_type` that can currently take a new task: they must be
+		/// `Active`, pass the reputation checks of edge-connect and satisfy `requirements`.
+		pub fn eligible_workers(
+			worker_type: &WorkerType,
+			requirements: Option<&ResourceRequirements>,
+		) -> Vec<(T::AccountId, WorkerId)> {
+			let active = pallet_edge_connect::Pallet::<T>::active_worker_count(worker_type);
+			(0..active)
+				.filter_map(|position| {
+					pallet_edge_connect::Pallet::<T>::active_worker_at(worker_type, position)
+				})
+				.filter(|worker_key| Self::eligible_worker(worker_key, worker_type, requirements).is_some())
+				.collect()
+		}
+
+		/// The worker `worker_key` of `worker_type`, if it can currently take a new task.
+		fn eligible_worker(
+			worker_key: &(T::AccountId, WorkerId),
+			worker_type: &WorkerType,
+			requirements: Option<&ResourceRequirements>,
+		) -> Option<Worker<T::AccountId, BlockNumberFor<T>, T::Moment>> {
+			let worker = Self::worker(worker_key, worker_type)?;
+			let meets_requirements = requirements
+				.is_none_or(|requirements| requirements.is_satisfied_by(&worker.specs, &worker.location));
+			let is_eligible = worker.status == WorkerStatusType::Active
+				&& meets_requirements
+				&& Self::has_capacity(worker_key, &worker)
+				&& pallet_edge_connect::Pallet::<T>::check_worker_status(worker_key, worker_type.clone())
+					.is_ok();
+			is_eligible.then_some(worker)
+		}
+
+		/// Picks one of the eligible workers of `worker_type` that is not owned by any of the
+		/// `excluded` accounts, using `T::Randomness`. With `needs_encryption_key`, only workers
+		/// that published an encryption key are considered.
+		///
+		/// Only `T::MaxWorkerCandidates` active workers are considered, following a random position
+		/// in the active workers of edge-connect, so the cost does not grow with their number.
+		///
+		/// `subject` is mixed into the random seed so that tasks scheduled within the same block
+		/// are not all sent to the same worker.
+		fn select_worker(
+			worker_type: &WorkerType,
+			requirements: Option<&ResourceRequirements>,
+			excluded: &[T::AccountId],
+			needs_encryption_key: bool,
+			subject: impl Encode,
+		) -> Result<(T::AccountId, WorkerId), Error<T>> {
+			let active = pallet_edge_connect::Pallet::<T>::active_worker_count(worker_type);
+			ensure!(active > 0, Error::<T>::NoWorkersAvailable);
+
+			let (seed, _) = T::Randomness::random(&(b"task_scheduler", subject).encode());
+			let random_number = u32::decode(&mut seed.as_ref()).unwrap_or_default();
+			let first = random_number % active;
+
+			let candidates: Vec<_> = (0..active.min(T::MaxWorkerCandidates::get()))
+				.filter_map(|offset| {
+					let position = (u64::from(first) + u64::from(offset)) % u64::from(active);
+					pallet_edge_connect::Pallet::<T>::active_worker_at(worker_type, position as u32)
+				})
+				.filter(|(owner, _)| !excluded.contains(owner))
+				.filter(|worker_key| {
+					Self::eligible_worker(worker_key, worker_type, requirements)
+						.is_some_and(|worker| !needs_encryption_key || worker.encryption_key.is_some())
+				})
+				.collect();
+			ensure!(!candidates.is_empty(), Error::<T>::NoWorkersAvailable);
+
+			let index = random_number as usize % candidates.len();
+
+			Ok(candidates[index].clone())
+		}
+
+		/// Weight of `selections` calls to `select_worker`. Each reads the number of active
+		/// workers, then for each candidate its position, the worker, read again by edge-connect,
+		/// and its allocations.
+		pub fn selection_weight(selections: u32) -> Weight {
+			let reads = 1 + 4 * u64::from(T::MaxWorkerCandidates::get());
+			T::DbWeight::get().reads(reads.saturating_mul(selections.into()))
+		}
+
+		/// Weight of picking workers in a call that can complete a task: up to two workers for
+		/// the task itself, and one for every child of a graph task it releases. The worker it
+		/// frees is handed queued tasks.
+		pub fn completion_weight() -> Weight {
+			Self::selection_weight(T::MaxGraphNodes::get().saturating_add(2))
+				.saturating_add(Self::dispatch_weight())
+		}
+
+		/// The version `version` of the model `model_id`, for a new task to run.
+		fn task_model_for(
+			model_id: ModelId,
+			version: ModelVersionId,
+		) -> Result<TaskModel<T::Hash>, Error<T>> {
+			ensure!(
+				Models::<T>::contains_key(model_id),
+				Error::<T>::ModelNotFound
+			);
+			let model_version =
+				ModelVersions::<T>::get(model_id, version).ok_or(Error::<T>::ModelVersionNotFound)?;
+			ensure!(
+				!model_version.deprecated,
+				Error::<T>::ModelVersionDeprecated
+			);
+
+			Ok(TaskModel {
+				model_id,
+				version,
+				artifact_hash: model_version.artifact_hash,
+			})
+		}
+
+		/// Versions of the model `model_id`, oldest first.
+		pub fn model_versions(
+			model_id: ModelId,
+		) -> Vec<(ModelVersionId, ModelVersion<T::Hash, BlockNumberFor<T>>)> {
+			let mut versions: Vec<_> = ModelVersions::<T>::iter_prefix(model_id).collect();
+			versions.sort_by_key(|(version, _)| *version);
+			versions
+		}
+
+		/// The kind of worker able to run tasks of `task_class`.
+		pub fn worker_type_for(task_class: TaskClass) -> WorkerType {
+			match task_class {
+				TaskClass::NeuroZK => WorkerType::Executable,
+				TaskClass::OpenInference => WorkerType::Executable,
+				TaskClass::Container => WorkerType::Docker,
+			}
+		}
+
+		/// The worker `worker_key` of `worker_type`, if it is registered.
+		fn worker(
+			worker_key: &(T::AccountId, WorkerId),
+			worker_type: &WorkerType,
+		) -> Option<Worker<T::AccountId, BlockNumberFor<T>, T::Moment>> {
+			match worker_type {
+				WorkerType::Docker => WorkerClusters::<T>::get(worker_key),
+				WorkerType::Executable => ExecutableWorkers::<T>::get(worker_key),
+			}
+		}
+
+		/// Stores `task` and its current status, and brings the task indexes up to date.
+		fn store_task(task_id: TaskId, task: TaskInfo<T::AccountId, BlockNumberFor<T>>) {
+			let status = task.task_status.clone();
+			Self::index_task(task_id, &task);
+			let task_kind = task.task_kind.clone();
+
+			Tasks::<T>::insert(task_id, task);
+			TaskStatus::<T>::insert(task_id, status.clone());
+			// Last, since a worker it frees is handed queued tasks right away
+			Self::update_occupied_worker(task_id, &task_kind, &status);
+		}
+
+		/// Records `task` in `TasksByOwner`, and in `TasksByWorker` for the workers it needs in
+		/// its current status.
+		pub(crate) fn index_task(task_id: TaskId, task: &TaskInfo<T::AccountId, BlockNumberFor<T>>) {
+			let status = &task.task_status;
+			TasksByOwner::<T>::insert(&task.task_owner, task_id, status.clone());
+
+			// A stopped task keeps its worker until the worker vacated it
+			let needs_workers = matches!(
+				status,
+				TaskStatusType::Assigned
+					| TaskStatusType::Running
+					| TaskStatusType::PendingValidation
+					| TaskStatusType::PendingResolution
+					| TaskStatusType::Stopped
+					| TaskStatusType::Disputed
+			);
+			if needs_workers {
+				for worker in Self::task_workers(task_id) {
+					TasksByWorker::<T>::insert(worker, task_id, status.clone());
+				}
+			} else {
+				Self::release_workers(task_id);
+			}
+		}
+
+		/// The workers involved in `task_id`: its executor, and its verifier and resolver while
+		/// its result is being verified.
+		fn task_workers(task_id: TaskId) -> Vec<(T::AccountId, WorkerId)> {
+			let mut workers: Vec<_> = TaskAllocations::<T>::get(task_id).into_iter().collect();
+			if let Some(verifications) = TaskVerifications::<T>::get(task_id) {
+				workers.extend(
+					[verifications.verifier, verifications.resolver]
+						.into_iter()
+						.flatten()
+						.map(|verification| verification.worker),
+				);
+			}
+			if let Some(verifier) = TaskDisputes::<T>::get(task_id).and_then(|dispute| dispute.verifier) {
+				workers.push(verifier);
+			}
+			workers
+		}
+
+		/// Removes `task_id` from the tasks of the workers involved in it.
+		fn release_workers(task_id: TaskId) {
+			for worker in Self::task_workers(task_id) {
+				TasksByWorker::<T>::remove(worker, task_id);
+			}
+		}
+
+		/// Whether `worker` can be handed one more task.
+		fn has_capacity(
+			worker_key: &(T::AccountId, WorkerId),
+			worker: &Worker<T::AccountId, BlockNumberFor<T>, T::Moment>,
+		) -> bool {
+			ActiveAllocations::<T>::get(worker_key) < worker.max_concurrent_tasks
+		}
+
+		/// Moves the capacity `task_id` takes up to the worker that runs it in `status`, if any.
+		pub(crate) fn update_occupied_worker(
+			task_id: TaskId,
+			task_kind: &TaskKind,
+			status: &TaskStatusType,
+		) {
+			let previous = OccupiedWorkers::<T>::get(task_id);
+			let occupied = match status {
+				TaskStatusType::Assigned | TaskStatusType::Running => TaskAllocations::<T>::get(task_id),
+				// A stopped task keeps its executor until the executor vacated it. An executor
+				// that already submitted its result has nothing left to vacate
+				TaskStatusType::Stopped => TaskAllocations::<T>::get(task_id).filter(|executor| {
+					previous
+						.as_ref()
+						.is_none_or(|previous| previous == executor)
+				}),
+				TaskStatusType::PendingValidation => TaskVerifications::<T>::get(task_id)
+					.and_then(|verifications| verifications.verifier)
+					.map(|verification| verification.worker),
+				TaskStatusType::PendingResolution => TaskVerifications::<T>::get(task_id)
+					.and_then(|verifications| verifications.resolver)
+					.map(|verification| verification.worker),
+				TaskStatusType::Disputed => {
+					TaskDisputes::<T>::get(task_id).and_then(|dispute| dispute.verifier)
+				}
+				_ => None,
+			};
+			if occupied == previous {
+				return;
+			}
+
+			let worker_type = Self::worker_type_for(task_kind.class());
+			if let Some(previous) = previous {
+				Self::free_worker(&previous, &worker_type);
+			}
+			match occupied {
+				Some(worker) => {
+					Self::occupy_worker(&worker, &worker_type);
+					OccupiedWorkers::<T>::insert(task_id, worker);
+				}
+				None => OccupiedWorkers::<T>::remove(task_id),
+			}
+		}
+
+		/// Counts one more task against the capacity of `worker_key`, which becomes `Busy` once it
+		/// runs as many tasks as it accepts.
+		fn occupy_worker(worker_key: &(T::AccountId, WorkerId), worker_type: &WorkerType) {
+			let active = ActiveAllocations::<T>::mutate(worker_key, |active| {
+				*active = active.saturating_add(1);
+				*active
+			});
+			Self::mutate_worker(worker_key, worker_type, |worker| {
+				if worker.status == WorkerStatusType::Active && active >= worker.max_concurrent_tasks {
+					worker.status = WorkerStatusType::Busy;
+				}
+			});
+		}
+
+		/// Releases a task counted by `occupy_worker`. A worker that was `Busy` because it was at
+		/// capacity becomes `Active` again, and is handed the queued tasks it can take right away.
+		/// Calls that can free a worker charge `dispatch_weight` for it.
+		fn free_worker(worker_key: &(T::AccountId, WorkerId), worker_type: &WorkerType) {
+			let active = ActiveAllocations::<T>::get(worker_key);
+			let remaining = active.saturating_sub(1);
+			if remaining == 0 {
+				ActiveAllocations::<T>::remove(worker_key);
+			} else {
+				ActiveAllocations::<T>::insert(worker_key, remaining);
+			}
+			Self::mutate_worker(worker_key, worker_type, |worker| {
+				let was_full = active >= worker.max_concurrent_tasks;
+				if worker.status == WorkerStatusType::Busy
+					&& was_full
+					&& remaining < worker.max_concurrent_tasks
+				{
+					worker.status = WorkerStatusType::Active;
+				}
+			});
+			Self::dispatch_pending_tasks(worker_key, worker_type);
+		}
+
+		/// Applies `f` to the worker `worker_key` of `worker_type`, if it is still registered.
+		fn mutate_worker(
+			worker_key: &(T::AccountId, WorkerId),
+			worker_type: &WorkerType,
+			f: impl FnOnce(&mut Worker<T::AccountId, BlockNumberFor<T>, T::Moment>),
+		) {
+			if let Some(mut worker) = Self::worker(worker_key, worker_type) {
+				f(&mut worker);
+				pallet_edge_connect::Pallet::<T>::store_worker(worker_key, worker_type, worker);
+			}
+		}
+
+		/// Up to `limit` tasks of `owner` with their status, following `start_after` if given.
+		///
+		/// Tasks are listed in a stable but arbitrary order, `start_after` being the last task of
+		/// the previous page. `limit` is capped at [`MAX_TASKS_PER_QUERY`].
+		pub fn tasks_by_owner(
+			owner: &T::AccountId,
+			start_after: Option<TaskId>,
+			limit: u32,
+		) -> Vec<(TaskId, TaskStatusType)> {
+			let tasks = match start_after {
+				Some(task_id) => TasksByOwner::<T>::iter_prefix_from(
+					owner,
+					TasksByOwner::<T>::hashed_key_for(owner, task_id),
+				),
+				None => TasksByOwner::<T>::iter_prefix(owner),
+			};
+			tasks
+				.take(limit.min(MAX_TASKS_PER_QUERY) as usize)
+				.collect()
+		}
+
+		/// Up to `limit` tasks `worker` is currently involved in with their status, following
+		/// `start_after` if given. Paginated like [`Self::tasks_by_owner`].
+		pub fn tasks_by_worker(
+			worker: &(T::AccountId, WorkerId),
+			start_after: Option<TaskId>,
+			limit: u32,
+		) -> Vec<(TaskId, TaskStatusType)> {
+			let tasks = match start_after {
+				Some(task_id) => TasksByWorker::<T>::iter_prefix_from(
+					worker,
+					TasksByWorker::<T>::hashed_key_for(worker, task_id),
+				),
+				None => TasksByWorker::<T>::iter_prefix(worker),
+			};
+			tasks
+				.take(limit.min(MAX_TASKS_PER_QUERY) as usize)
+				.collect()
+		}
+
+		/// Tasks of `task_class` waiting for a worker with their priority, in the order they are
+		/// dispatched.
+		pub fn pending_tasks(task_class: TaskClass) -> Vec<(TaskId, TaskPriority)> {
+			TaskPriority::DESCENDING
+				.into_iter()
+				.flat_map(|priority| {
+					PendingTasks::<T>::get(task_class, priority)
+						.into_iter()
+						.map(move |task_id| (task_id, priority))
+				})
+				.collect()
+		}
+
+		/// What running a task of `task_kind` for `compute_hours` compute hours costs at the
+		/// current subscription fee. Every kind of task is priced the same for now.
+		pub fn task_cost_estimate(_task_kind: &TaskKind, compute_hours: u32) -> BalanceOf<T> {
+			pallet_payment::Pallet::<T>::compute_hours_cost(compute_hours)
+		}
+
+		/// Builds the zk data of a task of `task_kind`, checking that zk files are given with, and
+		/// only with, NeuroZK tasks.
+		fn nzk_data_for(
+			task_kind: &TaskKind,
+			nzk_info: Option<NeuroZkTaskSubmissionDetails>,
+		) -> Result<Option<NzkData<BlockNumberFor<T>>>, Error<T>> {
+			match task_kind {
+				// For NeuroZK, zk_info must be present
+				TaskKind::NeuroZK => {
+					let data = nzk_info.ok_or(Error::<T>::ZkFilesMissing)?;
+					Ok(Some(NzkData {
+						zk_input: data.zk_input,
+						zk_settings: data.zk_settings,
+						zk_verifying_key: data.zk_verifying_key,
+						zk_proof: None,
+						last_proof_accepted: None,
+					}))
+				}
+				TaskKind::OpenInference | TaskKind::Container(_) => {
+					ensure!(nzk_info.is_none(), Error::<T>::UnexpectedZkFiles);
+					Ok(None)
+				}
+			}
+		}
+
+		/// Builds a new task of `who`, which still has to be stored and scheduled.
+		fn new_task_info(
+			who: &T::AccountId,
+			task_kind: TaskKind,
+			task_location: BoundedVec<u8, ConstU32<500>>,
+			nzk_info: Option<NeuroZkTaskSubmissionDetails>,
+			deposit: u32,
+		) -> Result<TaskInfo<T::AccountId, BlockNumberFor<T>>, Error<T>> {
+			let nzk_data = Self::nzk_data_for(&task_kind, nzk_info)?;
+
+			Ok(TaskInfo {
+				task_owner: who.clone(),
+				create_block: <frame_system::Pallet<T>>::block_number(),
+				metadata: task_location,
+				nzk_data,
+				time_elapsed: None,
+				average_cpu_percentage_use: None,
+				task_kind,
+				result: None,
+				compute_hours_deposit: Some(deposit),
+				consume_compute_hours: None,
+				task_status: TaskStatusType::Pending,
+			})
+		}
+
+		/// Hands `task` to a randomly selected worker, or puts it in the pending queue with
+		/// `priority` when no worker can take it.
+		fn schedule_task(
+			task_id: TaskId,
+			mut task: TaskInfo<T::AccountId, BlockNumberFor<T>>,
+			priority: TaskPriority,
+		) -> DispatchResult {
+			let selected_worker = Self::select_worker(
+				&Self::worker_type_for(task.task_kind.class()),
+				TaskRequirements::<T>::get(task_id).as_ref(),
+				&[],
+				ConfidentialTasks::<T>::contains_key(task_id),
+				task_id,
+			);
+			if let Ok(selected_worker) = selected_worker {
+				Self::assign_task(task_id, task, selected_worker);
+				return Ok(());
+			}
+
+			PendingTasks::<T>::try_mutate(task.task_kind.class(), priority, |queue| {
+				queue.try_push(task_id)
+			})
+			.map_err(|_| Error::<T>::PendingQueueFull)?;
+
+			let event = Event::TaskQueued {
+				task_id,
+				task_kind: task.task_kind.clone(),
+				priority,
+				task_owner: task.task_owner.clone(),
+			};
+
+			task.task_status = TaskStatusType::Pending;
+			Self::store_task(task_id, task);
+			Self::deposit_event(event);
+
+			Ok(())
+		}
+
+		/// Hands `task`, freshly scheduled or taken from the pending queue, to `worker`, which then
+		/// has `T::ReceptionTimeout` blocks to confirm its reception.
+		fn assign_task(
+			task_id: TaskId,
+			mut task: TaskInfo<T::AccountId, BlockNumberFor<T>>,
+			worker: (T::AccountId, WorkerId),
+		) {
+			TaskAllocations::<T>::insert(task_id, worker.clone());
+			task.task_status = TaskStatusType::Assigned;
+
+			let event = Event::TaskScheduled {
+				assigned_worker: worker,
+				task_kind: task.task_kind.clone(),
+				task_owner: task.task_owner.clone(),
+				task_id,
+				task: task.metadata.clone(),
+			};
+
+			Self::store_task(task_id, task);
+			Self::set_deadline(task_id, T::ReceptionTimeout::get());
+			Self::deposit_event(event);
+		}
+
+		/// Hands the most urgent queued tasks that `worker_key` can run to it, until it is at
+		/// capacity or `MaxTasksPerDispatch` tasks were handed. Among tasks of the same priority
+		/// the one with the highest tip goes first, then the oldest one. Returns the weight used.
+		fn dispatch_pending_tasks(
+			worker_key: &(T::AccountId, WorkerId),
+			worker_type: &WorkerType,
+		) -> Weight {
+			let mut weight = Weight::zero();
+			for _ in 0..T::MaxTasksPerDispatch::get() {
+				let (used, assigned) = Self::dispatch_pending_task(worker_key, worker_type);
+				weight.saturating_accrue(used);
+				if !assigned {
+					break;
+				}
+			}
+			weight
+		}
+
+		/// Upper bound of the weight of `dispatch_pending_tasks`, charged by the calls that can
+		/// free a worker.
+		pub fn dispatch_weight() -> Weight {
+			<T as pallet::Config>::WeightInfo::dispatch_pending_task()
+				.saturating_mul(T::MaxTasksPerDispatch::get().into())
+		}
+
+		/// Hands the most urgent queued task `worker_key` can run to it, if it is `Active` with
+		/// spare capacity. Returns the weight used and whether a task was assigned.
+		pub(crate) fn dispatch_pending_task(
+			worker_key: &(T::AccountId, WorkerId),
+			worker_type: &WorkerType,
+		) -> (Weight, bool) {
+			// The worker, read again by edge-connect, its allocations and marking it `Busy`
+			let mut weight = T::DbWeight::get().reads_writes(3, 1);
+			let Some(worker) = Self::worker(worker_key, worker_type) else {
+				return (weight, false);
+			};
+			let is_eligible = worker.status == WorkerStatusType::Active
+				&& pallet_edge_connect::Pallet::<T>::check_worker_status(
+					worker_key,
+					worker_type.clone(),
+				)
+				.is_ok();
+			if !is_eligible {
+				return (weight, false);
+			}
+			// The status aggregator may report a worker at capacity as available
+			if !Self::has_capacity(worker_key, &worker) {
+				Self::mutate_worker(worker_key, worker_type, |worker| {
+					worker.status = WorkerStatusType::Busy
+				});
+				return (weight, false);
+			}
+
+			let task_classes: Vec<_> = TaskClass::ALL
+				.into_iter()
+				.filter(|task_class| Self::worker_type_for(*task_class) == *worker_type)
+				.collect();
+			for priority in TaskPriority::DESCENDING {
+				let queued: Vec<_> = task_classes
+					.iter()
+					.flat_map(|task_class| {
+						PendingTasks::<T>::get(task_class, priority)
+							.into_iter()
+							.map(move |task_id| (task_id, *task_class))
+					})
+					.collect();
+				// The queues, then the requirements, confidentiality and tip of every queued task
+				weight.saturating_accrue(
+					T::DbWeight::get()
+						.reads((task_classes.len() as u64).saturating_add(3 * queued.len() as u64)),
+				);
+
+				let next = queued
+					.into_iter()
+					.filter(|(task_id, _)| {
+						TaskRequirements::<T>::get(task_id).is_none_or(|requirements| {
+							requirements.is_satisfied_by(&worker.specs, &worker.location)
+						})
+					})
+					.filter(|(task_id, _)| {
+						worker.encryption_key.is_some() || !ConfidentialTasks::<T>::contains_key(task_id)
+					})
+					.max_by_key(|(task_id, _)| {
+						(TaskTips::<T>::get(task_id).unwrap_or_default(), Reverse(*task_id))
+					});
+
+				if let Some((task_id, task_class)) = next {
+					PendingTasks::<T>::mutate(task_class, priority, |queue| {
+						queue.retain(|queued| *queued != task_id)
+					});
+					if let Some(task) = Tasks::<T>::get(task_id) {
+						Self::assign_task(task_id, task, worker_key.clone());
+					}
+					// Taking the task out of its queue and assigning it
+					weight.saturating_accrue(T::DbWeight::get().reads_writes(4, 10));
+					return (weight, true);
+				}
+			}
+			(weight, false)
+		}
+
+		/// Takes `task_id` out of the pending queue of its class.
+		fn remove_pending_task(task_id: TaskId, task_class: TaskClass) {
+			for priority in TaskPriority::DESCENDING {
+				PendingTasks::<T>::mutate(task_class, priority, |queue| {
+					queue.retain(|queued| *queued != task_id)
+				});
+			}
+		}
+
+		/// Marks `task` as completed, refunds the unused part of its deposit and stores it.
+		///
+		/// `worker` is the worker whose result was accepted. The tasks of its graph waiting for
+		/// the result are only released once the result can no longer be disputed. Returns the
+		/// number of them scheduled.
+		fn complete_task(
+			task_id: TaskId,
+			mut task: TaskInfo<T::AccountId, BlockNumberFor<T>>,
+			worker: (T::AccountId, WorkerId),
+		) -> u32 {
+			task.task_status = TaskStatusType::Completed;
+			Self::refund_unused_deposit(task_id, &task);
+			// The deadline of the verifier or resolver, if any
+			TaskDeadlines::<T>::remove(task_id);
+			let disputable = !T::DisputeWindow::get().is_zero();
+			if !disputable {
+				Self::accept_result(task_id, &worker, &task.task_kind);
+			} else {
+				DisputableResults::<T>::insert(task_id, worker.clone());
+				Self::set_deadline(task_id, T::DisputeWindow::get());
+			}
+
+			let event = Event::TaskCompleted {
+				task_id,
+				worker,
+				result: task.result.clone().unwrap_or_default(),
+				time_elapsed: task.time_elapsed.unwrap_or_default(),
+				consumed_compute_hours: task.consume_compute_hours.unwrap_or_default(),
+			};
+
+			Self::store_task(task_id, task);
+			Self::queue_for_pruning(task_id);
+			Self::deposit_event(event);
+
+			if disputable {
+				return 0;
+			}
+			Self::release_children(task_id)
+		}
+
+		/// The results of the parents of `task_id`, or `None` while any of them has not completed
+		/// or its result can still be disputed.
+		pub fn parent_results(
+			task_id: TaskId,
+		) -> Option<Vec<(TaskId, BoundedVec<u8, ConstU32<500>>)>> {
+			TaskParents::<T>::get(task_id)
+				.into_iter()
+				.map(|parent| {
+					Tasks::<T>::get(parent)
+						.filter(|task| {
+							task.task_status == TaskStatusType::Completed
+								&& !DisputableResults::<T>::contains_key(parent)
+						})
+						.map(|task| (parent, task.result.unwrap_or_default()))
+				})
+				.collect()
+		}
+
+		/// Schedules the children of the completed task `task_id` whose parents all completed.
+		/// A child that cannot be scheduled because the pending queue is full fails.
+		///
+		/// The children stay recorded, so that `task_id` is not pruned while some of them still
+		/// wait for other parents. Returns the number of children scheduled.
+		fn release_children(task_id: TaskId) -> u32 {
+			let mut scheduled = 0;
+			for child in TaskChildren::<T>::get(task_id) {
+				let Some(task) = Tasks::<T>::get(child) else {
+					continue;
+				};
+				if task.task_status != TaskStatusType::WaitingForDependencies {
+					continue;
+				}
+				let Some(parent_results) = Self::parent_results(child) else {
+					continue;
+				};
+
+				Self::deposit_event(Event::TaskDependenciesMet { task_id: child, parent_results });
+				scheduled += 1;
+				if Self::schedule_task(child, task.clone(), TaskPriority::default()).is_err() {
+					Self::fail_task(child, task);
+				}
+			}
+			scheduled
+		}
+
+		/// Marks `task` as failed and refunds its whole deposit and its tip, then fails the tasks
+		/// of its task graph that depend on it.
+		fn fail_task(task_id: TaskId, mut task: TaskInfo<T::AccountId, BlockNumberFor<T>>) {
+			TaskDeadlines::<T>::remove(task_id);
+			task.consume_compute_hours = Some(0);
+			task.task_status = TaskStatusType::Failed;
+			Self::refund_unused_deposit(task_id, &task);
+			Self::refund_tip(task_id, &task.task_owner);
+			Self::store_task(task_id, task);
+			Self::queue_for_pruning(task_id);
+
+			Self::deposit_event(Event::TaskFailed { task_id });
+
+			Self::fail_dependents(task_id);
+		}
+
+		/// Fails every task waiting, directly or not, for `task_id`, which will never complete,
+		/// and refunds their deposits.
+		fn fail_dependents(task_id: TaskId) {
+			let mut failed = Vec::from([task_id]);
+			while let Some(parent) = failed.pop() {
+				for child in TaskChildren::<T>::take(parent) {
+					let Some(mut task) = Tasks::<T>::get(child) else {
+						continue;
+					};
+					// Already failed because of another parent
+					if task.task_status != TaskStatusType::WaitingForDependencies {
+						continue;
+					}
+
+					task.consume_compute_hours = Some(0);
+					task.task_status = TaskStatusType::Failed;
+					Self::refund_unused_deposit(child, &task);
+					Self::store_task(child, task);
+					Self::queue_for_pruning(child);
+					Self::deposit_event(Event::TaskFailed { task_id: child });
+
+					failed.push(child);
+				}
+			}
+		}
+
+		/// Hands `task` to a new worker that is not owned by any of the `excluded` accounts and
+		/// discards everything recorded about the previous execution, which is not charged.
+		///
+		/// When no such worker is available the task fails and its whole deposit is refunded, as
+		/// are those of the tasks depending on it.
+		fn reassign_task(
+			task_id: TaskId,
+			mut task: TaskInfo<T::AccountId, BlockNumberFor<T>>,
+			excluded: &[T::AccountId],
+		) {
+			Self::release_workers(task_id);
+			TaskVerifications::<T>::remove(task_id);
+			ComputeAggregations::<T>::remove(task_id);
+			// The payload was encrypted to the previous worker
+			TaskPayloads::<T>::remove(task_id);
+			task.result = None;
+			task.time_elapsed = None;
+			task.average_cpu_percentage_use = None;
+			task.consume_compute_hours = None;
+
+			let new_worker = Self::select_worker(
+				&Self::worker_type_for(task.task_kind.class()),
+				TaskRequirements::<T>::get(task_id).as_ref(),
+				excluded,
+				ConfidentialTasks::<T>::contains_key(task_id),
+				(b"executor", task_id),
+			);
+
+			match new_worker {
+				Ok(assigned_worker) => {
+					TaskAllocations::<T>::insert(task_id, assigned_worker.clone());
+					task.task_status = TaskStatusType::Assigned;
+					Self::store_task(task_id, task);
+					Self::set_deadline(task_id, T::ReceptionTimeout::get());
+
+					Self::deposit_event(Event::TaskReassigned { task_id, assigned_worker });
+				}
+				Err(_) => Self::fail_task(task_id, task),
+			}
+		}
+
+		/// Decides the dispute against the result of `task_id`. A worker at fault is penalized,
+		/// its tip and the compute hours it charged go back to the owner along with the bond, and
+		/// the task fails along with the tasks of its graph waiting for it. Otherwise the bond is
+		/// forfeited, the tip is paid to the worker and the waiting tasks are released.
+		fn resolve_task_dispute(
+			task_id: TaskId,
+			mut task: TaskInfo<T::AccountId, BlockNumberFor<T>>,
+			dispute: TaskDispute<T::AccountId, BalanceOf<T>>,
+			worker_at_fault: bool,
+		) {
+			if worker_at_fault {
+				Self::penalize_false_completion(
+					&dispute.worker,
+					&Self::worker_type_for(task.task_kind.class()),
+				);
+				if let Err(error) =
+					pallet_payment::Pallet::<T>::refund_bond(&dispute.disputer, dispute.bond)
+				{
+					log::warn!(
+						"Failed to refund the bond of task {:?}: {:?}",
+						task_id,
+						error
+					);
+				}
+				Self::refund_tip(task_id, &task.task_owner);
+				pallet_payment::Pallet::<T>::refund_compute_hours(
+					&task.task_owner,
+					task.consume_compute_hours.unwrap_or_default(),
+				);
+				task.consume_compute_hours = Some(0);
+				task.task_status = TaskStatusType::Failed;
+			} else {
+				Self::accept_result(task_id, &dispute.worker, &task.task_kind);
+				task.task_status = TaskStatusType::Completed;
+			}
+
+			// Storing the task releases the verifier, which is only known while disputed
+			Self::store_task(task_id, task);
+			TaskDisputes::<T>::remove(task_id);
+
+			Self::deposit_event(Event::DisputeResolved {
+				task_id,
+				worker_at_fault,
+			});
+
+			if worker_at_fault {
+				Self::fail_dependents(task_id);
+			} else {
+				Self::release_children(task_id);
+			}
+		}
+		/// Deducts `T::FalseCompletionPenalty` from the reputation of a worker whose result was
+		/// outvoted. A worker that has been removed in the meantime is skipped.
+		fn penalize_false_completion(worker: &(T::AccountId, WorkerId), worker_type: &WorkerType) {
+			let _ = pallet_edge_connect::Pallet::<T>::apply_penalty(
+				worker,
+				worker_type,
+				T::FalseCompletionPenalty::get(),
+				PenaltyReason::FalseCompletion,
+			);
+		}
+
+		/// Ensures `origin` may stop a task of `task_owner`: root, the gatekeeper or the owner.
+		fn ensure_task_controller(origin: OriginFor<T>, task_owner: &T::AccountId) -> DispatchResult {
+			if let Some(who) = ensure_signed_or_root(origin)? {
+				let is_gatekeeper = GatekeeperAccount::<T>::get().as_ref() == Some(&who);
+				ensure!(
+					*task_owner == who || is_gatekeeper,
+					Error::<T>::NotTaskOwner
+				);
+			}
+			Ok(())
+		}
+
+		/// Stops `task`, charging the compute hours it used so far and refunding the rest of its
+		/// deposit, and fails the tasks of its task graph that depend on it. Returns the compute
+		/// hours charged.
+		fn stop_task(task_id: TaskId, mut task: TaskInfo<T::AccountId, BlockNumberFor<T>>) -> u32 {
+			TaskDeadlines::<T>::remove(task_id);
+
+			let verifying = matches!(
+				task.task_status,
+				TaskStatusType::PendingValidation | TaskStatusType::PendingResolution
+			);
+			let awaiting_reception = task.task_status == TaskStatusType::Assigned;
+			if verifying {
+				// Only the executor is left in charge of the stopped task, and its capacity is
+				// freed along with the verifier or resolver since it is done running it
+				Self::release_workers(task_id);
+				TaskVerifications::<T>::remove(task_id);
+			} else {
+				// A task that never started running has no open aggregation and uses nothing
+				let time_elapsed = Self::close_compute_aggregation(task_id);
+				task.time_elapsed = Some(time_elapsed);
+				let mut consumed = Self::compute_hours_for(time_elapsed);
+				if awaiting_reception {
+					consumed = consumed
+						.max(T::CancellationCharge::get())
+						.min(task.compute_hours_deposit.unwrap_or_default());
+				}
+				task.consume_compute_hours = Some(consumed);
+			}
+			let consumed_compute_hours = task.consume_compute_hours.unwrap_or_default();
+			Self::refund_unused_deposit(task_id, &task);
+			Self::refund_tip(task_id, &task.task_owner);
+
+			task.task_status = TaskStatusType::Stopped;
+			Self::store_task(task_id, task);
+
+			Self::fail_dependents(task_id);
+
+			consumed_compute_hours
+		}
+
+		/// Gives the worker in charge of `task_id` until `timeout` blocks from now to move the
+		/// task forward, replacing any previous deadline of the task.
+		///
+		/// When the block of the deadline is already full, the deadline is pushed to the first
+		/// following block with room left.
+		fn set_deadline(task_id: TaskId, timeout: BlockNumberFor<T>) {
+			let now = <frame_system::Pallet<T>>::block_number();
+			let mut deadline = now.saturating_add(timeout.max(One::one()));
+			loop {
+				let scheduled =
+					ExpiringTasks::<T>::try_mutate(deadline, |tasks| tasks.try_push(task_id));
+				if scheduled.is_ok() {
+					break;
+				}
+				deadline = deadline.saturating_add(One::one());
+			}
+			TaskDeadlines::<T>::insert(task_id, deadline);
+		}
+
+		/// Number of blocks a worker has to run `task`: as many as its compute hours deposit pays
+		/// for, at most `T::ExecutionTimeout`.
+		fn execution_timeout(task: &TaskInfo<T::AccountId, BlockNumberFor<T>>) -> BlockNumberFor<T> {
+			let deposit: BlockNumberFor<T> = task.compute_hours_deposit.unwrap_or_default().into();
+			T::BlocksPerHour::get()
+				.saturating_mul(deposit)
+				.min(T::ExecutionTimeout::get())
+		}
+
+		/// Handles a task whose deadline expired at block `now`.
+		///
+		/// A task still waiting on its assigned worker is handed to a worker of another account,
+		/// or fails and is refunded, and the late worker is penalized. A late verifier or resolver
+		/// is penalized and replaced, see [`Self::expire_verification`]. Deadlines that were
+		/// replaced or cleared since they were set are ignored.
+		///
+		/// Returns the number of tasks of a task graph released, on top of the workers picked for
+		/// the task itself.
+		fn expire_task(task_id: TaskId, now: BlockNumberFor<T>) -> u32 {
+			if TaskDeadlines::<T>::get(task_id) != Some(now) {
+				return 0;
+			}
+			TaskDeadlines::<T>::remove(task_id);
+
+			if let Some(worker) = DisputableResults::<T>::take(task_id) {
+				if let Some(task) = Tasks::<T>::get(task_id) {
+					Self::accept_result(task_id, &worker, &task.task_kind);
+				}
+				Self::deposit_event(Event::DisputeWindowClosed { task_id });
+				return Self::release_children(task_id);
+			}
+
+			let (Some(task), Some(worker)) =
+				(Tasks::<T>::get(task_id), TaskAllocations::<T>::get(task_id))
+			else {
+				return 0;
+			};
+			let task_status = task.task_status.clone();
+			match task_status {
+				TaskStatusType::Assigned | TaskStatusType::Running => {}
+				TaskStatusType::PendingValidation | TaskStatusType::PendingResolution => {
+					return Self::expire_verification(task_id, task);
+				}
+				_ => return 0,
+			}
+
+			// A worker still waiting for the payload of a confidential task is not to blame
+			let awaiting_payload =
+				ConfidentialTasks::<T>::contains_key(task_id) && !TaskPayloads::<T>::contains_key(task_id);
+			if !awaiting_payload {
+				let _ = pallet_edge_connect::Pallet::<T>::apply_penalty(
+					&worker,
+					&Self::worker_type_for(task.task_kind.class()),
+					T::LateResponsePenalty::get(),
+					PenaltyReason::LateResponse,
+				);
+			}
+			Self::deposit_event(Event::TaskTimedOut {
+				task_id,
+				worker: worker.clone(),
+				task_status,
+			});
+
+			Self::reassign_task(task_id, task, &[worker.0]);
+			0
+		}
+
+		/// Penalizes the verifier or resolver of `task_id` that missed its deadline and hands its
+		/// role to a worker of an account not involved so far.
+		///
+		/// Without such a worker, a result nobody could verify is accepted as is, like a result
+		/// submitted when no verifier is available. A result nobody could resolve leaves no
+		/// majority to be found, so the task is handed to a new worker.
+		///
+		/// Returns the number of tasks of a task graph released by accepting the result.
+		fn expire_verification(
+			task_id: TaskId,
+			task: TaskInfo<T::AccountId, BlockNumberFor<T>>,
+		) -> u32 {
+			let Some(mut verifications) = TaskVerifications::<T>::get(task_id) else {
+				return 0;
+			};
+			let task_status = task.task_status.clone();
+			let resolving = task_status == TaskStatusType::PendingResolution;
+			let late = if resolving {
+				&verifications.resolver
+			} else {
+				&verifications.verifier
+			};
+			let Some(late) = late
+				.as_ref()
+				.map(|verification| verification.worker.clone())
+			else {
+				return 0;
+			};
+
+			let worker_type = Self::worker_type_for(task.task_kind.class());
+			let _ = pallet_edge_connect::Pallet::<T>::apply_penalty(
+				&late,
+				&worker_type,
+				T::LateResponsePenalty::get(),
+				PenaltyReason::LateResponse,
+			);
+			Self::deposit_event(Event::TaskTimedOut {
+				task_id,
+				worker: late.clone(),
+				task_status,
+			});
+
+			let mut involved = Vec::from([verifications.executor.worker.0.clone(), late.0]);
+			if resolving {
+				involved.extend(
+					verifications
+						.verifier
+						.as_ref()
+						.map(|verifier| verifier.worker.0.clone()),
+				);
+			}
+			let role: &[u8; 8] = if resolving { b"resolver" } else { b"verifier" };
+			let replacement = Self::select_worker(
+				&worker_type,
+				TaskRequirements::<T>::get(task_id).as_ref(),
+				&involved,
+				false,
+				(role, task_id),
+			);
+
+			// The late worker no longer takes part in the task
+			Self::release_workers(task_id);
+			match replacement {
+				Ok(replacement) => {
+					let assigned = Some(VerificationHashes::assigned(replacement.clone()));
+					if resolving {
+						verifications.resolver = assigned;
+					} else {
+						verifications.verifier = assigned;
+					}
+					TaskVerifications::<T>::insert(task_id, verifications);
+					let execution_timeout = Self::execution_timeout(&task);
+					Self::store_task(task_id, task);
+					Self::set_deadline(task_id, execution_timeout);
+
+					let event = if resolving {
+						Event::ResolverAssigned {
+							task_id,
+							resolver: replacement,
+						}
+					} else {
+						Event::VerifierAssigned {
+							task_id,
+							verifier: replacement,
+						}
+					};
+					Self::deposit_event(event);
+					0
+				}
+				Err(_) if resolving => {
+					Self::reassign_task(task_id, task, &involved);
+					0
+				}
+				Err(_) => {
+					let executor = verifications.executor.worker.clone();
+					verifications.verifier = None;
+					TaskVerifications::<T>::insert(task_id, verifications);
+					Self::complete_task(task_id, task, executor)
+				}
+			}
+		}
+
+		/// Puts the next run of `schedule_id` on the agenda of block `at`, or of the first
+		/// following block with room left, and returns that block.
+		fn add_to_agenda(schedule_id: ScheduleId, at: BlockNumberFor<T>) -> BlockNumberFor<T> {
+			let mut at = at;
+			while TaskAgenda::<T>::try_mutate(at, |due| due.try_push(schedule_id)).is_err() {
+				at = at.saturating_add(One::one());
+			}
+			at
+		}
+
+		/// Starts the run of `schedule_id` due at block `now` as a new task, and puts the next
+		/// run, if any, on the agenda. A run that can be neither assigned nor queued fails and is
+		/// refunded.
+		fn start_scheduled_task(schedule_id: ScheduleId, now: BlockNumberFor<T>) {
+			let Some(mut scheduled) = ScheduledTasks::<T>::get(schedule_id) else {
+				return;
+			};
+			if scheduled.next_run != now {
+				return;
+			}
+
+			let task_info = Self::new_task_info(
+				&scheduled.task_owner,
+				scheduled.task_kind.clone(),
+				scheduled.task_location.clone(),
+				scheduled.nzk_info.clone(),
+				scheduled.compute_hours_deposit,
+			);
+			// Checked when the task was scheduled
+			let Ok(task_info) = task_info else {
+				return;
+			};
+
+			let task_id = NextTaskId::<T>::get();
+			NextTaskId::<T>::put(task_id.wrapping_add(1));
+
+			TaskOwners::<T>::insert(task_id, scheduled.task_owner.clone());
+			if let Some(requirements) = scheduled.requirements.clone() {
+				TaskRequirements::<T>::insert(task_id, requirements);
+			}
+			Self::deposit_event(Event::ScheduledTaskStarted {
+				schedule_id,
+				task_id,
+			});
+			if Self::schedule_task(task_id, task_info.clone(), TaskPriority::default()).is_err() {
+				Self::fail_task(task_id, task_info);
+			}
+
+			scheduled.remaining_runs = scheduled.remaining_runs.saturating_sub(1);
+			match scheduled.interval {
+				Some(interval) if scheduled.remaining_runs > 0 => {
+					scheduled.next_run = Self::add_to_agenda(schedule_id, now.saturating_add(interval));
+					ScheduledTasks::<T>::insert(schedule_id, scheduled);
+				}
+				_ => ScheduledTasks::<T>::remove(schedule_id),
+			}
+		}
+
+		/// Adds `task_id`, which just finished, to the pruning queue.
+		fn queue_for_pruning(task_id: TaskId) {
+			let now = <frame_system::Pallet<T>>::block_number();
+			PruningQueueRange::<T>::mutate(|(_, next)| {
+				PruningQueue::<T>::insert(*next, (task_id, now));
+				*next = next.wrapping_add(1);
+			});
+		}
+
+		/// Prunes the tasks that finished more than `T::TaskRetentionPeriod` blocks before `now`,
+		/// oldest first, as long as `limit` allows. Returns the weight used.
+		fn prune_finished_tasks(now: BlockNumberFor<T>, limit: Weight) -> Weight {
+			let mut used = T::DbWeight::get().reads_writes(1, 1);
+			// Including the status of every child of the task
+			let prune_weight = <T as pallet::Config>::WeightInfo::prune_task()
+				.saturating_add(T::DbWeight::get().reads(T::MaxGraphNodes::get().into()));
+			if !limit.all_gte(used) {
+				return Weight::zero();
+			}
+
+			let (mut oldest, next) = PruningQueueRange::<T>::get();
+			let mut deferred = Vec::new();
+			while oldest != next && limit.all_gte(used.saturating_add(prune_weight)) {
+				used = used.saturating_add(prune_weight);
+				if let Some((task_id, finished_at)) = PruningQueue::<T>::get(oldest) {
+					if finished_at.saturating_add(T::TaskRetentionPeriod::get()) > now {
+						break;
+					}
+					if DisputableResults::<T>::contains_key(task_id)
+						|| TaskDisputes::<T>::contains_key(task_id)
+						|| Self::has_waiting_children(task_id)
+					{
+						deferred.push(task_id);
+					} else {
+						Self::prune_task(task_id);
+					}
+				}
+				PruningQueue::<T>::remove(oldest);
+				oldest = oldest.wrapping_add(1);
+			}
+			PruningQueueRange::<T>::put((oldest, next));
+
+			// Tasks whose result can still be disputed or is still awaited by tasks of their graph
+			// get another retention period
+			for task_id in deferred {
+				Self::queue_for_pruning(task_id);
+			}
+
+			used
+		}
+
+		/// Whether tasks of the graph of `task_id` still wait for other parents, and so will need
+		/// the result of `task_id` once they are released.
+		fn has_waiting_children(task_id: TaskId) -> bool {
+			TaskChildren::<T>::get(task_id)
+				.into_iter()
+				.any(|child| TaskStatus::<T>::get(child) == Some(TaskStatusType::WaitingForDependencies))
+		}
+
+		/// Removes everything stored about the finished task `task_id` but a `TaskSummary`.
+		///
+		/// A tip that could not be refunded is kept.
+		fn prune_task(task_id: TaskId) {
+			let Some(task) = Tasks::<T>::take(task_id) else {
+				return;
+			};
+			Self::release_workers(task_id);
+			TasksByOwner::<T>::remove(&task.task_owner, task_id);
+			let summary = TaskSummary {
+				task_hash: T::Hashing::hash_of(&task),
+				task_owner: task.task_owner,
+				worker: TaskAllocations::<T>::take(task_id),
+				outcome: task.task_status,
+				consumed_compute_hours: task.consume_compute_hours.unwrap_or_default(),
+			};
+
+			TaskStatus::<T>::remove(task_id);
+			TaskOwners::<T>::remove(task_id);
+			ComputeAggregations::<T>::remove(task_id);
+			TaskRequirements::<T>::remove(task_id);
+			TaskModels::<T>::remove(task_id);
+			ConfidentialTasks::<T>::remove(task_id);
+			TaskPayloads::<T>::remove(task_id);
+			EncryptedResults::<T>::remove(task_id);
+			TaskVerifications::<T>::remove(task_id);
+			TaskDeadlines::<T>::remove(task_id);
+			TaskParents::<T>::remove(task_id);
+			TaskChildren::<T>::remove(task_id);
+			TaskSummaries::<T>::insert(task_id, summary.clone());
+
+			Self::deposit_event(Event::TaskPruned { task_id, summary });
+		}
+
+		/// Records `result`, submitted by `who` for the running task `task_id`, and the compute
+		/// hours the task consumed. Returns the task and the worker that ran it.
+		fn record_task_result(
+			who: &T::AccountId,
+			task_id: TaskId,
+			result: BoundedVec<u8, ConstU32<500>>,
+			average_cpu_percentage_use: Option<u8>,
+		) -> Result<
+			(
+				TaskInfo<T::AccountId, BlockNumberFor<T>>,
+				(T::AccountId, WorkerId),
+			),
+			DispatchError,
+		> {
+			let mut task = Tasks::<T>::get(task_id).ok_or(Error::<T>::TaskNotFound)?;
+
+			// Check that caller is the assigned worker
+			let assigned_worker =
+				TaskAllocations::<T>::get(task_id).ok_or(Error::<T>::UnassignedTaskId)?;
+			ensure!(assigned_worker.0 == *who, Error::<T>::InvalidTaskOwner);
+
+			// Only a running task can complete
+			ensure!(
+				task.task_status == TaskStatusType::Running,
+				Error::<T>::InvalidTaskState
+			);
+
+			if let Some(cpu_usage) = average_cpu_percentage_use {
+				ensure!(cpu_usage <= 100, Error::<T>::InvalidCpuUsage);
+			}
+
+			TaskDeadlines::<T>::remove(task_id);
+			let time_elapsed = Self::close_compute_aggregation(task_id);
+
+			task.result = Some(result);
+			task.time_elapsed = Some(time_elapsed);
+			task.average_cpu_percentage_use = average_cpu_percentage_use;
+			task.consume_compute_hours = Some(Self::compute_hours_for(time_elapsed));
+
+			Ok((task, assigned_worker))
+		}
+
+		/// Marks the end of the compute aggregation of `task_id` at the current block and returns
+		/// the number of blocks the task ran for.
+		fn close_compute_aggregation(task_id: TaskId) -> BlockNumberFor<T> {
+			let now = <frame_system::Pallet<T>>::block_number();
+			ComputeAggregations::<T>::mutate(task_id, |record| match record {
+				Some((start, end)) => {
+					*end = Some(now);
+					now.saturating_sub(*start)
+				}
+				None => Zero::zero(),
+			})
+		}
+
+		/// Returns the part of the compute hour deposit of `task` that it did not consume to the
+		/// task owner.
+		fn refund_unused_deposit(
+			task_id: TaskId,
+			task: &TaskInfo<T::AccountId, BlockNumberFor<T>>,
+		) {
+			let deposit = task.compute_hours_deposit.unwrap_or_default();
+			let consumed = task.consume_compute_hours.unwrap_or_default();
+			let refunded_hours = deposit.saturating_sub(consumed);
+			if refunded_hours == 0 {
+				return;
+			}
+
+			pallet_payment::Pallet::<T>::refund_compute_hours(&task.task_owner, refunded_hours);
+			Self::deposit_event(Event::ComputeHoursRefunded {
+				task_id,
+				task_owner: task.task_owner.clone(),
+				refunded_hours,
+			});
+		}
+
+		/// Pays the tip of `task_id` to the worker whose result can no longer be disputed and
+		/// rewards its reputation. A worker that has been removed in the meantime is not rewarded.
+		///
+		/// The reputation of Neuro-ZK workers is rewarded by neuro-zk once their proof is accepted.
+		fn accept_result(task_id: TaskId, worker: &(T::AccountId, WorkerId), task_kind: &TaskKind) {
+			Self::pay_tip(task_id, &worker.0);
+			if *task_kind != TaskKind::NeuroZK {
+				let worker_type = Self::worker_type_for(task_kind.class());
+				let _ = pallet_edge_connect::Pallet::<T>::reward_reputation(worker, &worker_type);
+			}
+		}
+
+		/// Credits the tip of `task_id`, if any, to the pending rewards of `worker_owner`.
+		fn pay_tip(task_id: TaskId, worker_owner: &T::AccountId) {
+			if let Some(tip) = TaskTips::<T>::take(task_id) {
+				pallet_payment::Pallet::<T>::reward_tip(worker_owner, tip);
+			}
+		}
+
+		/// Gives the tip of `task_id`, if any, back to `task_owner`. A tip that cannot be
+		/// refunded right now is kept so that it is not lost.
+		fn refund_tip(task_id: TaskId, task_owner: &T::AccountId) {
+			let Some(tip) = TaskTips::<T>::get(task_id) else {
+				return;
+			};
+			match pallet_payment::Pallet::<T>::refund_tip(task_owner, tip) {
+				Ok(()) => TaskTips::<T>::remove(task_id),
+				Err(error) => {
+					log::warn!("Failed to refund the tip of task {:?}: {:?}", task_id, error)
+				}
+			}
+		}
+
+		/// Converts a number of blocks into compute hours, rounding any started hour up.
+		pub fn compute_hours_for(blocks: BlockNumberFor<T>) -> u32 {
+			let blocks: u32 = blocks.saturated_into();
+			let blocks_per_hour: u32 = T::BlocksPerHour::get().saturated_into::<u32>().max(1);
+			blocks.div_ceil(blocks_per_hour)
+		}
+
+		/// The rate limit window `who` schedules tasks in at the current block, with the tasks it
+		/// scheduled in it so far.
+		fn rate_limit_window(who: &T::AccountId) -> (BlockNumberFor<T>, u32) {
+			let current_block = <frame_system::Pallet<T>>::block_number();
+			let (window_start, count) = TaskRateLimits::<T>::get(who);
+
+			// Start a new window once the current one has elapsed
+			if current_block >= window_start.saturating_add(T::RateLimitWindow::get()) {
+				(current_block, 0)
+			} else {
+				(window_start, count)
+			}
+		}
+
+		/// Counts the submission of `tasks` tasks by `who` against its rate limit. Fails with
+		/// `RateLimitExceeded` when they would take the account over `T::MaxTasksPerWindow` tasks
+		/// in its current window.
+		fn check_rate_limit(who: &T::AccountId, tasks: u32) -> DispatchResult {
+			let (window_start, count) = Self::rate_limit_window(who);
+			let count = count.saturating_add(tasks);
+			ensure!(count <= T::MaxTasksPerWindow::get(), Error::<T>::RateLimitExceeded);
+
+			// Update storage
+			TaskRateLimits::<T>::insert(who, (window_start, count));
+
+			Ok(())
+		}
+
+		/// How many more tasks `who` can schedule in its current rate limit window, and the block
+		/// from which a new window starts. The gatekeeper is not rate limited.
+		pub fn task_submission_allowance(who: &T::AccountId) -> (u32, BlockNumberFor<T>) {
+			let (window_start, count) = Self::rate_limit_window(who);
+			let allowance = if GatekeeperAccount::<T>::get().as_ref() == Some(who) {
+				u32::MAX
+			} else {
+				T::MaxTasksPerWindow::get().saturating_sub(count)
+			};
+			(
+				allowance,
+				window_start.saturating_add(T::RateLimitWindow::get()),
+			)
+		}
+	}
+
+	impl<T: Config> WorkerStatusHandler<T::AccountId, WorkerId> for Pallet<T> {
+		fn on_worker_active(worker_key: &(T::AccountId, WorkerId), worker_type: &WorkerType) -> Weight {
+			Self::dispatch_pending_tasks(worker_key, worker_type)
+		}
+
+		fn on_worker_active_weight() -> Weight {
+			Self::dispatch_weight()
+		}
+	}
+
+	impl<T: Config + timestamp::Config> NzkTaskInfoHandler<T::AccountId, TaskId, BlockNumberFor<T>>
+		for Pallet<T>
+	{
+		// Implementation of the NzkTaskInfoHandler trait, which provides methods for accessing NZK task information.
+		fn get_nzk_task(task_key: TaskId) -> Option<TaskInfo<T::AccountId, BlockNumberFor<T>>> {
+			Tasks::<T>::get(task_key)
+		}
+
+		// Implementation of the NzkTaskInfoHandler trait, which provides methods for NZK task information.
+		fn update_nzk_task(task_key: TaskId, task: TaskInfo<T::AccountId, BlockNumberFor<T>>) {
+			Tasks::<T>::insert(task_key, task);
+		}
+
+		fn get_nzk_task_worker(task_key: TaskId) -> Option<(T::AccountId, WorkerId)> {
+			TaskAllocations::<T>::get(task_key)
 		}
 	}
 }
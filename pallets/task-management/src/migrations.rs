@@ -0,0 +1,47 @@
+//! Storage migrations of the task-management pallet.
+
+use super::*;
+use core::marker::PhantomData;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{GetStorageVersion, OnRuntimeUpgrade},
+};
+
+pub mod v1 {
+	use super::*;
+
+	/// Migrates the storage of task-management from version 0 to 1:
+	///
+	/// - Adds the tasks still in `Tasks` to `TasksByOwner`, and to `TasksByWorker` for the
+	///   workers they need in their current status.
+	/// - Counts the tasks that are running against the capacity of their worker in
+	///   `ActiveAllocations` and records the worker in `OccupiedWorkers`. Runs after the
+	///   edge-connect migration, so that workers have their `max_concurrent_tasks`.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() >= 1 {
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut reads = 1u64;
+			let mut writes = 1u64;
+
+			for (task_id, task) in Tasks::<T>::iter() {
+				// The task, its allocation, verifications and dispute
+				reads += 4;
+				// Its owner and at most an executor, a verifier and a resolver
+				writes += 4;
+				Pallet::<T>::index_task(task_id, &task);
+				// The worker and its allocations
+				reads += 2;
+				writes += 3;
+				Pallet::<T>::update_occupied_worker(task_id, &task.task_kind, &task.task_status);
+			}
+
+			STORAGE_VERSION.put::<Pallet<T>>();
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+	}
+}
@@ -1,10 +1,14 @@
 pub use crate as pallet_task_management;
-use frame_support::traits::ConstU32;
+use frame_support::traits::{ConstBool, ConstU128, ConstU32, Randomness};
 use frame_support::{derive_impl, parameter_types, weights::constants::RocksDbWeight};
-use frame_system::{mocking::MockBlock, GenesisConfig};
+use frame_system::{mocking::MockBlock, pallet_prelude::BlockNumberFor, EnsureRoot, GenesisConfig};
 use pallet_edge_connect;
 use pallet_payment;
-use sp_runtime::{traits::ConstU64, BuildStorage};
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, ConstI32, ConstU64, Hash},
+	BuildStorage,
+};
 
 // Configure a mock runtime to test the pallet.
 #[frame_support::runtime]
@@ -51,18 +55,68 @@ impl frame_system::Config for Test {
 	type AccountData = pallet_balances::AccountData<u128>;
 }
 
+// Deterministic randomness for tests, derived from the parent hash and the subject.
+pub struct TestRandomness;
+
+impl Randomness<H256, BlockNumberFor<Test>> for TestRandomness {
+	fn random(subject: &[u8]) -> (H256, BlockNumberFor<Test>) {
+		(
+			BlakeTwo256::hash_of(&(System::parent_hash(), subject)),
+			System::block_number(),
+		)
+	}
+}
+
 impl pallet_task_management::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
+	type Randomness = TestRandomness;
+	type BlocksPerHour = ConstU64<10>;
+	type FalseCompletionPenalty = ConstI32<20>;
+	type ReceptionTimeout = ConstU64<10>;
+	type CancellationCharge = CancellationCharge;
+	type ExecutionTimeout = ConstU64<100>;
+	type LateResponsePenalty = ConstI32<10>;
+	type MaxExpiringTasksPerBlock = ConstU32<2>;
+	type RateLimitWindow = ConstU64<10>;
+	type MaxTasksPerWindow = ConstU32<5>;
+	type MaxPendingTasks = ConstU32<5>;
+	type MaxTasksPerDispatch = ConstU32<3>;
+	type MaxGraphNodes = ConstU32<5>;
+	type MaxScheduledTasksPerBlock = ConstU32<2>;
+	type TaskRetentionPeriod = ConstU64<100>;
+	type DisputeWindow = DisputeWindow;
+	type DisputeBond = ConstU128<50>;
+	type DisputeOrigin = EnsureRoot<u64>;
+	type ModelDeposit = ConstU128<100>;
+	type ModelVersionDeposit = ConstU128<10>;
+	type MaxWorkerCandidates = ConstU32<16>;
 }
 
 impl pallet_edge_connect::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
+	type BondCurrency = Balances;
+	type WorkerBond = ConstU128<0>;
+	type UnbondingPeriod = ConstU64<0>;
+	type PenaltySlash = ();
+	type BanSlash = ();
+	type Slash = ();
+	type ReattestSpecUpgrades = ConstBool<false>;
+	type BanAppealOrigin = EnsureRoot<u64>;
+	type SuccessReward = ConstI32<5>;
+	type MaxReputationScore = ConstI32<100>;
+	type ViolationDecayPeriod = ConstU64<0>;
+	type ReputationBaseline = ConstI32<60>;
+	type ReputationRecovery = ConstI32<0>;
+	type MaxWorkersPerAccount = ConstU32<10>;
+	type WorkerStatusHandler = TaskManagementModule;
 }
 
 parameter_types! {
 		pub const MaxPaymentIdLength: u32 = 128;
+		pub static DisputeWindow: u64 = 0;
+		pub static CancellationCharge: u32 = 0;
 		pub const ExistentialDeposit: u128 = 10;
 		pub const MaxUserIdLength: u32 = 128;
 }
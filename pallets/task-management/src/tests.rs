@@ -1,13 +1,28 @@
 use crate::{mock::*, Error};
-use crate::{ComputeAggregations, GatekeeperAccount, ModelHashes, NextTaskId, TaskStatus, Tasks};
+use crate::{
+	ActiveAllocations, ComputeAggregations, ConfidentialTasks, DisputableResults, EncryptedResults,
+	ExpiringTasks, GatekeeperAccount, ModelDeposits, ModelHashes, ModelTransferOffers, ModelVersions,
+	Models, NextModelId, NextScheduleId, NextTaskId, PendingTasks, PruningQueueRange, ScheduledTasks,
+	TaskAgenda, TaskAllocations, TaskChildren, TaskDeadlines, TaskDisputes, TaskModels, TaskOwners,
+	TaskParents, TaskPayloads, TaskRateLimits, TaskRequirements, TaskStatus, TaskSummaries, TaskTips,
+	TaskVerifications, Tasks, TasksByOwner, TasksByWorker, WeightInfo, MAX_TASKS_PER_QUERY,
+};
 pub use cyborg_primitives::task::NeuroZkTaskSubmissionDetails;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Get, Hooks},
+	weights::Weight,
+};
 
-pub use cyborg_primitives::task::{TaskKind, TaskStatusType};
+pub use cyborg_primitives::task::{
+	ContainerImage, EncryptedPayload, LocationRequirement, ResourceRequirements, TaskClass,
+	TaskGraphNode, TaskKind, TaskOptions, TaskPriority, TaskStatusType, TaskSummary,
+};
 pub use cyborg_primitives::worker::*;
 use frame_support::dispatch::{DispatchErrorWithPostInfo, PostDispatchInfo};
 use frame_support::BoundedVec;
 use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::traits::{BlakeTwo256, Hash};
 use sp_std::convert::TryFrom;
 
 fn register_worker(
@@ -24,13 +39,130 @@ fn register_worker(
 		10000000, // ram
 		10000000, // storage
 		12,       // cpu
+		10,       // max concurrent tasks
 	)
 }
 
+fn register_large_worker(account: u64, domain_str: &str, latitude: i32, longitude: i32) {
+	assert_ok!(EdgeConnectModule::register_worker(
+		RuntimeOrigin::signed(account),
+		WorkerType::Executable,
+		BoundedVec::try_from(domain_str.as_bytes().to_vec()).unwrap(),
+		latitude,
+		longitude,
+		32_000_000_000,  // ram
+		500_000_000_000, // storage
+		16,              // cpu
+		10,              // max concurrent tasks
+	));
+}
+
 fn setup_gatekeeper() {
 	TaskManagementModule::set_gatekeeper(RuntimeOrigin::root(), 1).unwrap();
 }
 
+/// A task for a test to schedule: an inference task of `owner` running `model.bin` on a worker
+/// picked by the pallet, unless changed with the methods below.
+struct TestTask {
+	owner: u64,
+	task_kind: TaskKind,
+	task_location: Vec<u8>,
+	worker: Option<(u64, WorkerId)>,
+	deposit: u32,
+	nzk_info: Option<NeuroZkTaskSubmissionDetails>,
+	options: TaskOptions<u128>,
+}
+
+fn task_of(owner: u64, deposit: u32) -> TestTask {
+	TestTask {
+		owner,
+		task_kind: TaskKind::OpenInference,
+		task_location: b"model.bin".to_vec(),
+		worker: None,
+		deposit,
+		nzk_info: None,
+		options: TaskOptions::default(),
+	}
+}
+
+impl TestTask {
+	/// Pins the task to the last worker of `executor`, which only the gatekeeper may do.
+	fn pinned_to(mut self, executor: u64) -> Self {
+		self.worker = Some(worker_of(executor));
+		self
+	}
+
+	fn priority(mut self, priority: TaskPriority) -> Self {
+		self.options.priority = priority;
+		self
+	}
+
+	fn tip(mut self, tip: u128) -> Self {
+		self.options.tip = Some(tip);
+		self
+	}
+
+	/// Runs [`container_task_kind`] instead of an inference task.
+	fn container(mut self) -> Self {
+		self.task_kind = container_task_kind();
+		self.task_location = b"--frames 1-100".to_vec();
+		self
+	}
+
+	/// Runs a Neuro-ZK task, whose proof is verified by neuro-zk.
+	fn neuro_zk(mut self) -> Self {
+		let cid =
+			|| BoundedVec::truncate_from(b"Qmf9v8VbJ6WFGbakeWEXFhUc91V1JG26grakv3dTj8rERh".to_vec());
+		self.task_kind = TaskKind::NeuroZK;
+		self.nzk_info = Some(NeuroZkTaskSubmissionDetails {
+			zk_input: cid(),
+			zk_settings: cid(),
+			zk_verifying_key: cid(),
+		});
+		self
+	}
+
+	/// Encrypts the result to the key derived from `//Owner`, leaving the metadata to be sent
+	/// to the worker once assigned.
+	fn confidential(mut self) -> Self {
+		self.options.confidential = Some(encryption_key_of("//Owner"));
+		self.task_location = Vec::new();
+		self
+	}
+
+	/// Gives the owner 100 compute hours, schedules the task and returns its id.
+	fn schedule(self) -> u64 {
+		pallet_payment::ComputeHours::<Test>::insert(self.owner, 100);
+		assert_ok!(TaskManagementModule::task_scheduler(
+			RuntimeOrigin::signed(self.owner),
+			self.task_kind,
+			BoundedVec::truncate_from(self.task_location),
+			self.nzk_info,
+			self.worker,
+			Some(self.deposit),
+			self.options
+		));
+		NextTaskId::<Test>::get() - 1
+	}
+}
+
+/// The last worker registered by `account`. Worker ids are allocated across all accounts.
+fn worker_of(account: u64) -> (u64, WorkerId) {
+	(
+		account,
+		pallet_edge_connect::AccountWorkers::<Test>::get(account).unwrap_or_default(),
+	)
+}
+
+fn activate_worker(worker: (u64, WorkerId)) {
+	assert_ok!(EdgeConnectModule::toggle_worker_visibility(
+		RuntimeOrigin::signed(worker.0),
+		WorkerType::Executable,
+		worker.1,
+		true
+	));
+}
+
 #[test]
 fn it_works_for_task_scheduler() {
 	new_test_ext().execute_with(|| {
@@ -87,9 +219,9 @@ fn it_works_for_task_scheduler() {
 			task_kind_infer.clone(),
 			task_data.clone(),
 			None,
-			executor,
-			worker_id_docker,
-			Some(10)
+			Some((executor, worker_id_docker)),
+			Some(10),
+			TaskOptions::default()
 		));
 
 		let task_id_0 = NextTaskId::<Test>::get() - 1;
@@ -123,9 +255,9 @@ fn it_works_for_task_scheduler() {
 			task_kind_neurozk,
 			task_data.clone(),
 			nzk_data.clone(),
-			executor,
-			worker_id_exec,
-			Some(10)
+			Some((executor, worker_id_exec)),
+			Some(10),
+			TaskOptions::default()
 		));
 
 		let task_id_2 = NextTaskId::<Test>::get() - 1;
@@ -182,9 +314,9 @@ fn it_fails_when_worker_not_registered() {
 				task_kind_neurozk,
 				task_data.clone(),
 				nzk_data.clone(),
-				worker_owner,
-				worker_id,
+				Some((worker_owner, worker_id)),
 				Some(1),
+				TaskOptions::default(),
 			),
 			Error::<Test>::WorkerDoesNotExist
 		);
@@ -213,18 +345,18 @@ fn it_fails_when_no_workers_are_available() {
 		// Create a task data BoundedVec
 		let task_data = BoundedVec::try_from(b"some-docker-imgv.0".to_vec()).unwrap();
 
-		// Dispatch a signed extrinsic and expect an error because no workers are available
+		// Dispatch a signed extrinsic and expect an error because the pinned worker does not exist
 		assert_noop!(
 			TaskManagementModule::task_scheduler(
 				RuntimeOrigin::signed(alice),
 				task_kind_infer,
 				task_data.clone(),
 				None,
-				worker_owner,
-				worker_id,
-				Some(10)
+				Some((worker_owner, worker_id)),
+				Some(10),
+				TaskOptions::default()
 			),
-			Error::<Test>::NoWorkersAvailable
+			Error::<Test>::WorkerDoesNotExist
 		);
 	});
 }
@@ -256,15 +388,327 @@ fn it_fails_when_no_computer_hours_available() {
 				task_kind_infer,
 				task_data.clone(),
 				None,
-				worker_owner,
-				worker_id,
-				None
+				Some((worker_owner, worker_id)),
+				None,
+				TaskOptions::default()
 			),
 			Error::<Test>::RequireComputeHoursDeposit
 		);
 	});
 }
 
+#[test]
+fn task_scheduler_selects_an_active_worker_automatically() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		let bob = 3;
+		let executor = 2;
+
+		assert_ok!(register_worker(executor, WorkerType::Executable, "inactive.worker"));
+		assert_ok!(register_worker(executor, WorkerType::Executable, "active.worker"));
+		activate_worker((executor, 1));
+
+		pallet_payment::ComputeHours::<Test>::insert(bob, 20);
+		let task_data = BoundedVec::try_from(b"some-docker-imgv.0".to_vec()).unwrap();
+
+		assert_ok!(TaskManagementModule::task_scheduler(
+			RuntimeOrigin::signed(bob),
+			TaskKind::OpenInference,
+			task_data,
+			None,
+			None,
+			Some(10),
+			TaskOptions::default()
+		));
+
+		let task_id = NextTaskId::<Test>::get() - 1;
+		assert_eq!(TaskAllocations::<Test>::get(task_id), Some((executor, 1)));
+		assert_eq!(Tasks::<Test>::get(task_id).unwrap().task_owner, bob);
+	});
+}
+
+#[test]
+fn task_scheduler_skips_workers_with_low_reputation() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		let bob = 3;
+		let low_reputation_owner = 4;
+		let executor = 5;
+
+		assert_ok!(register_worker(low_reputation_owner, WorkerType::Executable, "low.worker"));
+		assert_ok!(register_worker(executor, WorkerType::Executable, "good.worker"));
+		activate_worker(worker_of(low_reputation_owner));
+		activate_worker(worker_of(executor));
+
+		pallet_edge_connect::ExecutableWorkers::<Test>::mutate(
+			worker_of(low_reputation_owner),
+			|worker| {
+				if let Some(worker) = worker {
+					worker.reputation.score = 40;
+				}
+			},
+		);
+
+		pallet_payment::ComputeHours::<Test>::insert(bob, 50);
+		let task_data = BoundedVec::try_from(b"some-docker-imgv.0".to_vec()).unwrap();
+
+		for _ in 0..3 {
+			assert_ok!(TaskManagementModule::task_scheduler(
+				RuntimeOrigin::signed(bob),
+				TaskKind::OpenInference,
+				task_data.clone(),
+				None,
+				None,
+				Some(5),
+				TaskOptions::default()
+			));
+
+			let task_id = NextTaskId::<Test>::get() - 1;
+			assert_eq!(
+				TaskAllocations::<Test>::get(task_id),
+				Some(worker_of(executor))
+			);
+		}
+	});
+}
+
+#[test]
+fn task_scheduler_queues_task_without_eligible_workers() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		let bob = 3;
+		let executor = 2;
+
+		// Registered workers start out inactive
+		assert_ok!(register_worker(executor, WorkerType::Executable, "exec.worker"));
+
+		pallet_payment::ComputeHours::<Test>::insert(bob, 20);
+		let task_data = BoundedVec::try_from(b"some-docker-imgv.0".to_vec()).unwrap();
+
+		assert_ok!(TaskManagementModule::task_scheduler(
+			RuntimeOrigin::signed(bob),
+			TaskKind::OpenInference,
+			task_data,
+			None,
+			None,
+			Some(10),
+			TaskOptions::default()
+		));
+
+		// The deposit stays locked while the task waits for a worker
+		let task_id = NextTaskId::<Test>::get() - 1;
+		assert_eq!(TaskStatus::<Test>::get(task_id), Some(TaskStatusType::Pending));
+		assert_eq!(TaskAllocations::<Test>::get(task_id), None);
+		assert_eq!(
+			PendingTasks::<Test>::get(TaskClass::OpenInference, TaskPriority::Normal).into_inner(),
+			vec![task_id]
+		);
+		assert_eq!(pallet_payment::ComputeHours::<Test>::get(bob), 10);
+		System::assert_last_event(
+			crate::Event::TaskQueued {
+				task_id,
+				task_kind: TaskKind::OpenInference,
+				priority: TaskPriority::Normal,
+				task_owner: bob,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn only_gatekeeper_can_pin_a_worker() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		let bob = 3;
+		let executor = 2;
+
+		assert_ok!(register_worker(executor, WorkerType::Executable, "exec.worker"));
+		activate_worker(worker_of(executor));
+
+		pallet_payment::ComputeHours::<Test>::insert(bob, 20);
+		let task_data = BoundedVec::try_from(b"some-docker-imgv.0".to_vec()).unwrap();
+
+		assert_noop!(
+			TaskManagementModule::task_scheduler(
+				RuntimeOrigin::signed(bob),
+				TaskKind::OpenInference,
+				task_data,
+				None,
+				Some(worker_of(executor)),
+				Some(10),
+				TaskOptions::default()
+			),
+			Error::<Test>::NotGatekeeper
+		);
+	});
+}
+
+#[test]
+fn task_scheduler_only_selects_workers_meeting_requirements() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		let bob = 3;
+		let small_worker_owner = 2;
+		let large_worker_owner = 4;
+
+		assert_ok!(register_worker(small_worker_owner, WorkerType::Executable, "small.worker"));
+		register_large_worker(large_worker_owner, "large.worker", 590000, 120000);
+		activate_worker(worker_of(small_worker_owner));
+		activate_worker(worker_of(large_worker_owner));
+
+		let requirements = ResourceRequirements {
+			ram: 16_000_000_000,
+			storage: 100_000_000_000,
+			cpu: 8,
+			location: None,
+		};
+
+		pallet_payment::ComputeHours::<Test>::insert(bob, 50);
+		let task_data = BoundedVec::try_from(b"some-docker-imgv.0".to_vec()).unwrap();
+
+		for _ in 0..3 {
+			assert_ok!(TaskManagementModule::task_scheduler(
+				RuntimeOrigin::signed(bob),
+				TaskKind::OpenInference,
+				task_data.clone(),
+				None,
+				None,
+				Some(5),
+				TaskOptions {
+					requirements: Some(requirements.clone()),
+					..Default::default()
+				}
+			));
+
+			let task_id = NextTaskId::<Test>::get() - 1;
+			assert_eq!(
+				TaskAllocations::<Test>::get(task_id),
+				Some(worker_of(large_worker_owner))
+			);
+			assert_eq!(TaskRequirements::<Test>::get(task_id), Some(requirements.clone()));
+		}
+	});
+}
+
+#[test]
+fn task_scheduler_respects_location_radius() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		let bob = 3;
+		let distant_worker_owner = 2;
+		let nearby_worker_owner = 4;
+
+		register_large_worker(distant_worker_owner, "distant.worker", 590000, 120000);
+		register_large_worker(nearby_worker_owner, "nearby.worker", 101000, 99000);
+		activate_worker(worker_of(distant_worker_owner));
+		activate_worker(worker_of(nearby_worker_owner));
+
+		let requirements = ResourceRequirements {
+			location: Some(LocationRequirement {
+				center: Location { latitude: 100000, longitude: 100000 },
+				radius: 5000,
+			}),
+			..Default::default()
+		};
+
+		pallet_payment::ComputeHours::<Test>::insert(bob, 50);
+		let task_data = BoundedVec::try_from(b"some-docker-imgv.0".to_vec()).unwrap();
+
+		for _ in 0..3 {
+			assert_ok!(TaskManagementModule::task_scheduler(
+				RuntimeOrigin::signed(bob),
+				TaskKind::OpenInference,
+				task_data.clone(),
+				None,
+				None,
+				Some(5),
+				TaskOptions {
+					requirements: Some(requirements.clone()),
+					..Default::default()
+				}
+			));
+
+			let task_id = NextTaskId::<Test>::get() - 1;
+			assert_eq!(
+				TaskAllocations::<Test>::get(task_id),
+				Some(worker_of(nearby_worker_owner))
+			);
+		}
+	});
+}
+
+#[test]
+fn task_scheduler_queues_task_when_no_worker_meets_requirements() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		let bob = 3;
+		let executor = 2;
+
+		assert_ok!(register_worker(executor, WorkerType::Executable, "exec.worker"));
+		activate_worker(worker_of(executor));
+
+		let requirements = ResourceRequirements { cpu: 64, ..Default::default() };
+
+		pallet_payment::ComputeHours::<Test>::insert(bob, 20);
+		let task_data = BoundedVec::try_from(b"some-docker-imgv.0".to_vec()).unwrap();
+
+		assert_ok!(TaskManagementModule::task_scheduler(
+			RuntimeOrigin::signed(bob),
+			TaskKind::OpenInference,
+			task_data,
+			None,
+			None,
+			Some(10),
+			TaskOptions {
+				requirements: Some(requirements),
+				..Default::default()
+			}
+		));
+
+		let task_id = NextTaskId::<Test>::get() - 1;
+		assert_eq!(TaskStatus::<Test>::get(task_id), Some(TaskStatusType::Pending));
+	});
+}
+
+#[test]
+fn gatekeeper_cannot_pin_a_worker_missing_requirements() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		let alice = 1;
+		let executor = 2;
+
+		assert_ok!(register_worker(executor, WorkerType::Executable, "exec.worker"));
+		activate_worker(worker_of(executor));
+
+		let requirements = ResourceRequirements { ram: 16_000_000_000, ..Default::default() };
+
+		pallet_payment::ComputeHours::<Test>::insert(alice, 20);
+		let task_data = BoundedVec::try_from(b"some-docker-imgv.0".to_vec()).unwrap();
+
+		assert_noop!(
+			TaskManagementModule::task_scheduler(
+				RuntimeOrigin::signed(alice),
+				TaskKind::OpenInference,
+				task_data,
+				None,
+				Some(worker_of(executor)),
+				Some(10),
+				TaskOptions {
+					requirements: Some(requirements),
+					..Default::default()
+				}
+			),
+			Error::<Test>::WorkerRequirementsNotMet
+		);
+	});
+}
+
 #[test]
 fn confirm_task_reception_should_work_for_valid_assigned_worker() {
 	new_test_ext().execute_with(|| {
@@ -286,9 +730,9 @@ fn confirm_task_reception_should_work_for_valid_assigned_worker() {
 			task_kind,
 			task_data.clone(),
 			None,
-			executor,
-			worker_id,
-			Some(10)
+			Some((executor, worker_id)),
+			Some(10),
+			TaskOptions::default()
 		));
 
 		let task_id = NextTaskId::<Test>::get() - 1;
@@ -327,9 +771,9 @@ fn confirm_task_reception_should_fail_for_wrong_executor() {
 			TaskKind::OpenInference,
 			task_data.clone(),
 			None,
-			executor,
-			worker_id,
-			Some(10)
+			Some((executor, worker_id)),
+			Some(10),
+			TaskOptions::default()
 		));
 
 		let task_id = NextTaskId::<Test>::get() - 1;
@@ -359,9 +803,9 @@ fn confirm_task_reception_should_fail_if_already_running() {
 			TaskKind::OpenInference,
 			task_data.clone(),
 			None,
-			executor,
-			worker_id,
-			Some(10)
+			Some((executor, worker_id)),
+			Some(10),
+			TaskOptions::default()
 		));
 
 		let task_id = NextTaskId::<Test>::get() - 1;
@@ -402,9 +846,9 @@ fn it_works_for_confirm_miner_vacation() {
 			task_kind.clone(),
 			task_data.clone(),
 			None,
-			alice,
-			0, // worker_id
+			Some(worker_of(alice)),
 			Some(10),
+			TaskOptions::default(),
 		));
 
 		let task_id = NextTaskId::<Test>::get() - 1;
@@ -453,9 +897,9 @@ fn fails_if_not_task_owner_for_vacation() {
 			task_kind.clone(),
 			task_data.clone(),
 			None,
-			alice,
-			0,
+			Some(worker_of(alice)),
 			Some(5),
+			TaskOptions::default(),
 		));
 
 		let task_id = NextTaskId::<Test>::get() - 1;
@@ -498,9 +942,9 @@ fn fails_if_task_not_stopped() {
 			task_kind.clone(),
 			task_data.clone(),
 			None,
-			alice,
-			0,
+			Some(worker_of(alice)),
 			Some(5),
+			TaskOptions::default(),
 		));
 
 		let task_id = NextTaskId::<Test>::get() - 1;
@@ -539,9 +983,9 @@ fn it_works_for_stop_task_and_vacate_miner() {
 			task_kind,
 			metadata.clone(),
 			None,
-			alice,
-			0,
+			Some(worker_of(alice)),
 			Some(10),
+			TaskOptions::default(),
 		));
 
 		let task_id = NextTaskId::<Test>::get() - 1;
@@ -593,9 +1037,9 @@ fn fails_if_task_is_not_running() {
 			task_kind.clone(),
 			task_data.clone(),
 			None,
-			alice,
-			0,
+			Some(worker_of(alice)),
 			Some(15),
+			TaskOptions::default(),
 		));
 
 		let task_id = NextTaskId::<Test>::get() - 1;
@@ -622,44 +1066,210 @@ fn fails_if_task_does_not_exist() {
 }
 
 #[test]
-fn test_register_model_hash_works() {
+fn stop_task_fails_for_unrelated_account() {
 	new_test_ext().execute_with(|| {
-		use hex_literal::hex;
-		use sp_core::H256;
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_active_workers(&[2]);
+		let task_id = task_of(3, 10).schedule();
+		assert_ok!(TaskManagementModule::confirm_task_reception(
+			RuntimeOrigin::signed(2),
+			task_id
+		));
 
-		let gatekeeper = 1;
-		GatekeeperAccount::<Test>::put(gatekeeper.clone());
+		assert_noop!(
+			TaskManagementModule::stop_task_and_vacate_miner(RuntimeOrigin::signed(4), task_id),
+			Error::<Test>::NotTaskOwner
+		);
+	});
+}
 
-		let origin = RuntimeOrigin::signed(gatekeeper.clone());
+#[test]
+fn owner_can_cancel_assigned_task() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		let owner = 3;
+		setup_active_workers(&[2]);
+		let task_id = task_of(owner, 10).schedule();
 
-		let model_id_hex = hex!("79c3bc0974696a2ea9efd2f7bca19fdd630834bd0086f1b4a1c3db3dce3b2a51");
-		let model_id_vec = model_id_hex.to_vec();
-		let model_hash = H256::repeat_byte(0x42);
+		assert_ok!(TaskManagementModule::cancel_task(RuntimeOrigin::signed(owner), task_id));
 
-		assert_ok!(TaskManagementModule::register_model_hash(
-			origin,
-			model_id_vec.clone(),
-			model_hash
+		// Nothing ran, so the whole deposit comes back
+		assert_eq!(TaskStatus::<Test>::get(task_id), Some(TaskStatusType::Stopped));
+		assert_eq!(TaskDeadlines::<Test>::get(task_id), None);
+		assert_eq!(pallet_payment::ComputeHours::<Test>::get(owner), 100);
+		System::assert_last_event(
+			crate::Event::TaskCancelled {
+				task_id,
+				consumed_compute_hours: 0,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn cancelling_a_task_before_its_reception_is_charged() {
+	new_test_ext().execute_with(|| {
+		CancellationCharge::set(2);
+		setup_gatekeeper();
+		System::set_block_number(1);
+		let owner = 3;
+		setup_active_workers(&[2]);
+		let task_id = task_of(owner, 10).schedule();
+
+		assert_ok!(TaskManagementModule::cancel_task(
+			RuntimeOrigin::signed(owner),
+			task_id
 		));
 
-		let mut fixed_id = [0u8; 32];
-		fixed_id.copy_from_slice(&model_id_vec);
-		assert_eq!(ModelHashes::<Test>::get(fixed_id), Some(model_hash));
+		// Cancelling and resubmitting to get another worker is not free
+		assert_eq!(pallet_payment::ComputeHours::<Test>::get(owner), 98);
+		System::assert_last_event(
+			crate::Event::TaskCancelled {
+				task_id,
+				consumed_compute_hours: 2,
+			}
+			.into(),
+		);
 	});
 }
 
 #[test]
-fn test_register_and_retrieve_model_hash() {
+fn gatekeeper_can_cancel_running_task() {
 	new_test_ext().execute_with(|| {
-		use base64::engine::general_purpose::STANDARD;
-		use base64::Engine;
-		use hex_literal::hex;
-		use sp_core::H256;
+		setup_gatekeeper();
+		System::set_block_number(1);
+		let owner = 3;
+		setup_active_workers(&[2]);
+		let task_id = task_of(owner, 10).schedule();
+		assert_ok!(TaskManagementModule::confirm_task_reception(
+			RuntimeOrigin::signed(2),
+			task_id
+		));
 
-		let gatekeeper = 1u64;
-		GatekeeperAccount::<Test>::put(gatekeeper);
+		System::set_block_number(6);
+		assert_ok!(TaskManagementModule::cancel_task(RuntimeOrigin::signed(1), task_id));
 
-		let origin = RuntimeOrigin::signed(gatekeeper);
+		let task = Tasks::<Test>::get(task_id).unwrap();
+		assert_eq!(task.task_status, TaskStatusType::Stopped);
+		assert_eq!(task.consume_compute_hours, Some(1));
+		assert_eq!(pallet_payment::ComputeHours::<Test>::get(owner), 99);
+		System::assert_last_event(
+			crate::Event::TaskCancelled {
+				task_id,
+				consumed_compute_hours: 1,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn root_can_cancel_task() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_active_workers(&[2]);
+		let task_id = task_of(3, 10).schedule();
+
+		assert_ok!(TaskManagementModule::cancel_task(RuntimeOrigin::root(), task_id));
+
+		assert_eq!(TaskStatus::<Test>::get(task_id), Some(TaskStatusType::Stopped));
+	});
+}
+
+#[test]
+fn cancel_task_fails_for_unrelated_account() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_active_workers(&[2]);
+		let task_id = task_of(3, 10).schedule();
+
+		// Not even the assigned miner can cancel the task
+		assert_noop!(
+			TaskManagementModule::cancel_task(RuntimeOrigin::signed(2), task_id),
+			Error::<Test>::NotTaskOwner
+		);
+	});
+}
+
+#[test]
+fn cancel_task_fails_for_finished_task() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_active_workers(&[2]);
+		let task_id = task_of(3, 10).schedule();
+		assert_ok!(TaskManagementModule::cancel_task(RuntimeOrigin::signed(3), task_id));
+
+		assert_noop!(
+			TaskManagementModule::cancel_task(RuntimeOrigin::signed(3), task_id),
+			Error::<Test>::InvalidTaskState
+		);
+	});
+}
+
+#[test]
+fn assigned_miner_can_confirm_vacation() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_active_workers(&[2]);
+		let task_id = task_of(3, 10).schedule();
+		assert_ok!(TaskManagementModule::cancel_task(RuntimeOrigin::signed(3), task_id));
+
+		assert_ok!(TaskManagementModule::confirm_miner_vacation(
+			RuntimeOrigin::signed(2),
+			task_id
+		));
+
+		assert_eq!(TaskStatus::<Test>::get(task_id), Some(TaskStatusType::Vacated));
+		System::assert_last_event(crate::Event::MinerVacated { task_id }.into());
+	});
+}
+
+#[test]
+fn test_register_model_hash_works() {
+	new_test_ext().execute_with(|| {
+		use hex_literal::hex;
+		use sp_core::H256;
+
+		let gatekeeper = 1;
+		GatekeeperAccount::<Test>::put(gatekeeper.clone());
+
+		let origin = RuntimeOrigin::signed(gatekeeper.clone());
+
+		let model_id_hex = hex!("79c3bc0974696a2ea9efd2f7bca19fdd630834bd0086f1b4a1c3db3dce3b2a51");
+		let model_id_vec = model_id_hex.to_vec();
+		let model_hash = H256::repeat_byte(0x42);
+
+		assert_ok!(TaskManagementModule::register_model_hash(
+			origin,
+			model_id_vec.clone(),
+			model_hash
+		));
+
+		let mut fixed_id = [0u8; 32];
+		fixed_id.copy_from_slice(&model_id_vec);
+		assert_eq!(ModelHashes::<Test>::get(fixed_id), Some(model_hash));
+	});
+}
+
+#[test]
+fn test_register_and_retrieve_model_hash() {
+	new_test_ext().execute_with(|| {
+		use base64::engine::general_purpose::STANDARD;
+		use base64::Engine;
+		use hex_literal::hex;
+		use sp_core::H256;
+
+		let gatekeeper = 1u64;
+		GatekeeperAccount::<Test>::put(gatekeeper);
+
+		let origin = RuntimeOrigin::signed(gatekeeper);
 
 		let model_id_vec =
 			hex!("79c3bc0974696a2ea9efd2f7bca19fdd630834bd0086f1b4a1c3db3dce3b2a51").to_vec();
@@ -683,3 +1293,3200 @@ fn test_register_and_retrieve_model_hash() {
 		assert_eq!(stored_hash, Some(model_hash));
 	});
 }
+
+/// Funds `owner` and has it register a model with a single version, and returns its id.
+fn register_test_model(owner: u64) -> u64 {
+	assert_ok!(Balances::force_set_balance(
+		RuntimeOrigin::root(),
+		owner,
+		1_000
+	));
+	let model_id = NextModelId::<Test>::get();
+	assert_ok!(TaskManagementModule::register_model(
+		RuntimeOrigin::signed(owner),
+		BoundedVec::truncate_from(b"resnet".to_vec()),
+		BoundedVec::truncate_from(b"onnx".to_vec()),
+		BoundedVec::truncate_from(b"https://license.example".to_vec()),
+	));
+	assert_ok!(TaskManagementModule::add_model_version(
+		RuntimeOrigin::signed(owner),
+		model_id,
+		BlakeTwo256::hash(b"resnet-v0"),
+		1024
+	));
+	model_id
+}
+
+#[test]
+fn register_model_and_add_versions() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let model_id = register_test_model(2);
+		assert_ok!(TaskManagementModule::add_model_version(
+			RuntimeOrigin::signed(2),
+			model_id,
+			BlakeTwo256::hash(b"resnet-v1"),
+			2048
+		));
+
+		let model = Models::<Test>::get(model_id).unwrap();
+		assert_eq!(model.owner, 2);
+		assert_eq!(model.version_count, 2);
+		// The model deposit and one deposit per version
+		assert_eq!(ModelDeposits::<Test>::get(model_id), 120);
+		assert_eq!(Balances::reserved_balance(2), 120);
+		assert_eq!(
+			ModelVersions::<Test>::get(model_id, 1).map(|version| version.artifact_hash),
+			Some(BlakeTwo256::hash(b"resnet-v1"))
+		);
+		assert_eq!(
+			TaskManagementModule::model_versions(model_id)
+				.into_iter()
+				.map(|(version, _)| version)
+				.collect::<Vec<_>>(),
+			vec![0, 1]
+		);
+		System::assert_last_event(
+			crate::Event::ModelVersionAdded {
+				model_id,
+				version: 1,
+				artifact_hash: BlakeTwo256::hash(b"resnet-v1"),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn registering_models_requires_the_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), 2, 105));
+		assert_noop!(
+			TaskManagementModule::register_model(
+				RuntimeOrigin::signed(3),
+				BoundedVec::truncate_from(b"resnet".to_vec()),
+				BoundedVec::truncate_from(b"onnx".to_vec()),
+				BoundedVec::truncate_from(b"https://license.example".to_vec()),
+			),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+
+		assert_ok!(TaskManagementModule::register_model(
+			RuntimeOrigin::signed(2),
+			BoundedVec::truncate_from(b"resnet".to_vec()),
+			BoundedVec::truncate_from(b"onnx".to_vec()),
+			BoundedVec::truncate_from(b"https://license.example".to_vec()),
+		));
+		assert_eq!(Balances::reserved_balance(2), 100);
+		// What is left is not enough for the deposit of a version
+		assert_noop!(
+			TaskManagementModule::add_model_version(
+				RuntimeOrigin::signed(2),
+				0,
+				BlakeTwo256::hash(b"resnet-v0"),
+				1024
+			),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn only_model_owner_can_add_versions() {
+	new_test_ext().execute_with(|| {
+		let model_id = register_test_model(2);
+
+		assert_noop!(
+			TaskManagementModule::add_model_version(
+				RuntimeOrigin::signed(3),
+				model_id,
+				BlakeTwo256::hash(b"resnet-v1"),
+				2048
+			),
+			Error::<Test>::NotModelOwner
+		);
+		assert_noop!(
+			TaskManagementModule::add_model_version(
+				RuntimeOrigin::signed(2),
+				model_id + 1,
+				BlakeTwo256::hash(b"resnet-v1"),
+				2048
+			),
+			Error::<Test>::ModelNotFound
+		);
+	});
+}
+
+#[test]
+fn owner_and_gatekeeper_can_deprecate_model_versions() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		let model_id = register_test_model(2);
+		assert_ok!(TaskManagementModule::add_model_version(
+			RuntimeOrigin::signed(2),
+			model_id,
+			BlakeTwo256::hash(b"resnet-v1"),
+			2048
+		));
+
+		assert_noop!(
+			TaskManagementModule::deprecate_model_version(RuntimeOrigin::signed(3), model_id, 0),
+			Error::<Test>::NotModelOwner
+		);
+		assert_noop!(
+			TaskManagementModule::deprecate_model_version(RuntimeOrigin::signed(2), model_id, 2),
+			Error::<Test>::ModelVersionNotFound
+		);
+
+		assert_ok!(TaskManagementModule::deprecate_model_version(
+			RuntimeOrigin::signed(2),
+			model_id,
+			0
+		));
+		assert_ok!(TaskManagementModule::deprecate_model_version(
+			RuntimeOrigin::signed(1),
+			model_id,
+			1
+		));
+		assert!(ModelVersions::<Test>::get(model_id, 0).unwrap().deprecated);
+		assert!(ModelVersions::<Test>::get(model_id, 1).unwrap().deprecated);
+	});
+}
+
+#[test]
+fn transferred_model_is_managed_by_the_new_owner() {
+	new_test_ext().execute_with(|| {
+		let model_id = register_test_model(2);
+
+		assert_noop!(
+			TaskManagementModule::transfer_model(RuntimeOrigin::signed(3), model_id, 3),
+			Error::<Test>::NotModelOwner
+		);
+		assert_ok!(TaskManagementModule::transfer_model(
+			RuntimeOrigin::signed(2),
+			model_id,
+			3
+		));
+		// Nothing is reserved from the new owner before it accepts
+		assert_eq!(Models::<Test>::get(model_id).unwrap().owner, 2);
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_noop!(
+			TaskManagementModule::accept_model_transfer(RuntimeOrigin::signed(4), model_id),
+			Error::<Test>::NoModelTransferOffer
+		);
+		// The new owner has to cover the deposit of the model
+		assert_noop!(
+			TaskManagementModule::accept_model_transfer(RuntimeOrigin::signed(3), model_id),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), 3, 1_000));
+		assert_ok!(TaskManagementModule::accept_model_transfer(
+			RuntimeOrigin::signed(3),
+			model_id
+		));
+		assert_eq!(Models::<Test>::get(model_id).unwrap().owner, 3);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::reserved_balance(3), 110);
+		assert!(!ModelTransferOffers::<Test>::contains_key(model_id));
+
+		assert_noop!(
+			TaskManagementModule::add_model_version(
+				RuntimeOrigin::signed(2),
+				model_id,
+				BlakeTwo256::hash(b"resnet-v1"),
+				2048
+			),
+			Error::<Test>::NotModelOwner
+		);
+		assert_ok!(TaskManagementModule::add_model_version(
+			RuntimeOrigin::signed(3),
+			model_id,
+			BlakeTwo256::hash(b"resnet-v1"),
+			2048
+		));
+	});
+}
+
+#[test]
+fn removing_models_and_versions_returns_their_deposit() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let model_id = register_test_model(2);
+		assert_ok!(TaskManagementModule::transfer_model(
+			RuntimeOrigin::signed(2),
+			model_id,
+			3
+		));
+
+		assert_noop!(
+			TaskManagementModule::remove_model(RuntimeOrigin::signed(2), model_id),
+			Error::<Test>::ModelHasVersions
+		);
+		assert_noop!(
+			TaskManagementModule::remove_model_version(RuntimeOrigin::signed(3), model_id, 0),
+			Error::<Test>::NotModelOwner
+		);
+		assert_noop!(
+			TaskManagementModule::remove_model_version(RuntimeOrigin::signed(2), model_id, 1),
+			Error::<Test>::ModelVersionNotFound
+		);
+
+		assert_ok!(TaskManagementModule::remove_model_version(
+			RuntimeOrigin::signed(2),
+			model_id,
+			0
+		));
+		assert!(!ModelVersions::<Test>::contains_key(model_id, 0));
+		assert_eq!(Balances::reserved_balance(2), 100);
+		assert_eq!(ModelDeposits::<Test>::get(model_id), 100);
+
+		assert_ok!(TaskManagementModule::remove_model(
+			RuntimeOrigin::signed(2),
+			model_id
+		));
+		assert!(!Models::<Test>::contains_key(model_id));
+		assert!(!ModelTransferOffers::<Test>::contains_key(model_id));
+		assert_eq!(Balances::reserved_balance(2), 0);
+		System::assert_last_event(crate::Event::ModelRemoved { model_id }.into());
+	});
+}
+
+#[test]
+fn task_scheduler_binds_task_to_model_version() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_active_workers(&[2]);
+		let model_id = register_test_model(3);
+		pallet_payment::ComputeHours::<Test>::insert(1, 100);
+
+		assert_ok!(TaskManagementModule::task_scheduler(
+			RuntimeOrigin::signed(1),
+			TaskKind::OpenInference,
+			BoundedVec::truncate_from(b"model.bin".to_vec()),
+			None,
+			None,
+			Some(10),
+			TaskOptions {
+				model: Some((model_id, 0)),
+				..Default::default()
+			}
+		));
+
+		let task_id = NextTaskId::<Test>::get() - 1;
+		let task_model = TaskModels::<Test>::get(task_id).unwrap();
+		assert_eq!(task_model.model_id, model_id);
+		assert_eq!(task_model.version, 0);
+		assert_eq!(task_model.artifact_hash, BlakeTwo256::hash(b"resnet-v0"));
+	});
+}
+
+#[test]
+fn task_scheduler_rejects_missing_or_deprecated_model_versions() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_active_workers(&[2]);
+		let model_id = register_test_model(3);
+		assert_ok!(TaskManagementModule::deprecate_model_version(
+			RuntimeOrigin::signed(3),
+			model_id,
+			0
+		));
+		pallet_payment::ComputeHours::<Test>::insert(1, 100);
+
+		for (model, error) in [
+			((model_id, 0), Error::<Test>::ModelVersionDeprecated),
+			((model_id, 1), Error::<Test>::ModelVersionNotFound),
+			((model_id + 1, 0), Error::<Test>::ModelNotFound),
+		] {
+			assert_noop!(
+				TaskManagementModule::task_scheduler(
+					RuntimeOrigin::signed(1),
+					TaskKind::OpenInference,
+					BoundedVec::truncate_from(b"model.bin".to_vec()),
+					None,
+					None,
+					Some(10),
+					TaskOptions {
+						model: Some(model),
+						..Default::default()
+					}
+				),
+				error
+			);
+		}
+	});
+}
+
+#[test]
+fn submit_task_result_completes_running_task() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		let creator = 1;
+		let executor = 2;
+
+		assert_ok!(register_worker(executor, WorkerType::Executable, "exec"));
+		let task_id = task_of(creator, 10).pinned_to(executor).schedule();
+
+		assert_ok!(TaskManagementModule::confirm_task_reception(
+			RuntimeOrigin::signed(executor),
+			task_id
+		));
+
+		// 25 blocks with 10 blocks per compute hour make 3 started hours
+		System::set_block_number(26);
+		let result: BoundedVec<u8, _> = BoundedVec::truncate_from(b"ipfs://result".to_vec());
+		assert_ok!(TaskManagementModule::submit_task_result(
+			RuntimeOrigin::signed(executor),
+			task_id,
+			result.clone(),
+			Some(70)
+		));
+
+		let task = Tasks::<Test>::get(task_id).unwrap();
+		assert_eq!(task.task_status, TaskStatusType::Completed);
+		assert_eq!(task.result, Some(result.clone()));
+		assert_eq!(task.time_elapsed, Some(25));
+		assert_eq!(task.average_cpu_percentage_use, Some(70));
+		assert_eq!(task.consume_compute_hours, Some(3));
+		assert_eq!(TaskStatus::<Test>::get(task_id), Some(TaskStatusType::Completed));
+		assert_eq!(ComputeAggregations::<Test>::get(task_id), Some((1, Some(26))));
+
+		System::assert_last_event(
+			crate::Event::TaskCompleted {
+				task_id,
+				worker: worker_of(executor),
+				result,
+				time_elapsed: 25,
+				consumed_compute_hours: 3,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn submit_task_result_fails_for_wrong_worker() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		let creator = 1;
+		let executor = 2;
+		let intruder = 99;
+
+		assert_ok!(register_worker(executor, WorkerType::Executable, "exec"));
+		let task_id = task_of(creator, 10).pinned_to(executor).schedule();
+
+		assert_ok!(TaskManagementModule::confirm_task_reception(
+			RuntimeOrigin::signed(executor),
+			task_id
+		));
+
+		assert_noop!(
+			TaskManagementModule::submit_task_result(
+				RuntimeOrigin::signed(intruder),
+				task_id,
+				BoundedVec::truncate_from(b"result".to_vec()),
+				None
+			),
+			Error::<Test>::InvalidTaskOwner
+		);
+	});
+}
+
+#[test]
+fn submit_task_result_fails_if_task_not_running() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		let creator = 1;
+		let executor = 2;
+
+		assert_ok!(register_worker(executor, WorkerType::Executable, "exec"));
+		let task_id = task_of(creator, 10).pinned_to(executor).schedule();
+
+		// Reception has not been confirmed yet
+		assert_noop!(
+			TaskManagementModule::submit_task_result(
+				RuntimeOrigin::signed(executor),
+				task_id,
+				BoundedVec::truncate_from(b"result".to_vec()),
+				None
+			),
+			Error::<Test>::InvalidTaskState
+		);
+	});
+}
+
+#[test]
+fn submit_task_result_rejects_invalid_cpu_usage() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		let creator = 1;
+		let executor = 2;
+
+		assert_ok!(register_worker(executor, WorkerType::Executable, "exec"));
+		let task_id = task_of(creator, 10).pinned_to(executor).schedule();
+
+		assert_ok!(TaskManagementModule::confirm_task_reception(
+			RuntimeOrigin::signed(executor),
+			task_id
+		));
+
+		assert_noop!(
+			TaskManagementModule::submit_task_result(
+				RuntimeOrigin::signed(executor),
+				task_id,
+				BoundedVec::truncate_from(b"result".to_vec()),
+				Some(101)
+			),
+			Error::<Test>::InvalidCpuUsage
+		);
+	});
+}
+
+#[test]
+fn unused_deposit_is_refunded_on_completion() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		let creator = 1;
+		let executor = 2;
+
+		assert_ok!(register_worker(executor, WorkerType::Executable, "exec"));
+		let task_id = task_of(creator, 10).pinned_to(executor).schedule();
+		assert_eq!(pallet_payment::ComputeHours::<Test>::get(creator), 90);
+
+		assert_ok!(TaskManagementModule::confirm_task_reception(
+			RuntimeOrigin::signed(executor),
+			task_id
+		));
+
+		// 25 blocks consume 3 of the 10 deposited compute hours
+		System::set_block_number(26);
+		assert_ok!(TaskManagementModule::submit_task_result(
+			RuntimeOrigin::signed(executor),
+			task_id,
+			BoundedVec::truncate_from(b"result".to_vec()),
+			None
+		));
+
+		assert_eq!(pallet_payment::ComputeHours::<Test>::get(creator), 97);
+		System::assert_has_event(
+			crate::Event::ComputeHoursRefunded {
+				task_id,
+				task_owner: creator,
+				refunded_hours: 7,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn unused_deposit_is_refunded_when_task_is_stopped() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		let creator = 1;
+		let executor = 2;
+
+		assert_ok!(register_worker(executor, WorkerType::Executable, "exec"));
+		let task_id = task_of(creator, 10).pinned_to(executor).schedule();
+
+		assert_ok!(TaskManagementModule::confirm_task_reception(
+			RuntimeOrigin::signed(executor),
+			task_id
+		));
+
+		// 5 blocks are charged as one started compute hour
+		System::set_block_number(6);
+		assert_ok!(TaskManagementModule::stop_task_and_vacate_miner(
+			RuntimeOrigin::signed(creator),
+			task_id
+		));
+
+		let task = Tasks::<Test>::get(task_id).unwrap();
+		assert_eq!(task.time_elapsed, Some(5));
+		assert_eq!(task.consume_compute_hours, Some(1));
+		assert_eq!(pallet_payment::ComputeHours::<Test>::get(creator), 99);
+		System::assert_has_event(
+			crate::Event::ComputeHoursRefunded {
+				task_id,
+				task_owner: creator,
+				refunded_hours: 9,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn fully_used_deposit_is_not_refunded() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		let creator = 1;
+		let executor = 2;
+
+		assert_ok!(register_worker(executor, WorkerType::Executable, "exec"));
+		let task_id = task_of(creator, 2).pinned_to(executor).schedule();
+
+		assert_ok!(TaskManagementModule::confirm_task_reception(
+			RuntimeOrigin::signed(executor),
+			task_id
+		));
+
+		// Running past the deposit does not give anything back
+		System::set_block_number(41);
+		assert_ok!(TaskManagementModule::submit_task_result(
+			RuntimeOrigin::signed(executor),
+			task_id,
+			BoundedVec::truncate_from(b"result".to_vec()),
+			None
+		));
+
+		assert_eq!(pallet_payment::ComputeHours::<Test>::get(creator), 98);
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::TaskManagementModule(crate::Event::ComputeHoursRefunded { .. })
+		)));
+	});
+}
+
+/// Registers and activates one executable worker for each of `owners`.
+fn setup_active_workers(owners: &[u64]) {
+	for owner in owners {
+		assert_ok!(register_worker(*owner, WorkerType::Executable, &format!("worker{}", owner)));
+		activate_worker(worker_of(*owner));
+	}
+}
+
+/// Runs an inference task of account 1 on `executor` until it submitted `result`.
+fn run_task_until_validation(executor: u64, result: &[u8]) -> u64 {
+	let task_id = task_of(1, 10).pinned_to(executor).schedule();
+
+	assert_ok!(TaskManagementModule::confirm_task_reception(
+		RuntimeOrigin::signed(executor),
+		task_id
+	));
+	System::set_block_number(System::block_number() + 5);
+	assert_ok!(TaskManagementModule::submit_task_result(
+		RuntimeOrigin::signed(executor),
+		task_id,
+		BoundedVec::truncate_from(result.to_vec()),
+		None
+	));
+
+	task_id
+}
+
+fn verifier_of(task_id: u64) -> (u64, WorkerId) {
+	TaskVerifications::<Test>::get(task_id).unwrap().verifier.unwrap().worker
+}
+
+fn resolver_of(task_id: u64) -> (u64, WorkerId) {
+	TaskVerifications::<Test>::get(task_id).unwrap().resolver.unwrap().worker
+}
+
+fn reputation_of(worker: (u64, WorkerId)) -> i32 {
+	pallet_edge_connect::ExecutableWorkers::<Test>::get(worker).unwrap().reputation.score
+}
+
+#[test]
+fn inference_result_is_sent_to_a_verifier() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		let executor = 2;
+		setup_active_workers(&[executor, 4]);
+
+		let task_id = run_task_until_validation(executor, b"result");
+
+		assert_eq!(TaskStatus::<Test>::get(task_id), Some(TaskStatusType::PendingValidation));
+		assert_eq!(verifier_of(task_id), worker_of(4));
+
+		// Settlement waits for the verification
+		assert_eq!(pallet_payment::ComputeHours::<Test>::get(1), 90);
+		System::assert_last_event(
+			crate::Event::VerifierAssigned {
+				task_id,
+				verifier: worker_of(4),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn matching_verification_completes_task() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		let executor = 2;
+		setup_active_workers(&[executor, 4, 5]);
+
+		let task_id = run_task_until_validation(executor, b"result");
+		let verifier = verifier_of(task_id);
+
+		assert_ok!(TaskManagementModule::verify_completed_task(
+			RuntimeOrigin::signed(verifier.0),
+			task_id,
+			BoundedVec::truncate_from(b"result".to_vec())
+		));
+
+		let task = Tasks::<Test>::get(task_id).unwrap();
+		assert_eq!(task.task_status, TaskStatusType::Completed);
+		assert_eq!(task.result, Some(BoundedVec::truncate_from(b"result".to_vec())));
+		assert_eq!(reputation_of(worker_of(executor)), 100);
+		assert_eq!(reputation_of(verifier), 100);
+
+		// One compute hour was used, the rest of the deposit is back
+		assert_eq!(pallet_payment::ComputeHours::<Test>::get(1), 99);
+	});
+}
+
+#[test]
+fn verified_completion_rewards_the_executor() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		let executor = 2;
+		setup_active_workers(&[executor, 4]);
+		assert_ok!(EdgeConnectModule::penalize_worker(
+			RuntimeOrigin::root(),
+			executor,
+			worker_of(executor).1,
+			WorkerType::Executable,
+			20,
+			pallet_edge_connect::PenaltyReason::LateResponse
+		));
+
+		let task_id = run_task_until_validation(executor, b"result");
+		assert_ok!(TaskManagementModule::verify_completed_task(
+			RuntimeOrigin::signed(verifier_of(task_id).0),
+			task_id,
+			BoundedVec::truncate_from(b"result".to_vec())
+		));
+
+		let reputation = pallet_edge_connect::ExecutableWorkers::<Test>::get(worker_of(executor))
+			.unwrap()
+			.reputation;
+		assert_eq!(reputation.score, 85);
+		assert_eq!(reputation.successful_tasks, 1);
+	});
+}
+
+#[test]
+fn verified_neuro_zk_completion_leaves_the_reward_to_neuro_zk() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		let executor = 2;
+		setup_active_workers(&[executor, 4]);
+
+		let task_id = task_of(1, 10).neuro_zk().pinned_to(executor).schedule();
+		assert_ok!(TaskManagementModule::confirm_task_reception(
+			RuntimeOrigin::signed(executor),
+			task_id
+		));
+		assert_ok!(TaskManagementModule::submit_task_result(
+			RuntimeOrigin::signed(executor),
+			task_id,
+			BoundedVec::truncate_from(b"result".to_vec()),
+			None
+		));
+		assert_ok!(TaskManagementModule::verify_completed_task(
+			RuntimeOrigin::signed(verifier_of(task_id).0),
+			task_id,
+			BoundedVec::truncate_from(b"result".to_vec())
+		));
+
+		// The worker is rewarded once its proof is accepted in neuro-zk
+		assert_eq!(
+			Tasks::<Test>::get(task_id).unwrap().task_status,
+			TaskStatusType::Completed
+		);
+		let reputation = pallet_edge_connect::ExecutableWorkers::<Test>::get(worker_of(executor))
+			.unwrap()
+			.reputation;
+		assert_eq!(reputation.successful_tasks, 0);
+	});
+}
+
+#[test]
+fn verification_fails_for_unassigned_worker() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		let executor = 2;
+		setup_active_workers(&[executor, 4]);
+
+		let task_id = run_task_until_validation(executor, b"result");
+
+		assert_noop!(
+			TaskManagementModule::verify_completed_task(
+				RuntimeOrigin::signed(executor),
+				task_id,
+				BoundedVec::truncate_from(b"result".to_vec())
+			),
+			Error::<Test>::RequireAssignedVerifier
+		);
+	});
+}
+
+#[test]
+fn verification_mismatch_assigns_resolver() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		let executor = 2;
+		setup_active_workers(&[executor, 4, 5]);
+
+		let task_id = run_task_until_validation(executor, b"result");
+		let verifier = verifier_of(task_id);
+
+		assert_ok!(TaskManagementModule::verify_completed_task(
+			RuntimeOrigin::signed(verifier.0),
+			task_id,
+			BoundedVec::truncate_from(b"other result".to_vec())
+		));
+
+		assert_eq!(TaskStatus::<Test>::get(task_id), Some(TaskStatusType::PendingResolution));
+		let resolver = resolver_of(task_id);
+		assert_ne!(resolver.0, executor);
+		assert_ne!(resolver.0, verifier.0);
+
+		assert_noop!(
+			TaskManagementModule::resolve_completed_task(
+				RuntimeOrigin::signed(verifier.0),
+				task_id,
+				BoundedVec::truncate_from(b"result".to_vec())
+			),
+			Error::<Test>::RequireAssignedResolver
+		);
+	});
+}
+
+#[test]
+fn resolver_agreeing_with_executor_penalizes_verifier() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		let executor = 2;
+		setup_active_workers(&[executor, 4, 5]);
+
+		let task_id = run_task_until_validation(executor, b"result");
+		let verifier = verifier_of(task_id);
+		assert_ok!(TaskManagementModule::verify_completed_task(
+			RuntimeOrigin::signed(verifier.0),
+			task_id,
+			BoundedVec::truncate_from(b"other result".to_vec())
+		));
+
+		let resolver = resolver_of(task_id);
+		assert_ok!(TaskManagementModule::resolve_completed_task(
+			RuntimeOrigin::signed(resolver.0),
+			task_id,
+			BoundedVec::truncate_from(b"result".to_vec())
+		));
+
+		let task = Tasks::<Test>::get(task_id).unwrap();
+		assert_eq!(task.task_status, TaskStatusType::Completed);
+		assert_eq!(task.result, Some(BoundedVec::truncate_from(b"result".to_vec())));
+		assert_eq!(reputation_of(worker_of(executor)), 100);
+		assert_eq!(reputation_of(verifier), 80);
+		assert_eq!(reputation_of(resolver), 100);
+	});
+}
+
+#[test]
+fn resolver_agreeing_with_verifier_penalizes_executor() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		let executor = 2;
+		setup_active_workers(&[executor, 4, 5]);
+
+		let task_id = run_task_until_validation(executor, b"wrong result");
+		let verifier = verifier_of(task_id);
+		assert_ok!(TaskManagementModule::verify_completed_task(
+			RuntimeOrigin::signed(verifier.0),
+			task_id,
+			BoundedVec::truncate_from(b"result".to_vec())
+		));
+
+		let resolver = resolver_of(task_id);
+		assert_ok!(TaskManagementModule::resolve_completed_task(
+			RuntimeOrigin::signed(resolver.0),
+			task_id,
+			BoundedVec::truncate_from(b"result".to_vec())
+		));
+
+		// The verifier's result replaces the executor's
+		let task = Tasks::<Test>::get(task_id).unwrap();
+		assert_eq!(task.task_status, TaskStatusType::Completed);
+		assert_eq!(task.result, Some(BoundedVec::truncate_from(b"result".to_vec())));
+		assert_eq!(reputation_of(worker_of(executor)), 80);
+		assert_eq!(reputation_of(verifier), 100);
+	});
+}
+
+#[test]
+fn disagreeing_results_reassign_task_to_uninvolved_worker() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		let executor = 2;
+		setup_active_workers(&[executor, 4, 5]);
+
+		let task_id = run_task_until_validation(executor, b"result a");
+		let verifier = verifier_of(task_id);
+		assert_ok!(TaskManagementModule::verify_completed_task(
+			RuntimeOrigin::signed(verifier.0),
+			task_id,
+			BoundedVec::truncate_from(b"result b".to_vec())
+		));
+
+		// A fourth worker becomes available only now
+		setup_active_workers(&[6]);
+		let resolver = resolver_of(task_id);
+		assert_ok!(TaskManagementModule::resolve_completed_task(
+			RuntimeOrigin::signed(resolver.0),
+			task_id,
+			BoundedVec::truncate_from(b"result c".to_vec())
+		));
+
+		let task = Tasks::<Test>::get(task_id).unwrap();
+		assert_eq!(task.task_status, TaskStatusType::Assigned);
+		assert_eq!(task.result, None);
+		assert_eq!(TaskAllocations::<Test>::get(task_id), Some(worker_of(6)));
+		assert_eq!(TaskVerifications::<Test>::get(task_id), None);
+		assert_eq!(reputation_of(worker_of(executor)), 100);
+		System::assert_last_event(
+			crate::Event::TaskReassigned {
+				task_id,
+				assigned_worker: worker_of(6),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn disagreeing_results_fail_task_without_uninvolved_worker() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		let executor = 2;
+		setup_active_workers(&[executor, 4, 5]);
+
+		let task_id = run_task_until_validation(executor, b"result a");
+		let verifier = verifier_of(task_id);
+		assert_ok!(TaskManagementModule::verify_completed_task(
+			RuntimeOrigin::signed(verifier.0),
+			task_id,
+			BoundedVec::truncate_from(b"result b".to_vec())
+		));
+
+		let resolver = resolver_of(task_id);
+		assert_ok!(TaskManagementModule::resolve_completed_task(
+			RuntimeOrigin::signed(resolver.0),
+			task_id,
+			BoundedVec::truncate_from(b"result c".to_vec())
+		));
+
+		// Nobody is charged for a task that could not be completed
+		assert_eq!(TaskStatus::<Test>::get(task_id), Some(TaskStatusType::Failed));
+		assert_eq!(pallet_payment::ComputeHours::<Test>::get(1), 100);
+		System::assert_last_event(crate::Event::TaskFailed { task_id }.into());
+	});
+}
+
+/// Advances to block `n`, running the `on_initialize` hook of the pallet for every block.
+fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		System::set_block_number(System::block_number() + 1);
+		TaskManagementModule::on_initialize(System::block_number());
+	}
+}
+
+#[test]
+fn late_verifier_is_penalized_and_replaced() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_active_workers(&[2, 4, 5]);
+
+		let task_id = run_task_until_validation(2, b"result");
+		let late = verifier_of(task_id);
+		assert_eq!(TaskDeadlines::<Test>::get(task_id), Some(106));
+
+		run_to_block(106);
+		let replacement = verifier_of(task_id);
+		assert_eq!(reputation_of(late), 90);
+		assert!(replacement != late && replacement != worker_of(2));
+		assert_eq!(
+			TaskStatus::<Test>::get(task_id),
+			Some(TaskStatusType::PendingValidation)
+		);
+		assert_eq!(TaskDeadlines::<Test>::get(task_id), Some(206));
+		assert_eq!(TasksByWorker::<Test>::get(late, task_id), None);
+		assert_eq!(
+			TasksByWorker::<Test>::get(replacement, task_id),
+			Some(TaskStatusType::PendingValidation)
+		);
+	});
+}
+
+#[test]
+fn result_is_accepted_when_no_other_verifier_replaces_a_late_one() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_active_workers(&[2, 4]);
+
+		let task_id = run_task_until_validation(2, b"result");
+		run_to_block(106);
+
+		assert_eq!(reputation_of(worker_of(4)), 90);
+		let task = Tasks::<Test>::get(task_id).unwrap();
+		assert_eq!(task.task_status, TaskStatusType::Completed);
+		assert_eq!(
+			task.result,
+			Some(BoundedVec::truncate_from(b"result".to_vec()))
+		);
+		assert_eq!(TasksByWorker::<Test>::get(worker_of(4), task_id), None);
+		assert_eq!(pallet_payment::ComputeHours::<Test>::get(1), 99);
+	});
+}
+
+#[test]
+fn task_is_reassigned_when_no_other_resolver_replaces_a_late_one() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_active_workers(&[2, 4, 5, 6]);
+
+		let task_id = run_task_until_validation(2, b"result");
+		let verifier = verifier_of(task_id);
+		assert_ok!(TaskManagementModule::verify_completed_task(
+			RuntimeOrigin::signed(verifier.0),
+			task_id,
+			BoundedVec::truncate_from(b"other".to_vec())
+		));
+		let late = resolver_of(task_id);
+		assert_eq!(TaskDeadlines::<Test>::get(task_id), Some(106));
+
+		// The only worker left becomes the resolver
+		run_to_block(106);
+		assert_eq!(reputation_of(late), 90);
+		let replacement = resolver_of(task_id);
+		assert_eq!(
+			TaskStatus::<Test>::get(task_id),
+			Some(TaskStatusType::PendingResolution)
+		);
+
+		// Nobody is left to resolve the task, which is handed to a new worker
+		run_to_block(206);
+		assert_eq!(reputation_of(replacement), 90);
+		assert_eq!(
+			TaskStatus::<Test>::get(task_id),
+			Some(TaskStatusType::Assigned)
+		);
+		assert_eq!(TaskAllocations::<Test>::get(task_id), Some(late));
+		assert_eq!(TaskVerifications::<Test>::get(task_id), None);
+	});
+}
+
+#[test]
+fn owner_can_cancel_task_pending_validation() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_active_workers(&[2, 4]);
+		let task_id = run_task_until_validation(2, b"result");
+
+		assert_ok!(TaskManagementModule::cancel_task(
+			RuntimeOrigin::signed(1),
+			task_id
+		));
+
+		assert_eq!(
+			TaskStatus::<Test>::get(task_id),
+			Some(TaskStatusType::Stopped)
+		);
+		assert_eq!(TaskDeadlines::<Test>::get(task_id), None);
+		assert_eq!(TaskVerifications::<Test>::get(task_id), None);
+		assert_eq!(TasksByWorker::<Test>::get(worker_of(4), task_id), None);
+		// The compute hours recorded with the result are charged
+		assert_eq!(pallet_payment::ComputeHours::<Test>::get(1), 99);
+	});
+}
+
+#[test]
+fn executor_of_a_task_cancelled_pending_validation_can_take_a_new_task() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_worker_with_capacity(2, 1);
+		setup_active_workers(&[4]);
+		let task_id = run_task_until_validation(2, b"result");
+
+		assert_ok!(TaskManagementModule::cancel_task(
+			RuntimeOrigin::signed(1),
+			task_id
+		));
+
+		// The executor is done running the task, so it has nothing left to vacate
+		assert_eq!(ActiveAllocations::<Test>::get(worker_of(2)), 0);
+		assert_eq!(ActiveAllocations::<Test>::get(worker_of(4)), 0);
+		assert_eq!(worker_status(worker_of(2)), WorkerStatusType::Active);
+		let next = task_of(1, 10).pinned_to(2).schedule();
+		assert_eq!(TaskAllocations::<Test>::get(next), Some(worker_of(2)));
+	});
+}
+
+#[test]
+fn unconfirmed_task_is_reassigned_after_reception_timeout() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_active_workers(&[2, 4]);
+		pallet_payment::ComputeHours::<Test>::insert(1, 100);
+
+		let task_id = task_of(1, 10).pinned_to(2).schedule();
+		assert_eq!(TaskDeadlines::<Test>::get(task_id), Some(11));
+
+		run_to_block(10);
+		assert_eq!(TaskAllocations::<Test>::get(task_id), Some(worker_of(2)));
+
+		run_to_block(11);
+		assert_eq!(TaskStatus::<Test>::get(task_id), Some(TaskStatusType::Assigned));
+		assert_eq!(TaskAllocations::<Test>::get(task_id), Some(worker_of(4)));
+		assert_eq!(TaskDeadlines::<Test>::get(task_id), Some(21));
+		assert_eq!(reputation_of(worker_of(2)), 90);
+		System::assert_has_event(
+			crate::Event::TaskTimedOut {
+				task_id,
+				worker: worker_of(2),
+				task_status: TaskStatusType::Assigned,
+			}
+			.into(),
+		);
+		System::assert_last_event(
+			crate::Event::TaskReassigned {
+				task_id,
+				assigned_worker: worker_of(4),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn running_task_fails_after_execution_timeout_without_other_workers() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_active_workers(&[2]);
+		pallet_payment::ComputeHours::<Test>::insert(1, 100);
+
+		let task_id = task_of(1, 10).pinned_to(2).schedule();
+		assert_ok!(TaskManagementModule::confirm_task_reception(
+			RuntimeOrigin::signed(2),
+			task_id
+		));
+
+		// Confirming the reception replaces the reception deadline
+		run_to_block(11);
+		assert_eq!(TaskStatus::<Test>::get(task_id), Some(TaskStatusType::Running));
+
+		run_to_block(101);
+		assert_eq!(TaskStatus::<Test>::get(task_id), Some(TaskStatusType::Failed));
+		assert_eq!(TaskDeadlines::<Test>::get(task_id), None);
+		assert_eq!(reputation_of(worker_of(2)), 90);
+		// The late worker's execution is not charged
+		assert_eq!(pallet_payment::ComputeHours::<Test>::get(1), 100);
+		System::assert_last_event(crate::Event::TaskFailed { task_id }.into());
+	});
+}
+
+#[test]
+fn execution_timeout_follows_the_compute_hours_deposit() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_active_workers(&[2, 4]);
+
+		// A deposit of 2 compute hours pays for 20 blocks
+		let short = task_of(1, 2).pinned_to(2).schedule();
+		assert_ok!(TaskManagementModule::confirm_task_reception(
+			RuntimeOrigin::signed(2),
+			short
+		));
+		assert_eq!(TaskDeadlines::<Test>::get(short), Some(21));
+
+		// Deposits paying for more blocks are capped by `ExecutionTimeout`
+		let long = task_of(1, 50).pinned_to(4).schedule();
+		assert_ok!(TaskManagementModule::confirm_task_reception(
+			RuntimeOrigin::signed(4),
+			long
+		));
+		assert_eq!(TaskDeadlines::<Test>::get(long), Some(101));
+
+		run_to_block(21);
+		assert_eq!(
+			TaskStatus::<Test>::get(short),
+			Some(TaskStatusType::Assigned)
+		);
+		assert_eq!(TaskAllocations::<Test>::get(short), Some(worker_of(4)));
+		assert_eq!(TaskStatus::<Test>::get(long), Some(TaskStatusType::Running));
+	});
+}
+
+#[test]
+fn completed_task_does_not_time_out() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_active_workers(&[2]);
+		pallet_payment::ComputeHours::<Test>::insert(1, 100);
+
+		let task_id = task_of(1, 10).pinned_to(2).schedule();
+		assert_ok!(TaskManagementModule::confirm_task_reception(
+			RuntimeOrigin::signed(2),
+			task_id
+		));
+		assert_ok!(TaskManagementModule::submit_task_result(
+			RuntimeOrigin::signed(2),
+			task_id,
+			BoundedVec::truncate_from(b"result".to_vec()),
+			None
+		));
+		assert_eq!(TaskDeadlines::<Test>::get(task_id), None);
+
+		run_to_block(101);
+		assert_eq!(TaskStatus::<Test>::get(task_id), Some(TaskStatusType::Completed));
+		assert_eq!(reputation_of(worker_of(2)), 100);
+	});
+}
+
+#[test]
+fn deadlines_overflow_into_following_blocks() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_active_workers(&[2]);
+		pallet_payment::ComputeHours::<Test>::insert(1, 100);
+
+		let tasks: Vec<u64> = (0..3)
+			.map(|_| task_of(1, 1).pinned_to(2).schedule())
+			.collect();
+
+		assert_eq!(ExpiringTasks::<Test>::get(11).into_inner(), vec![tasks[0], tasks[1]]);
+		assert_eq!(ExpiringTasks::<Test>::get(12).into_inner(), vec![tasks[2]]);
+		assert_eq!(TaskDeadlines::<Test>::get(tasks[2]), Some(12));
+	});
+}
+
+#[test]
+fn task_submissions_over_the_rate_limit_are_rejected() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_active_workers(&[2]);
+
+		for _ in 0..5 {
+			task_of(3, 1).schedule();
+		}
+		assert_eq!(TaskManagementModule::task_submission_allowance(&3), (0, 10));
+
+		// The sixth submission is rejected without consuming compute hours
+		pallet_payment::ComputeHours::<Test>::insert(3, 100);
+		assert_noop!(
+			TaskManagementModule::task_scheduler(
+				RuntimeOrigin::signed(3),
+				TaskKind::OpenInference,
+				BoundedVec::truncate_from(b"model.bin".to_vec()),
+				None,
+				None,
+				Some(1),
+				TaskOptions::default()
+			),
+			Error::<Test>::RateLimitExceeded
+		);
+		assert_eq!(pallet_payment::ComputeHours::<Test>::get(3), 100);
+	});
+}
+
+#[test]
+fn rate_limit_resets_after_the_window() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_active_workers(&[2]);
+
+		for _ in 0..5 {
+			task_of(3, 1).schedule();
+		}
+
+		System::set_block_number(10);
+		assert_eq!(TaskManagementModule::task_submission_allowance(&3), (5, 20));
+		let task_id = task_of(3, 1).schedule();
+
+		assert_eq!(TaskStatus::<Test>::get(task_id), Some(TaskStatusType::Assigned));
+		assert_eq!(TaskRateLimits::<Test>::get(3), (10, 1));
+	});
+}
+
+#[test]
+fn gatekeeper_is_exempt_from_the_rate_limit() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_active_workers(&[2]);
+		pallet_payment::ComputeHours::<Test>::insert(1, 100);
+
+		for _ in 0..6 {
+			task_of(1, 1).pinned_to(2).schedule();
+		}
+
+		assert_eq!(NextTaskId::<Test>::get(), 6);
+		assert_eq!(TaskRateLimits::<Test>::get(1), (0, 0));
+		assert_eq!(
+			TaskManagementModule::task_submission_allowance(&1).0,
+			u32::MAX
+		);
+	});
+}
+
+#[test]
+fn queued_task_is_dispatched_when_a_worker_becomes_active() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		assert_ok!(register_worker(2, WorkerType::Executable, "exec"));
+		let task_id = task_of(3, 1).schedule();
+
+		// Its owner makes the worker active
+		activate_worker(worker_of(2));
+
+		assert_eq!(TaskStatus::<Test>::get(task_id), Some(TaskStatusType::Assigned));
+		assert_eq!(TaskAllocations::<Test>::get(task_id), Some(worker_of(2)));
+		assert_eq!(TaskDeadlines::<Test>::get(task_id), Some(11));
+		assert!(PendingTasks::<Test>::get(TaskClass::OpenInference, TaskPriority::Normal).is_empty());
+		System::assert_last_event(
+			crate::Event::TaskScheduled {
+				assigned_worker: worker_of(2),
+				task_kind: TaskKind::OpenInference,
+				task_owner: 3,
+				task_id,
+				task: BoundedVec::truncate_from(b"model.bin".to_vec()),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn queued_tasks_are_dispatched_by_priority_then_age() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		assert_ok!(register_worker(2, WorkerType::Executable, "exec"));
+		let low = task_of(3, 1).priority(TaskPriority::Low).schedule();
+		let first = task_of(3, 1).schedule();
+		let second = task_of(3, 1).schedule();
+
+		activate_worker(worker_of(2));
+		assert_eq!(scheduled_tasks(), vec![first, second, low]);
+	});
+}
+
+/// The tasks handed to a worker so far, in the order they were assigned.
+fn scheduled_tasks() -> Vec<u64> {
+	System::events()
+		.into_iter()
+		.filter_map(|record| match record.event {
+			RuntimeEvent::TaskManagementModule(crate::Event::TaskScheduled { task_id, .. }) => {
+				Some(task_id)
+			}
+			_ => None,
+		})
+		.collect()
+}
+
+#[test]
+fn queued_tasks_are_dispatched_up_to_the_capacity_of_the_worker() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		let first = task_of(3, 1).schedule();
+		let second = task_of(3, 1).schedule();
+		let third = task_of(3, 1).schedule();
+
+		setup_worker_with_capacity(2, 2);
+		assert_eq!(scheduled_tasks(), vec![first, second]);
+		assert_eq!(worker_status(worker_of(2)), WorkerStatusType::Busy);
+		assert_eq!(
+			TaskManagementModule::pending_tasks(TaskClass::OpenInference),
+			vec![(third, TaskPriority::Normal)]
+		);
+	});
+}
+
+#[test]
+fn queued_tasks_are_dispatched_a_bounded_number_at_a_time() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		assert_ok!(register_worker(2, WorkerType::Executable, "exec"));
+		let queued: Vec<_> = (0..5).map(|_| task_of(3, 1).schedule()).collect();
+
+		// At most `MaxTasksPerDispatch` tasks are handed out at once
+		activate_worker(worker_of(2));
+		assert_eq!(scheduled_tasks(), queued[..3].to_vec());
+
+		// The rest follow the next status report
+		TaskManagementModule::on_worker_active(&worker_of(2), &WorkerType::Executable);
+		assert_eq!(scheduled_tasks(), queued);
+		assert!(TaskManagementModule::pending_tasks(TaskClass::OpenInference).is_empty());
+	});
+}
+
+#[test]
+fn queued_task_waits_for_a_worker_meeting_its_requirements() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		assert_ok!(register_worker(2, WorkerType::Executable, "small"));
+		register_large_worker(4, "large", 590000, 120000);
+
+		pallet_payment::ComputeHours::<Test>::insert(3, 100);
+		assert_ok!(TaskManagementModule::task_scheduler(
+			RuntimeOrigin::signed(3),
+			TaskKind::OpenInference,
+			BoundedVec::truncate_from(b"model.bin".to_vec()),
+			None,
+			None,
+			Some(1),
+			TaskOptions {
+				requirements: Some(ResourceRequirements {
+					cpu: 16,
+					..Default::default()
+				}),
+				..Default::default()
+			}
+		));
+		let task_id = NextTaskId::<Test>::get() - 1;
+
+		activate_worker(worker_of(2));
+		assert_eq!(TaskStatus::<Test>::get(task_id), Some(TaskStatusType::Pending));
+
+		activate_worker(worker_of(4));
+		assert_eq(TaskAllocations::<Test>::get(task_id), Some(worker_of(4)));
+	});
+}
+
+#[test]
+fn only_gatekeeper_can_schedule_high_priority_tasks() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		pallet_payment::ComputeHours::<Test>::insert(3, 100);
+
+		assert_noop!(
+			TaskManagementModule::task_scheduler(
+				RuntimeOrigin::signed(3),
+				TaskKind::OpenInference,
+				BoundedVec::truncate_from(b"model.bin".to_vec()),
+				None,
+				None,
+				Some(1),
+				TaskOptions {
+					priority: TaskPriority::High,
+					..Default::default()
+				}
+			),
+			Error::<Test>::NotGatekeeper
+		);
+	});
+}
+
+#[test]
+fn task_scheduler_fails_when_pending_queue_is_full() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+
+		for _ in 0..5 {
+			task_of(3, 1).priority(TaskPriority::Low).schedule();
+		}
+
+		// The rate limit window is over, the queue is still full
+		System::set_block_number(20);
+		assert_noop!(
+			TaskManagementModule::task_scheduler(
+				RuntimeOrigin::signed(3),
+				TaskKind::OpenInference,
+				BoundedVec::truncate_from(b"model.bin".to_vec()),
+				None,
+				None,
+				Some(1),
+				TaskOptions {
+					priority: TaskPriority::Low,
+					..Default::default()
+				}
+			),
+			Error::<Test>::PendingQueueFull
+		);
+	});
+}
+
+#[test]
+fn cancelling_a_queued_task_refunds_its_deposit() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		let task_id = task_of(3, 1).schedule();
+		assert_eq!(pallet_payment::ComputeHours::<Test>::get(3), 99);
+
+		assert_ok!(TaskManagementModule::cancel_task(RuntimeOrigin::signed(3), task_id));
+
+		assert_eq!(TaskStatus::<Test>::get(task_id), Some(TaskStatusType::Stopped));
+		assert!(PendingTasks::<Test>::get(TaskClass::OpenInference, TaskPriority::Normal).is_empty());
+		assert_eq!(pallet_payment::ComputeHours::<Test>::get(3), 100);
+	});
+}
+
+/// Funds account 3 and sets up account 10 as the service provider collecting task tips.
+fn setup_tipping() {
+	assert_ok!(PaymentModule::set_service_provider_account(RuntimeOrigin::root(), 10));
+	assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), 10, 1_000));
+	assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), 3, 1_000));
+}
+
+#[test]
+fn tip_is_paid_to_the_worker_whose_result_is_accepted() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_tipping();
+		setup_active_workers(&[2]);
+
+		let task_id = task_of(3, 1).tip(100).schedule();
+		assert_eq!(Balances::free_balance(3), 900);
+		assert_eq!(TaskTips::<Test>::get(task_id), Some(100));
+
+		assert_ok!(TaskManagementModule::confirm_task_reception(
+			RuntimeOrigin::signed(2),
+			task_id
+		));
+		assert_ok!(TaskManagementModule::submit_task_result(
+			RuntimeOrigin::signed(2),
+			task_id,
+			BoundedVec::truncate_from(b"result".to_vec()),
+			None
+		));
+
+		assert_eq!(TaskTips::<Test>::get(task_id), None);
+		assert_eq!(pallet_payment::MinerPendingRewards::<Test>::get(2), 100);
+	});
+}
+
+#[test]
+fn tip_is_refunded_when_task_is_cancelled() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_tipping();
+		setup_active_workers(&[2]);
+
+		let task_id = task_of(3, 1).tip(100).schedule();
+		assert_ok!(TaskManagementModule::cancel_task(RuntimeOrigin::signed(3), task_id));
+
+		assert_eq!(TaskTips::<Test>::get(task_id), None);
+		assert_eq!(Balances::free_balance(3), 1_000);
+		assert_eq!(pallet_payment::MinerPendingRewards::<Test>::get(2), 0);
+	});
+}
+
+#[test]
+fn queued_tasks_with_higher_tips_are_dispatched_first() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_tipping();
+		assert_ok!(register_worker(2, WorkerType::Executable, "exec"));
+
+		let untipped = task_of(3, 1).tip(0).schedule();
+		let small_tip = task_of(3, 1).tip(10).schedule();
+		let large_tip = task_of(3, 1).tip(50).schedule();
+
+		activate_worker(worker_of(2));
+		assert_eq!(scheduled_tasks(), vec![large_tip, small_tip, untipped]);
+	});
+}
+
+/// An inference node of a task graph depending on the nodes at `parents`.
+fn graph_node(parents: &[u32]) -> TaskGraphNode {
+	TaskGraphNode {
+		task_kind: TaskKind::OpenInference,
+		task_location: BoundedVec::truncate_from(b"model.bin".to_vec()),
+		nzk_info: None,
+		requirements: None,
+		compute_hours_deposit: 10,
+		parents: BoundedVec::truncate_from(parents.to_vec()),
+	}
+}
+
+/// Has account 3 submit a graph of `nodes` and returns the id of its first task.
+fn submit_graph(nodes: Vec<TaskGraphNode>) -> u64 {
+	pallet_payment::ComputeHours::<Test>::insert(3, 100);
+	let first_task_id = NextTaskId::<Test>::get();
+	assert_ok!(TaskManagementModule::submit_task_graph(
+		RuntimeOrigin::signed(3),
+		BoundedVec::truncate_from(nodes)
+	));
+	first_task_id
+}
+
+/// Has the assigned worker run `task_id` and submit `result`.
+fn complete_graph_task(task_id: u64, result: &[u8]) {
+	let (worker, _) = TaskAllocations::<Test>::get(task_id).unwrap();
+	assert_ok!(TaskManagementModule::confirm_task_reception(
+		RuntimeOrigin::signed(worker),
+		task_id
+	));
+	assert_ok!(TaskManagementModule::submit_task_result(
+		RuntimeOrigin::signed(worker),
+		task_id,
+		BoundedVec::truncate_from(result.to_vec()),
+		None
+	));
+	assert_eq!(TaskStatus::<Test>::get(task_id), Some(TaskStatusType::Completed));
+}
+
+#[test]
+fn graph_tasks_are_scheduled_once_their_parents_complete() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_active_workers(&[2]);
+
+		// preprocess -> inference -> proof, the proof also needing the preprocessed data
+		let preprocess = submit_graph(vec![graph_node(&[]), graph_node(&[0]), graph_node(&[0, 1])]);
+		let (inference, proof) = (preprocess + 1, preprocess + 2);
+		assert_eq!(NextTaskId::<Test>::get(), preprocess + 3);
+		assert_eq!(pallet_payment::ComputeHours::<Test>::get(3), 70);
+		assert_eq!(TaskStatus::<Test>::get(preprocess), Some(TaskStatusType::Assigned));
+		for task_id in [inference, proof] {
+			assert_eq!(
+				TaskStatus::<Test>::get(task_id),
+				Some(TaskStatusType::WaitingForDependencies)
+			);
+		}
+		assert_eq!(TaskParents::<Test>::get(proof).into_inner(), vec![preprocess, inference]);
+		assert_eq!(TaskChildren::<Test>::get(preprocess).into_inner(), vec![inference, proof]);
+
+		complete_graph_task(preprocess, b"preprocessed");
+		assert_eq!(TaskStatus::<Test>::get(inference), Some(TaskStatusType::Assigned));
+		assert_eq!(TaskStatus::<Test>::get(proof), Some(TaskStatusType::WaitingForDependencies));
+		System::assert_has_event(
+			crate::Event::TaskDependenciesMet {
+				task_id: inference,
+				parent_results: vec![(
+					preprocess,
+					BoundedVec::truncate_from(b"preprocessed".to_vec()),
+				)],
+			}
+			.into(),
+		);
+
+		complete_graph_task(inference, b"inferred");
+		assert_eq!(TaskStatus::<Test>::get(proof), Some(TaskStatusType::Assigned));
+		assert_eq!(
+			TaskManagementModule::parent_results(proof),
+			Some(vec![
+				(preprocess, BoundedVec::truncate_from(b"preprocessed".to_vec())),
+				(inference, BoundedVec::truncate_from(b"inferred".to_vec())),
+			])
+		);
+	});
+}
+
+#[test]
+fn submit_task_graph_rejects_invalid_graphs() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		pallet_payment::ComputeHours::<Test>::insert(3, 100);
+
+		for nodes in [vec![], vec![graph_node(&[0])], vec![graph_node(&[1]), graph_node(&[])]] {
+			assert_noop!(
+				TaskManagementModule::submit_task_graph(
+					RuntimeOrigin::signed(3),
+					BoundedVec::truncate_from(nodes)
+				),
+				Error::<Test>::InvalidTaskGraph
+			);
+		}
+	});
+}
+
+#[test]
+fn graph_failure_cascades_to_dependent_tasks() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_active_workers(&[2]);
+
+		let first = submit_graph(vec![graph_node(&[]), graph_node(&[0]), graph_node(&[1])]);
+		assert_eq!(pallet_payment::ComputeHours::<Test>::get(3), 70);
+
+		// The only worker never confirms the first task, no other worker can take it
+		run_to_block(11);
+
+		for task_id in first..first + 3 {
+			assert_eq!(TaskStatus::<Test>::get(task_id), Some(TaskStatusType::Failed));
+			System::assert_has_event(crate::Event::TaskFailed { task_id }.into());
+		}
+		assert_eq!(pallet_payment::ComputeHours::<Test>::get(3), 100);
+		assert!(TaskChildren::<Test>::get(first).is_empty());
+	});
+}
+
+#[test]
+fn cancelling_a_graph_task_fails_its_dependents() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_active_workers(&[2]);
+
+		let first = submit_graph(vec![graph_node(&[]), graph_node(&[]), graph_node(&[1])]);
+		assert_ok!(TaskManagementModule::cancel_task(RuntimeOrigin::signed(3), first + 1));
+
+		assert_eq!(TaskStatus::<Test>::get(first), Some(TaskStatusType::Assigned));
+		assert_eq!(TaskStatus::<Test>::get(first + 1), Some(TaskStatusType::Stopped));
+		assert_eq!(TaskStatus::<Test>::get(first + 2), Some(TaskStatusType::Failed));
+		assert_eq!(pallet_payment::ComputeHours::<Test>::get(3), 90);
+	});
+}
+
+#[test]
+fn every_graph_task_counts_against_the_rate_limit() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_active_workers(&[2]);
+
+		let first = submit_graph(vec![graph_node(&[]); 4]);
+		assert_eq!(TaskRateLimits::<Test>::get(3), (0, 4));
+
+		// A second graph of two tasks would exceed the five tasks allowed per window
+		assert_noop!(
+			TaskManagementModule::submit_task_graph(
+				RuntimeOrigin::signed(3),
+				BoundedVec::truncate_from(vec![graph_node(&[]), graph_node(&[0])])
+			),
+			Error::<Test>::RateLimitExceeded
+		);
+		assert_eq!(NextTaskId::<Test>::get(), first + 4);
+		assert_eq!(TaskManagementModule::task_submission_allowance(&3), (1, 10));
+	});
+}
+
+/// Has account 3 schedule an inference task with a deposit of 10 hours per run.
+fn schedule_task_at(start: u64, interval: Option<u64>, runs: u32) -> u64 {
+	pallet_payment::ComputeHours::<Test>::insert(3, 100);
+	assert_ok!(TaskManagementModule::schedule_task_at(
+		RuntimeOrigin::signed(3),
+		TaskKind::OpenInference,
+		BoundedVec::truncate_from(b"nightly-batch.bin".to_vec()),
+		None,
+		None,
+		10,
+		start,
+		interval,
+		runs
+	));
+	NextScheduleId::<Test>::get() - 1
+}
+
+#[test]
+fn scheduled_task_starts_at_its_block() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_active_workers(&[2]);
+
+		let schedule_id = schedule_task_at(5, None, 1);
+		assert_eq!(pallet_payment::ComputeHours::<Test>::get(3), 90);
+		assert_eq!(TaskAgenda::<Test>::get(5).into_inner(), vec![schedule_id]);
+
+		let task_id = NextTaskId::<Test>::get();
+		run_to_block(4);
+		assert_eq!(NextTaskId::<Test>::get(), task_id);
+
+		run_to_block(5);
+		assert_eq!(
+			TaskStatus::<Test>::get(task_id),
+			Some(TaskStatusType::Assigned)
+		);
+		assert_eq!(TaskOwners::<Test>::get(task_id), Some(3));
+		assert_eq!(
+			Tasks::<Test>::get(task_id).unwrap().compute_hours_deposit,
+			Some(10)
+		);
+		assert!(!ScheduledTasks::<Test>::contains_key(schedule_id));
+		System::assert_has_event(
+			crate::Event::ScheduledTaskStarted {
+				schedule_id,
+				task_id,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn recurring_task_runs_every_interval_until_its_runs_are_used() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_active_workers(&[2]);
+
+		let schedule_id = schedule_task_at(5, Some(10), 3);
+		assert_eq!(pallet_payment::ComputeHours::<Test>::get(3), 70);
+		let first_task_id = NextTaskId::<Test>::get();
+
+		run_to_block(5);
+		let scheduled = ScheduledTasks::<Test>::get(schedule_id).unwrap();
+		assert_eq!((scheduled.next_run, scheduled.remaining_runs), (15, 2));
+
+		run_to_block(25);
+		assert_eq!(NextTaskId::<Test>::get(), first_task_id + 3);
+		assert!(!ScheduledTasks::<Test>::contains_key(schedule_id));
+		for task_id in first_task_id..first_task_id + 3 {
+			assert_eq!(Tasks::<Test>::get(task_id).unwrap().task_owner, 3);
+		}
+	});
+}
+
+#[test]
+fn owner_can_cancel_the_remaining_runs_of_a_recurring_task() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_active_workers(&[2]);
+
+		let schedule_id = schedule_task_at(5, Some(10), 3);
+		run_to_block(5);
+		let next_task_id = NextTaskId::<Test>::get();
+
+		assert_noop!(
+			TaskManagementModule::cancel_scheduled_task(RuntimeOrigin::signed(4), schedule_id),
+			Error::<Test>::NotTaskOwner
+		);
+		assert_ok!(TaskManagementModule::cancel_scheduled_task(
+			RuntimeOrigin::signed(3),
+			schedule_id
+		));
+
+		// The run that already started is not affected
+		assert_eq!(pallet_payment::ComputeHours::<Test>::get(3), 90);
+		assert!(TaskAgenda::<Test>::get(15).is_empty());
+		System::assert_last_event(
+			crate::Event::ScheduledTaskCancelled {
+				schedule_id,
+				refunded_hours: 20,
+			}
+			.into(),
+		);
+
+		run_to_block(15);
+		assert_eq!(NextTaskId::<Test>::get(), next_task_id);
+	});
+}
+
+#[test]
+fn schedule_task_at_rejects_invalid_schedules() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(5);
+		pallet_payment::ComputeHours::<Test>::insert(3, 100);
+
+		for (start, interval, runs) in [(5, None, 1), (6, None, 0), (6, None, 2), (6, Some(0), 2)] {
+			assert_noop!(
+				TaskManagementModule::schedule_task_at(
+					RuntimeOrigin::signed(3),
+					TaskKind::OpenInference,
+					BoundedVec::truncate_from(b"nightly-batch.bin".to_vec()),
+					None,
+					None,
+					10,
+					start,
+					interval,
+					runs
+				),
+				Error::<Test>::InvalidSchedule
+			);
+		}
+	});
+}
+
+#[test]
+fn finished_tasks_are_pruned_after_the_retention_period() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_active_workers(&[2]);
+
+		let task_id = task_of(3, 10).schedule();
+		assert_ok!(TaskManagementModule::confirm_task_reception(
+			RuntimeOrigin::signed(2),
+			task_id
+		));
+		System::set_block_number(3);
+		assert_ok!(TaskManagementModule::submit_task_result(
+			RuntimeOrigin::signed(2),
+			task_id,
+			BoundedVec::truncate_from(b"result".to_vec()),
+			None
+		));
+		let task_hash = BlakeTwo256::hash_of(&Tasks::<Test>::get(task_id).unwrap());
+
+		TaskManagementModule::on_idle(102, Weight::MAX);
+		assert!(Tasks::<Test>::contains_key(task_id));
+
+		TaskManagementModule::on_idle(103, Weight::MAX);
+		assert!(!Tasks::<Test>::contains_key(task_id));
+		assert!(!TaskStatus::<Test>::contains_key(task_id));
+		assert!(!TaskOwners::<Test>::contains_key(task_id));
+		assert!(!TaskAllocations::<Test>::contains_key(task_id));
+		assert!(!ComputeAggregations::<Test>::contains_key(task_id));
+		assert_eq!(PruningQueueRange::<Test>::get(), (1, 1));
+
+		let summary = TaskSummary {
+			task_hash,
+			task_owner: 3,
+			worker: Some(worker_of(2)),
+			outcome: TaskStatusType::Completed,
+			consumed_compute_hours: 1,
+		};
+		assert_eq!(TaskSummaries::<Test>::get(task_id), Some(summary.clone()));
+		System::assert_last_event(crate::Event::TaskPruned { task_id, summary }.into());
+	});
+}
+
+#[test]
+fn pruning_stops_at_recent_tasks_and_within_the_weight_limit() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_active_workers(&[2]);
+
+		let vacated = |finished_at: u64| {
+			System::set_block_number(finished_at);
+			let task_id = task_of(3, 10).schedule();
+			assert_ok!(TaskManagementModule::cancel_task(
+				RuntimeOrigin::signed(3),
+				task_id
+			));
+			assert_ok!(TaskManagementModule::confirm_miner_vacation(
+				RuntimeOrigin::signed(3),
+				task_id
+			));
+			task_id
+		};
+		let old = vacated(1);
+		let recent = vacated(50);
+		// Stopped tasks are not finished until their miner vacated them
+		let stopped = task_of(3, 10).schedule();
+		assert_ok!(TaskManagementModule::cancel_task(
+			RuntimeOrigin::signed(3),
+			stopped
+		));
+
+		TaskManagementModule::on_idle(101, Weight::MAX);
+		assert!(TaskSummaries::<Test>::contains_key(old));
+		assert!(Tasks::<Test>::contains_key(recent));
+
+		let base = <Test as frame_system::Config>::DbWeight::get().reads_writes(1, 1);
+		TaskManagementModule::on_idle(200, base);
+		assert!(Tasks::<Test>::contains_key(recent));
+
+		// Pruning a task reads the status of up to `MaxGraphNodes` children
+		let children = <Test as frame_system::Config>::DbWeight::get().reads(5);
+		TaskManagementModule::on_idle(200, base + <() as WeightInfo>::prune_task() + children);
+		assert!(TaskSummaries::<Test>::contains_key(recent));
+		assert!(Tasks::<Test>::contains_key(stopped));
+	});
+}
+
+#[test]
+fn cancelled_tasks_that_were_never_assigned_are_pruned() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+
+		// No worker can take the task, so it waits in the pending queue
+		let task_id = task_of(3, 10).schedule();
+		assert_ok!(TaskManagementModule::cancel_task(
+			RuntimeOrigin::signed(3),
+			task_id
+		));
+		assert_eq!(
+			TaskStatus::<Test>::get(task_id),
+			Some(TaskStatusType::Stopped)
+		);
+
+		TaskManagementModule::on_idle(101, Weight::MAX);
+		assert!(!Tasks::<Test>::contains_key(task_id));
+		assert_eq!(
+			TaskSummaries::<Test>::get(task_id).map(|summary| summary.outcome),
+			Some(TaskStatusType::Stopped)
+		);
+	});
+}
+
+#[test]
+fn graph_tasks_are_not_pruned_while_their_children_wait() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_active_workers(&[2]);
+
+		// The last task needs the results of both others
+		let first = submit_graph(vec![graph_node(&[]), graph_node(&[]), graph_node(&[0, 1])]);
+		let (second, last) = (first + 1, first + 2);
+		complete_graph_task(first, b"first");
+
+		System::set_block_number(200);
+		TaskManagementModule::on_idle(200, Weight::MAX);
+		assert!(Tasks::<Test>::contains_key(first));
+
+		complete_graph_task(second, b"second");
+		assert_eq!(
+			TaskStatus::<Test>::get(last),
+			Some(TaskStatusType::Assigned)
+		);
+		System::assert_has_event(
+			crate::Event::TaskDependenciesMet {
+				task_id: last,
+				parent_results: vec![
+					(first, BoundedVec::truncate_from(b"first".to_vec())),
+					(second, BoundedVec::truncate_from(b"second".to_vec())),
+				],
+			}
+			.into(),
+		);
+
+		// Once released, the children no longer hold their parents back
+		TaskManagementModule::on_idle(300, Weight::MAX);
+		assert!(TaskSummaries::<Test>::contains_key(first));
+		assert!(TaskSummaries::<Test>::contains_key(second));
+	});
+}
+
+#[test]
+fn tasks_are_indexed_by_owner_and_worker_on_every_transition() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_active_workers(&[2]);
+
+		let task_id = task_of(3, 10).schedule();
+		assert_eq!(
+			TasksByOwner::<Test>::get(3, task_id),
+			Some(TaskStatusType::Assigned)
+		);
+		assert_eq!(
+			TasksByWorker::<Test>::get(worker_of(2), task_id),
+			Some(TaskStatusType::Assigned)
+		);
+
+		assert_ok!(TaskManagementModule::confirm_task_reception(
+			RuntimeOrigin::signed(2),
+			task_id
+		));
+		assert_eq!(
+			TasksByOwner::<Test>::get(3, task_id),
+			Some(TaskStatusType::Running)
+		);
+		assert_eq!(
+			TasksByWorker::<Test>::get(worker_of(2), task_id),
+			Some(TaskStatusType::Running)
+		);
+
+		assert_ok!(TaskManagementModule::submit_task_result(
+			RuntimeOrigin::signed(2),
+			task_id,
+			BoundedVec::truncate_from(b"result".to_vec()),
+			None
+		));
+		assert_eq!(
+			TasksByOwner::<Test>::get(3, task_id),
+			Some(TaskStatusType::Completed)
+		);
+		assert_eq!(TasksByWorker::<Test>::get(worker_of(2), task_id), None);
+
+		// Pruning drops the task from the indexes
+		TaskManagementModule::on_idle(101, Weight::MAX);
+		assert_eq!(TasksByOwner::<Test>::get(3, task_id), None);
+	});
+}
+
+#[test]
+fn migration_to_v1_indexes_existing_tasks() {
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_active_workers(&[2]);
+		let completed = task_of(3, 10).schedule();
+		assert_ok!(TaskManagementModule::confirm_task_reception(
+			RuntimeOrigin::signed(2),
+			completed
+		));
+		assert_ok!(TaskManagementModule::submit_task_result(
+			RuntimeOrigin::signed(2),
+			completed,
+			BoundedVec::truncate_from(b"result".to_vec()),
+			None
+		));
+		let assigned = task_of(3, 10).schedule();
+		// Tasks created before the indexes existed
+		let _ = TasksByOwner::<Test>::clear(u32::MAX, None);
+		let _ = TasksByWorker::<Test>::clear(u32::MAX, None);
+		StorageVersion::new(0).put::<TaskManagementModule>();
+
+		crate::migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(TaskManagementModule::on_chain_storage_version(), 1);
+		assert_eq!(
+			TasksByOwner::<Test>::get(3, completed),
+			Some(TaskStatusType::Completed)
+		);
+		assert_eq!(
+			TasksByOwner::<Test>::get(3, assigned),
+			Some(TaskStatusType::Assigned)
+		);
+		assert_eq!(TasksByWorker::<Test>::get(worker_of(2), completed), None);
+		assert_eq!(
+			TasksByWorker::<Test>::get(worker_of(2), assigned),
+			Some(TaskStatusType::Assigned)
+		);
+	});
+}
+
+#[test]
+fn migration_to_v1_counts_running_tasks_against_their_worker() {
+	use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_worker_with_capacity(2, 1);
+		let task_id = task_of(3, 10).schedule();
+		assert_ok!(TaskManagementModule::confirm_task_reception(
+			RuntimeOrigin::signed(2),
+			task_id
+		));
+		// A task that was running before allocations were counted
+		ActiveAllocations::<Test>::remove(worker_of(2));
+		crate::OccupiedWorkers::<Test>::remove(task_id);
+		let mut worker = pallet_edge_connect::ExecutableWorkers::<Test>::get(worker_of(2)).unwrap();
+		worker.status = WorkerStatusType::Active;
+		pallet_edge_connect::Pallet::<Test>::store_worker(
+			&worker_of(2),
+			&WorkerType::Executable,
+			worker,
+		);
+		StorageVersion::new(0).put::<TaskManagementModule>();
+
+		crate::migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(ActiveAllocations::<Test>::get(worker_of(2)), 1);
+		assert_eq!(
+			crate::OccupiedWorkers::<Test>::get(task_id),
+			Some(worker_of(2))
+		);
+		assert_eq!(worker_status(worker_of(2)), WorkerStatusType::Busy);
+
+		// Once the task is done, the worker takes new tasks again
+		assert_ok!(TaskManagementModule::submit_task_result(
+			RuntimeOrigin::signed(2),
+			task_id,
+			BoundedVec::truncate_from(b"result".to_vec()),
+			None
+		));
+		assert_eq!(ActiveAllocations::<Test>::get(worker_of(2)), 0);
+	});
+}
+
+#[test]
+fn verifiers_and_reassigned_workers_are_indexed() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_active_workers(&[2, 4]);
+
+		let task_id = run_task_until_validation(2, b"result");
+		let verifier = verifier_of(task_id);
+		let pending = Some(TaskStatusType::PendingValidation);
+		assert_eq!(TasksByWorker::<Test>::get(worker_of(2), task_id), pending);
+		assert_eq!(TasksByWorker::<Test>::get(verifier, task_id), pending);
+
+		// Without a resolver the task goes to a worker not involved so far, or fails
+		assert_ok!(TaskManagementModule::verify_completed_task(
+			RuntimeOrigin::signed(verifier.0),
+			task_id,
+			BoundedVec::truncate_from(b"other".to_vec())
+		));
+		assert_eq!(TasksByWorker::<Test>::get(worker_of(2), task_id), None);
+		assert_eq!(TasksByWorker::<Test>::get(verifier, task_id), None);
+		assert_eq!(
+			TasksByOwner::<Test>::get(1, task_id),
+			Some(TaskStatusType::Failed)
+		);
+	});
+}
+
+#[test]
+fn tasks_by_owner_is_paginated() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_active_workers(&[2]);
+
+		let mut scheduled: Vec<_> = (0..3).map(|_| task_of(3, 10).schedule()).collect();
+		let first_page = TaskManagementModule::tasks_by_owner(&3, None, 2);
+		assert_eq!(first_page.len(), 2);
+		let last = first_page.last().map(|(task_id, _)| *task_id);
+		let second_page = TaskManagementModule::tasks_by_owner(&3, last, 2);
+		assert_eq!(second_page.len(), 1);
+
+		let mut listed: Vec<_> = first_page
+			.into_iter()
+			.chain(second_page)
+			.map(|(task_id, _)| task_id)
+			.collect();
+		listed.sort();
+		scheduled.sort();
+		assert_eq!(listed, scheduled);
+		assert_eq!(
+			TaskManagementModule::tasks_by_worker(&worker_of(2), None, 10).len(),
+			3
+		);
+	});
+}
+
+#[test]
+fn task_queries_are_capped() {
+	new_test_ext().execute_with(|| {
+		for task_id in 0..(MAX_TASKS_PER_QUERY as u64 + 10) {
+			TasksByOwner::<Test>::insert(3, task_id, TaskStatusType::Completed);
+		}
+
+		assert_eq!(
+			TaskManagementModule::tasks_by_owner(&3, None, u32::MAX).len(),
+			MAX_TASKS_PER_QUERY as usize
+		);
+	});
+}
+
+#[test]
+fn pending_tasks_are_listed_in_dispatch_order() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		let low = task_of(3, 1).priority(TaskPriority::Low).schedule();
+		let first = task_of(3, 1).schedule();
+		let second = task_of(3, 1).schedule();
+
+		assert_eq!(
+			TaskManagementModule::pending_tasks(TaskClass::OpenInference),
+			vec![
+				(first, TaskPriority::Normal),
+				(second, TaskPriority::Normal),
+				(low, TaskPriority::Low)
+			]
+		);
+		assert!(TaskManagementModule::pending_tasks(TaskClass::NeuroZK).is_empty());
+	});
+}
+
+#[test]
+fn task_cost_estimate_follows_the_subscription_fee() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(
+			TaskManagementModule::task_cost_estimate(&TaskKind::OpenInference, 5),
+			0
+		);
+
+		assert_ok!(PaymentModule::set_subscription_fee_per_hour(
+			RuntimeOrigin::root(),
+			20
+		));
+		assert_eq!(
+			TaskManagementModule::task_cost_estimate(&TaskKind::OpenInference, 5),
+			100
+		);
+		assert_eq!(
+			TaskManagementModule::task_cost_estimate(&TaskKind::NeuroZK, 0),
+			0
+		);
+	});
+}
+
+/// Registers and activates an executable worker of `owner` that accepts `max_concurrent_tasks`
+/// tasks at the same time.
+fn setup_worker_with_capacity(owner: u64, max_concurrent_tasks: u32) {
+	assert_ok!(EdgeConnectModule::register_worker(
+		RuntimeOrigin::signed(owner),
+		WorkerType::Executable,
+		BoundedVec::truncate_from(b"limited".to_vec()),
+		590000,
+		120000,
+		10000000,
+		10000000,
+		12,
+		max_concurrent_tasks
+	));
+	activate_worker(worker_of(owner));
+}
+
+fn worker_status(worker: (u64, WorkerId)) -> WorkerStatusType {
+	pallet_edge_connect::ExecutableWorkers::<Test>::get(worker)
+		.unwrap()
+		.status
+}
+
+#[test]
+fn worker_is_busy_while_at_capacity() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_worker_with_capacity(2, 2);
+
+		let first = task_of(3, 10).schedule();
+		assert_eq!(worker_status(worker_of(2)), WorkerStatusType::Active);
+		let second = task_of(3, 10).schedule();
+		assert_eq!(ActiveAllocations::<Test>::get(worker_of(2)), 2);
+		assert_eq!(worker_status(worker_of(2)), WorkerStatusType::Busy);
+
+		// A full worker is not handed more tasks
+		let queued = task_of(3, 10).schedule();
+		assert_eq!(
+			TaskStatus::<Test>::get(queued),
+			Some(TaskStatusType::Pending)
+		);
+		assert_eq!(TaskAllocations::<Test>::get(first), Some(worker_of(2)));
+		assert_eq!(TaskAllocations::<Test>::get(second), Some(worker_of(2)));
+
+		// A stopped task keeps the worker busy until it vacated it
+		assert_ok!(TaskManagementModule::cancel_task(
+			RuntimeOrigin::signed(3),
+			first
+		));
+		assert_eq!(worker_status(worker_of(2)), WorkerStatusType::Busy);
+		// The capacity it frees goes to the queued task right away
+		assert_ok!(TaskManagementModule::confirm_miner_vacation(
+			RuntimeOrigin::signed(2),
+			first
+		));
+		assert_eq!(TaskAllocations::<Test>::get(queued), Some(worker_of(2)));
+		assert_eq!(ActiveAllocations::<Test>::get(worker_of(2)), 2);
+		assert_eq!(worker_status(worker_of(2)), WorkerStatusType::Busy);
+	});
+}
+
+#[test]
+fn worker_reported_available_at_capacity_stays_busy() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_worker_with_capacity(2, 1);
+		task_of(3, 10).schedule();
+		let queued = task_of(3, 10).schedule();
+
+		// The status aggregator marks the worker active again
+		activate_worker(worker_of(2));
+		TaskManagementModule::on_worker_active(&worker_of(2), &WorkerType::Executable);
+
+		assert_eq!(
+			TaskStatus::<Test>::get(queued),
+			Some(TaskStatusType::Pending)
+		);
+		assert_eq!(worker_status(worker_of(2)), WorkerStatusType::Busy);
+	});
+}
+
+#[test]
+fn gatekeeper_cannot_pin_a_worker_at_capacity() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_worker_with_capacity(2, 1);
+		task_of(1, 10).pinned_to(2).schedule();
+
+		assert_noop!(
+			TaskManagementModule::task_scheduler(
+				RuntimeOrigin::signed(1),
+				TaskKind::OpenInference,
+				BoundedVec::truncate_from(b"model.bin".to_vec()),
+				None,
+				Some(worker_of(2)),
+				Some(10),
+				TaskOptions::default()
+			),
+			Error::<Test>::WorkerAtCapacity
+		);
+	});
+}
+
+#[test]
+fn verifier_capacity_is_taken_while_it_reruns_the_task() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_active_workers(&[2, 4]);
+		let task_id = run_task_until_validation(2, b"result");
+		let verifier = verifier_of(task_id);
+
+		assert_eq!(ActiveAllocations::<Test>::get(worker_of(2)), 0);
+		assert_eq!(ActiveAllocations::<Test>::get(verifier), 1);
+
+		assert_ok!(TaskManagementModule::verify_completed_task(
+			RuntimeOrigin::signed(verifier.0),
+			task_id,
+			BoundedVec::truncate_from(b"result".to_vec())
+		));
+		assert_eq!(ActiveAllocations::<Test>::get(verifier), 0);
+	});
+}
+
+fn container_task_kind() -> TaskKind {
+	TaskKind::Container(ContainerImage {
+		reference: BoundedVec::truncate_from(b"registry.example.com/jobs/render:1.2".to_vec()),
+		digest: [7; 32],
+	})
+}
+
+fn setup_active_docker_worker(account: u64) {
+	assert_ok!(register_worker(
+		account,
+		WorkerType::Docker,
+		&format!("cluster{}", account)
+	));
+	assert_ok!(EdgeConnectModule::toggle_worker_visibility(
+		RuntimeOrigin::signed(account),
+		WorkerType::Docker,
+		worker_of(account).1,
+		true
+	));
+}
+
+#[test]
+fn container_task_runs_on_a_docker_worker() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_active_workers(&[2]);
+		setup_active_docker_worker(3);
+
+		let task_id = task_of(1, 10).container().schedule();
+		assert_eq!(TaskAllocations::<Test>::get(task_id), Some(worker_of(3)));
+		assert_eq!(
+			Tasks::<Test>::get(task_id).unwrap().task_kind,
+			container_task_kind()
+		);
+
+		assert_ok!(TaskManagementModule::confirm_task_reception(
+			RuntimeOrigin::signed(3),
+			task_id
+		));
+		System::set_block_number(11);
+		assert_ok!(TaskManagementModule::submit_task_result(
+			RuntimeOrigin::signed(3),
+			task_id,
+			BoundedVec::truncate_from(b"ipfs://frames".to_vec()),
+			None
+		));
+
+		// Container results are accepted without a verifier
+		assert_eq!(
+			TaskStatus::<Test>::get(task_id),
+			Some(TaskStatusType::Completed)
+		);
+		assert_eq!(TaskVerifications::<Test>::get(task_id), None);
+	});
+}
+
+#[test]
+fn container_task_can_be_stopped_and_vacated() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_active_docker_worker(3);
+		let task_id = task_of(1, 10).container().schedule();
+		assert_ok!(TaskManagementModule::confirm_task_reception(
+			RuntimeOrigin::signed(3),
+			task_id
+		));
+
+		assert_ok!(TaskManagementModule::stop_task_and_vacate_miner(
+			RuntimeOrigin::signed(1),
+			task_id
+		));
+		assert_ok!(TaskManagementModule::confirm_miner_vacation(
+			RuntimeOrigin::signed(3),
+			task_id
+		));
+		assert_eq!(
+			TaskStatus::<Test>::get(task_id),
+			Some(TaskStatusType::Vacated)
+		);
+	});
+}
+
+#[test]
+fn container_task_waits_for_a_docker_worker() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_active_workers(&[2]);
+		let task_id = task_of(1, 10).container().schedule();
+
+		// Executable workers cannot run containers
+		assert_eq!(
+			TaskStatus::<Test>::get(task_id),
+			Some(TaskStatusType::Pending)
+		);
+		assert_eq!(
+			TaskManagementModule::pending_tasks(TaskClass::Container),
+			vec![(task_id, TaskPriority::Normal)]
+		);
+		TaskManagementModule::on_worker_active(&worker_of(2), &WorkerType::Executable);
+		assert_eq!(
+			TaskStatus::<Test>::get(task_id),
+			Some(TaskStatusType::Pending)
+		);
+
+		setup_active_docker_worker(3);
+		TaskManagementModule::on_worker_active(&worker_of(3), &WorkerType::Docker);
+		assert_eq!(
+			TaskStatus::<Test>::get(task_id),
+			Some(TaskStatusType::Assigned)
+		);
+		assert_eq!(TaskAllocations::<Test>::get(task_id), Some(worker_of(3)));
+		assert!(TaskManagementModule::pending_tasks(TaskClass::Container).is_empty());
+	});
+}
+
+#[test]
+fn gatekeeper_can_only_pin_docker_workers_for_container_tasks() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_active_workers(&[2]);
+		setup_active_docker_worker(3);
+		pallet_payment::ComputeHours::<Test>::insert(1, 100);
+
+		let pin = |worker| {
+			TaskManagementModule::task_scheduler(
+				RuntimeOrigin::signed(1),
+				container_task_kind(),
+				BoundedVec::truncate_from(b"--frames 1-100".to_vec()),
+				None,
+				Some(worker),
+				Some(10),
+				TaskOptions::default(),
+			)
+		};
+		assert_noop!(pin(worker_of(2)), Error::<Test>::WorkerDoesNotExist);
+		assert_ok!(pin(worker_of(3)));
+		assert_eq!(
+			TaskAllocations::<Test>::get(NextTaskId::<Test>::get() - 1),
+			Some(worker_of(3))
+		);
+	});
+}
+
+#[test]
+fn container_task_rejects_zk_files() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_active_docker_worker(3);
+		pallet_payment::ComputeHours::<Test>::insert(1, 100);
+
+		assert_noop!(
+			TaskManagementModule::task_scheduler(
+				RuntimeOrigin::signed(1),
+				container_task_kind(),
+				BoundedVec::truncate_from(b"--frames 1-100".to_vec()),
+				Some(NeuroZkTaskSubmissionDetails {
+					zk_input: BoundedVec::truncate_from(b"input".to_vec()),
+					zk_settings: BoundedVec::truncate_from(b"settings".to_vec()),
+					zk_verifying_key: BoundedVec::truncate_from(b"vk".to_vec()),
+				}),
+				None,
+				Some(10),
+				TaskOptions::default()
+			),
+			Error::<Test>::UnexpectedZkFiles
+		);
+	});
+}
+
+/// The X25519 secret key derived from `seed`.
+fn secret_key_of(seed: &str) -> crypto_box::SecretKey {
+	crypto_box::SecretKey::from(sp_core::hashing::blake2_256(seed.as_bytes()))
+}
+
+fn encryption_key_of(seed: &str) -> EncryptionKey {
+	secret_key_of(seed).public_key().to_bytes()
+}
+
+/// Seals `plaintext` for `recipient` the way task owners and workers do off-chain.
+fn encrypt_to(recipient: EncryptionKey, plaintext: &[u8]) -> EncryptedPayload {
+	use crypto_box::aead::Aead;
+	let ephemeral = secret_key_of("//Ephemeral");
+	let nonce = [3; 24];
+	let ciphertext = crypto_box::SalsaBox::new(&recipient.into(), &ephemeral)
+		.encrypt(&nonce.into(), plaintext)
+		.unwrap();
+	EncryptedPayload {
+		recipient,
+		ephemeral_key: ephemeral.public_key().to_bytes(),
+		nonce,
+		ciphertext: BoundedVec::truncate_from(ciphertext),
+	}
+}
+
+/// Opens `payload` with the secret key derived from `seed`, if it was sealed for it.
+fn decrypt_with(seed: &str, payload: &EncryptedPayload) -> Option<Vec<u8>> {
+	use crypto_box::aead::Aead;
+	crypto_box::SalsaBox::new(&payload.ephemeral_key.into(), &secret_key_of(seed))
+		.decrypt(&payload.nonce.into(), &payload.ciphertext[..])
+		.ok()
+}
+
+fn encrypted_payload(recipient: EncryptionKey) -> EncryptedPayload {
+	encrypt_to(recipient, b"model.bin")
+}
+
+/// Registers an active executable worker for `account` with the encryption key derived from
+/// `seed`.
+fn setup_confidential_worker(account: u64, seed: &str) {
+	setup_active_workers(&[account]);
+	assert_ok!(EdgeConnectModule::set_encryption_key(
+		RuntimeOrigin::signed(account),
+		WorkerType::Executable,
+		worker_of(account).1,
+		Some(encryption_key_of(seed))
+	));
+}
+
+#[test]
+fn confidential_task_is_only_assigned_to_workers_with_an_encryption_key() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_active_workers(&[2]);
+
+		let task_id = task_of(1, 10).confidential().schedule();
+		assert_eq!(
+			ConfidentialTasks::<Test>::get(task_id),
+			Some(encryption_key_of("//Owner"))
+		);
+		assert_eq!(
+			TaskStatus::<Test>::get(task_id),
+			Some(TaskStatusType::Pending)
+		);
+		TaskManagementModule::on_worker_active(&worker_of(2), &WorkerType::Executable);
+		assert_eq!(
+			TaskStatus::<Test>::get(task_id),
+			Some(TaskStatusType::Pending)
+		);
+
+		setup_confidential_worker(3, "//Worker3");
+		TaskManagementModule::on_worker_active(&worker_of(3), &WorkerType::Executable);
+		assert_eq!(TaskAllocations::<Test>::get(task_id), Some(worker_of(3)));
+
+		let next_task_id = task_of(1, 10).confidential().schedule();
+		assert_eq!(
+			TaskAllocations::<Test>::get(next_task_id),
+			Some(worker_of(3))
+		);
+	});
+}
+
+#[test]
+fn gatekeeper_cannot_pin_a_worker_without_encryption_key_to_a_confidential_task() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_active_workers(&[2]);
+		pallet_payment::ComputeHours::<Test>::insert(1, 100);
+
+		assert_noop!(
+			TaskManagementModule::task_scheduler(
+				RuntimeOrigin::signed(1),
+				TaskKind::OpenInference,
+				BoundedVec::new(),
+				None,
+				Some(worker_of(2)),
+				Some(10),
+				TaskOptions {
+					confidential: Some(encryption_key_of("//Owner")),
+					..Default::default()
+				}
+			),
+			Error::<Test>::WorkerEncryptionKeyMissing
+		);
+	});
+}
+
+#[test]
+fn confidential_task_is_confirmed_once_the_payload_is_submitted() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_confidential_worker(2, "//Worker2");
+		let task_id = task_of(1, 10).confidential().pinned_to(2).schedule();
+
+		assert_noop!(
+			TaskManagementModule::confirm_task_reception(RuntimeOrigin::signed(2), task_id),
+			Error::<Test>::TaskPayloadMissing
+		);
+		assert_noop!(
+			TaskManagementModule::submit_task_payload(
+				RuntimeOrigin::signed(2),
+				task_id,
+				encrypted_payload(encryption_key_of("//Worker2"))
+			),
+			Error::<Test>::NotTaskOwner
+		);
+		assert_noop!(
+			TaskManagementModule::submit_task_payload(
+				RuntimeOrigin::signed(1),
+				task_id,
+				encrypted_payload(encryption_key_of("//Worker3"))
+			),
+			Error::<Test>::WrongEncryptionKey
+		);
+
+		System::set_block_number(5);
+		assert_ok!(TaskManagementModule::submit_task_payload(
+			RuntimeOrigin::signed(1),
+			task_id,
+			encrypted_payload(encryption_key_of("//Worker2"))
+		));
+		assert_eq!(
+			TaskPayloads::<Test>::get(task_id),
+			Some(encrypted_payload(encryption_key_of("//Worker2")))
+		);
+		assert_eq!(TaskDeadlines::<Test>::get(task_id), Some(15));
+		System::assert_last_event(
+			crate::Event::TaskPayloadSubmitted {
+				task_id,
+				worker: worker_of(2),
+			}
+			.into(),
+		);
+
+		assert_ok!(TaskManagementModule::confirm_task_reception(
+			RuntimeOrigin::signed(2),
+			task_id
+		));
+		assert_eq!(
+			TaskStatus::<Test>::get(task_id),
+			Some(TaskStatusType::Running)
+		);
+	});
+}
+
+#[test]
+fn payload_can_only_be_submitted_for_confidential_tasks() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_confidential_worker(2, "//Worker2");
+		let task_id = task_of(1, 10).pinned_to(2).schedule();
+
+		assert_noop!(
+			TaskManagementModule::submit_task_payload(
+				RuntimeOrigin::signed(1),
+				task_id,
+				encrypted_payload(encryption_key_of("//Worker2"))
+			),
+			Error::<Test>::TaskNotConfidential
+		);
+	});
+}
+
+#[test]
+fn confidential_task_completes_with_a_result_encrypted_to_the_owner() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_confidential_worker(2, "//Worker2");
+		setup_confidential_worker(3, "//Worker3");
+		let task_id = task_of(1, 10).confidential().pinned_to(2).schedule();
+		assert_ok!(TaskManagementModule::submit_task_payload(
+			RuntimeOrigin::signed(1),
+			task_id,
+			encrypted_payload(encryption_key_of("//Worker2"))
+		));
+		assert_ok!(TaskManagementModule::confirm_task_reception(
+			RuntimeOrigin::signed(2),
+			task_id
+		));
+		System::set_block_number(6);
+
+		assert_noop!(
+			TaskManagementModule::submit_task_result(
+				RuntimeOrigin::signed(2),
+				task_id,
+				BoundedVec::truncate_from(b"plaintext".to_vec()),
+				None
+			),
+			Error::<Test>::EncryptedResultRequired
+		);
+		assert_noop!(
+			TaskManagementModule::submit_encrypted_task_result(
+				RuntimeOrigin::signed(2),
+				task_id,
+				encrypted_payload(encryption_key_of("//Worker2")),
+				None
+			),
+			Error::<Test>::WrongEncryptionKey
+		);
+		assert_noop!(
+			TaskManagementModule::submit_encrypted_task_result(
+				RuntimeOrigin::signed(3),
+				task_id,
+				encrypted_payload(encryption_key_of("//Owner")),
+				None
+			),
+			Error::<Test>::InvalidTaskOwner
+		);
+
+		let result = encrypted_payload(encryption_key_of("//Owner"));
+		assert_ok!(TaskManagementModule::submit_encrypted_task_result(
+			RuntimeOrigin::signed(2),
+			task_id,
+			result.clone(),
+			Some(40)
+		));
+
+		// Nobody else can read the task, so the result is not sent to a verifier
+		assert_eq!(
+			TaskStatus::<Test>::get(task_id),
+			Some(TaskStatusType::Completed)
+		);
+		assert_eq!(TaskVerifications::<Test>::get(task_id), None);
+		assert_eq!(EncryptedResults::<Test>::get(task_id), Some(result.clone()));
+		let task = Tasks::<Test>::get(task_id).unwrap();
+		assert_eq!(
+			task.result.unwrap().to_vec(),
+			BlakeTwo256::hash_of(&result).as_ref().to_vec()
+		);
+		assert_eq!(task.time_elapsed, Some(5));
+	});
+}
+
+#[test]
+fn worker_is_not_penalized_for_a_missing_payload() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_confidential_worker(2, "//Worker2");
+		setup_confidential_worker(3, "//Worker3");
+		let task_id = task_of(1, 10).confidential().pinned_to(2).schedule();
+
+		run_to_block(11);
+		assert_eq!(reputation_of(worker_of(2)), 100);
+		assert_eq!(TaskAllocations::<Test>::get(task_id), Some(worker_of(3)));
+
+		// Once it has the payload, the worker has to confirm in time
+		assert_ok!(TaskManagementModule::submit_task_payload(
+			RuntimeOrigin::signed(1),
+			task_id,
+			encrypted_payload(encryption_key_of("//Worker3"))
+		));
+		run_to_block(21);
+		assert_eq!(reputation_of(worker_of(3)), 90);
+		assert_eq!(TaskAllocations::<Test>::get(task_id), Some(worker_of(2)));
+		// The payload was encrypted to the previous worker
+		assert_eq!(TaskPayloads::<Test>::get(task_id), None);
+	});
+}
+
+#[test]
+fn confidential_task_rejects_metadata_in_the_clear() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		pallet_payment::ComputeHours::<Test>::insert(1, 100);
+
+		assert_noop!(
+			TaskManagementModule::task_scheduler(
+				RuntimeOrigin::signed(1),
+				TaskKind::OpenInference,
+				BoundedVec::truncate_from(b"model.bin".to_vec()),
+				None,
+				None,
+				Some(10),
+				TaskOptions {
+					confidential: Some(encryption_key_of("//Owner")),
+					..Default::default()
+				}
+			),
+			Error::<Test>::ConfidentialMetadataInClear
+		);
+	});
+}
+
+#[test]
+fn confidential_payloads_can_only_be_read_by_their_recipient() {
+	new_test_ext().execute_with(|| {
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_confidential_worker(2, "//Worker2");
+		let task_id = task_of(1, 10).confidential().pinned_to(2).schedule();
+
+		// The owner seals the metadata for the key the worker published in edge-connect
+		let worker_key = pallet_edge_connect::ExecutableWorkers::<Test>::get(worker_of(2))
+			.and_then(|worker| worker.encryption_key)
+			.unwrap();
+		assert_ok!(TaskManagementModule::submit_task_payload(
+			RuntimeOrigin::signed(1),
+			task_id,
+			encrypt_to(worker_key, b"s3://private/model.bin")
+		));
+		let payload = TaskPayloads::<Test>::get(task_id).unwrap();
+		assert_eq!(
+			decrypt_with("//Worker2", &payload),
+			Some(b"s3://private/model.bin".to_vec())
+		);
+		assert_eq!(decrypt_with("//Worker3", &payload), None);
+
+		// The worker seals the result for the key of the owner
+		assert_ok!(TaskManagementModule::confirm_task_reception(
+			RuntimeOrigin::signed(2),
+			task_id
+		));
+		let owner_key = ConfidentialTasks::<Test>::get(task_id).unwrap();
+		assert_ok!(TaskManagementModule::submit_encrypted_task_result(
+			RuntimeOrigin::signed(2),
+			task_id,
+			encrypt_to(owner_key, b"label: cat"),
+			None
+		));
+		let result = EncryptedResults::<Test>::get(task_id).unwrap();
+		assert_eq!(
+			decrypt_with("//Owner", &result),
+			Some(b"label: cat".to_vec())
+		);
+		assert_eq!(decrypt_with("//Worker2", &result), None);
+	});
+}
+
+/// Runs an inference task of account 1 on worker 2 until worker 4 verified its result, with the
+/// dispute window open, and returns its id.
+fn complete_disputable_task() -> u64 {
+	DisputeWindow::set(20);
+	setup_gatekeeper();
+	setup_tipping();
+	assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), 1, 1_000));
+	setup_active_workers(&[2, 4]);
+
+	let task_id = run_task_until_validation(2, b"result");
+	assert_ok!(TaskManagementModule::verify_completed_task(
+		RuntimeOrigin::signed(4),
+		task_id,
+		BoundedVec::truncate_from(b"result".to_vec())
+	));
+	assert_eq!(
+		TaskStatus::<Test>::get(task_id),
+		Some(TaskStatusType::Completed)
+	);
+	task_id
+}
+
+#[test]
+fn tip_is_paid_once_the_dispute_window_closes() {
+	new_test_ext().execute_with(|| {
+		DisputeWindow::set(20);
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_tipping();
+		setup_active_workers(&[2]);
+
+		let task_id = task_of(3, 1).tip(100).schedule();
+		assert_ok!(TaskManagementModule::confirm_task_reception(
+			RuntimeOrigin::signed(2),
+			task_id
+		));
+		assert_ok!(TaskManagementModule::submit_task_result(
+			RuntimeOrigin::signed(2),
+			task_id,
+			BoundedVec::truncate_from(b"result".to_vec()),
+			None
+		));
+		assert_eq!(DisputableResults::<Test>::get(task_id), Some(worker_of(2)));
+		assert_eq!(TaskDeadlines::<Test>::get(task_id), Some(21));
+		assert_eq!(pallet_payment::MinerPendingRewards::<Test>::get(2), 0);
+
+		run_to_block(21);
+		assert_eq!(TaskTips::<Test>::get(task_id), None);
+		assert_eq!(pallet_payment::MinerPendingRewards::<Test>::get(2), 100);
+		assert_eq!(
+			TaskStatus::<Test>::get(task_id),
+			Some(TaskStatusType::Completed)
+		);
+		System::assert_last_event(crate::Event::DisputeWindowClosed { task_id }.into());
+
+		assert_noop!(
+			TaskManagementModule::dispute_task_result(RuntimeOrigin::signed(3), task_id),
+			Error::<Test>::NotDisputable
+		);
+	});
+}
+
+#[test]
+fn upheld_dispute_forfeits_the_bond() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let task_id = complete_disputable_task();
+
+		assert_noop!(
+			TaskManagementModule::dispute_task_result(RuntimeOrigin::signed(3), task_id),
+			Error::<Test>::NotTaskOwner
+		);
+		assert_ok!(TaskManagementModule::dispute_task_result(
+			RuntimeOrigin::signed(1),
+			task_id
+		));
+		assert_eq!(Balances::free_balance(1), 950);
+		assert_eq!(Balances::free_balance(10), 1_050);
+		assert_eq!(
+			TaskStatus::<Test>::get(task_id),
+			Some(TaskStatusType::Disputed)
+		);
+		assert_eq!(TaskDeadlines::<Test>::get(task_id), None);
+		System::assert_last_event(
+			crate::Event::TaskDisputed {
+				task_id,
+				disputer: 1,
+				bond: 50,
+				verifier: Some(worker_of(4)),
+			}
+			.into(),
+		);
+
+		assert_noop!(
+			TaskManagementModule::submit_dispute_verification(
+				RuntimeOrigin::signed(2),
+				task_id,
+				BoundedVec::truncate_from(b"result".to_vec())
+			),
+			Error::<Test>::RequireDisputeVerifier
+		);
+		assert_ok!(TaskManagementModule::submit_dispute_verification(
+			RuntimeOrigin::signed(4),
+			task_id,
+			BoundedVec::truncate_from(b"result".to_vec())
+		));
+
+		assert_eq!(
+			TaskStatus::<Test>::get(task_id),
+			Some(TaskStatusType::Completed)
+		);
+		assert_eq!(TaskDisputes::<Test>::get(task_id), None);
+		assert_eq!(Balances::free_balance(1), 950);
+		assert_eq!(reputation_of(worker_of(2)), 100);
+		System::assert_last_event(
+			crate::Event::DisputeResolved {
+				task_id,
+				worker_at_fault: false,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn worker_at_fault_is_penalized_and_loses_its_reward() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let task_id = complete_disputable_task();
+		assert_eq!(pallet_payment::ComputeHours::<Test>::get(1), 99);
+
+		assert_ok!(TaskManagementModule::dispute_task_result(
+			RuntimeOrigin::signed(1),
+			task_id
+		));
+		assert_eq!(ActiveAllocations::<Test>::get(worker_of(4)), 1);
+		assert_ok!(TaskManagementModule::submit_dispute_verification(
+			RuntimeOrigin::signed(4),
+			task_id,
+			BoundedVec::truncate_from(b"another result".to_vec())
+		));
+
+		assert_eq!(
+			TaskStatus::<Test>::get(task_id),
+			Some(TaskStatusType::Failed)
+		);
+		assert_eq!(reputation_of(worker_of(2)), 80);
+		assert_eq!(Balances::free_balance(1), 1_000);
+		// The compute hours charged for the false result are given back
+		assert_eq!(pallet_payment::ComputeHours::<Test>::get(1), 100);
+		assert_eq!(ActiveAllocations::<Test>::get(worker_of(4)), 0);
+		System::assert_last_event(
+			crate::Event::DisputeResolved {
+				task_id,
+				worker_at_fault: true,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn dispute_origin_decides_and_disputed_tasks_are_not_pruned() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let task_id = complete_disputable_task();
+		assert_noop!(
+			TaskManagementModule::resolve_dispute(RuntimeOrigin::root(), task_id, true),
+			Error::<Test>::TaskNotDisputed
+		);
+		assert_ok!(TaskManagementModule::dispute_task_result(
+			RuntimeOrigin::signed(1),
+			task_id
+		));
+
+		TaskManagementModule::on_idle(200, Weight::MAX);
+		assert!(Tasks::<Test>::contains_key(task_id));
+
+		System::set_block_number(200);
+		assert_noop!(
+			TaskManagementModule::resolve_dispute(RuntimeOrigin::signed(1), task_id, true),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(TaskManagementModule::resolve_dispute(
+			RuntimeOrigin::root(),
+			task_id,
+			false
+		));
+		assert_eq!(
+			TaskStatus::<Test>::get(task_id),
+			Some(TaskStatusType::Completed)
+		);
+
+		TaskManagementModule::on_idle(300, Weight::MAX);
+		assert!(!Tasks::<Test>::contains_key(task_id));
+	});
+}
+
+#[test]
+fn graph_tasks_wait_for_the_dispute_window_of_their_parents() {
+	new_test_ext().execute_with(|| {
+		DisputeWindow::set(20);
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_active_workers(&[2]);
+
+		let first = submit_graph(vec![graph_node(&[]), graph_node(&[0])]);
+		complete_graph_task(first, b"first");
+		assert_eq!(
+			TaskStatus::<Test>::get(first + 1),
+			Some(TaskStatusType::WaitingForDependencies)
+		);
+
+		run_to_block(21);
+		assert_eq!(
+			TaskStatus::<Test>::get(first + 1),
+			Some(TaskStatusType::Assigned)
+		);
+		System::assert_has_event(
+			crate::Event::TaskDependenciesMet {
+				task_id: first + 1,
+				parent_results: vec![(first, BoundedVec::truncate_from(b"first".to_vec()))],
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn graph_tasks_wait_for_the_dispute_window_of_every_parent() {
+	new_test_ext().execute_with(|| {
+		DisputeWindow::set(20);
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_active_workers(&[2]);
+
+		let first = submit_graph(vec![graph_node(&[]), graph_node(&[]), graph_node(&[0, 1])]);
+		let (second, last) = (first + 1, first + 2);
+		let (worker, _) = TaskAllocations::<Test>::get(second).unwrap();
+		assert_ok!(TaskManagementModule::confirm_task_reception(
+			RuntimeOrigin::signed(worker),
+			second
+		));
+		complete_graph_task(first, b"first");
+
+		run_to_block(11);
+		assert_ok!(TaskManagementModule::submit_task_result(
+			RuntimeOrigin::signed(worker),
+			second,
+			BoundedVec::truncate_from(b"second".to_vec()),
+			None
+		));
+
+		// The result of the first parent can no longer be disputed, that of the second can
+		run_to_block(21);
+		assert_eq!(
+			TaskStatus::<Test>::get(last),
+			Some(TaskStatusType::WaitingForDependencies)
+		);
+		assert_eq!(TaskManagementModule::parent_results(last), None);
+
+		run_to_block(31);
+		assert_eq!(
+			TaskStatus::<Test>::get(last),
+			Some(TaskStatusType::Assigned)
+		);
+	});
+}
+
+#[test]
+fn graph_tasks_fail_when_their_parent_loses_a_dispute() {
+	new_test_ext().execute_with(|| {
+		DisputeWindow::set(20);
+		setup_gatekeeper();
+		System::set_block_number(1);
+		setup_tipping();
+		setup_active_workers(&[2]);
+
+		let first = submit_graph(vec![graph_node(&[]), graph_node(&[0])]);
+		complete_graph_task(first, b"first");
+		assert_ok!(TaskManagementModule::dispute_task_result(
+			RuntimeOrigin::signed(3),
+			first
+		));
+		assert_ok!(TaskManagementModule::resolve_dispute(
+			RuntimeOrigin::root(),
+			first,
+			true
+		));
+
+		assert_eq!(TaskStatus::<Test>::get(first), Some(TaskStatusType::Failed));
+		assert_eq!(
+			TaskStatus::<Test>::get(first + 1),
+			Some(TaskStatusType::Failed)
+		);
+		// Both deposits are refunded
+		assert_eq!(pallet_payment::ComputeHours::<Test>::get(3), 100);
+		System::assert_last_event(crate::Event::TaskFailed { task_id: first + 1 }.into());
+	});
+}
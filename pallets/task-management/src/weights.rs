@@ -6,6 +6,8 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `SGOWMBP3`, CPU: `<UNKNOWN>`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+//!
+//! Functions marked as placeholder estimates were written by hand and are still to be benchmarked.
 
 // Executed Command:
 // ./target/release/cyborg-node
@@ -42,6 +44,27 @@ pub trait WeightInfo {
 	fn set_gatekeeper() -> Weight;
 	fn register_model_hash()-> Weight;
 	fn get_model_hash()->Weight;
+	fn submit_task_result(s: u32, ) -> Weight;
+	fn expire_task() -> Weight;
+	fn cancel_task() -> Weight;
+	fn submit_task_graph(n: u32, ) -> Weight;
+	fn schedule_task_at(s: u32, ) -> Weight;
+	fn cancel_scheduled_task() -> Weight;
+	fn start_scheduled_task() -> Weight;
+	fn prune_task() -> Weight;
+	fn register_model() -> Weight;
+	fn add_model_version() -> Weight;
+	fn deprecate_model_version() -> Weight;
+	fn transfer_model() -> Weight;
+	fn accept_model_transfer() -> Weight;
+	fn remove_model_version() -> Weight;
+	fn remove_model() -> Weight;
+	fn submit_task_payload() -> Weight;
+	fn submit_encrypted_task_result() -> Weight;
+	fn dispute_task_result() -> Weight;
+	fn submit_dispute_verification(s: u32, ) -> Weight;
+	fn resolve_dispute() -> Weight;
+	fn dispatch_pending_task() -> Weight;
 
 }
 
@@ -63,14 +86,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `TaskManagement::TaskOwners` (r:0 w:1)
 	/// Proof: `TaskManagement::TaskOwners` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[0, 100]`.
+	/// Storage: `TaskManagement::TaskRateLimits` (r:1 w:1)
+	/// Proof: `TaskManagement::TaskRateLimits` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
 	fn task_scheduler_no_nzk(_s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `302`
 		//  Estimated: `6386`
 		// Minimum execution time: 20_000_000 picoseconds.
+		// Placeholder estimate: storage accesses adjusted by hand since the last benchmark.
 		Weight::from_parts(22_654_374, 6386)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: `EdgeConnect::AccountWorkers` (r:2 w:0)
 	/// Proof: `EdgeConnect::AccountWorkers` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
@@ -87,14 +113,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `TaskManagement::TaskOwners` (r:0 w:1)
 	/// Proof: `TaskManagement::TaskOwners` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[0, 100]`.
+	/// Storage: `TaskManagement::TaskRateLimits` (r:1 w:1)
+	/// Proof: `TaskManagement::TaskRateLimits` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
 	fn task_scheduler_nzk(_s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `302`
 		//  Estimated: `6386`
 		// Minimum execution time: 20_000_000 picoseconds.
+		// Placeholder estimate: storage accesses adjusted by hand since the last benchmark.
 		Weight::from_parts(22_654_374, 6386)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: `TaskManagement::TaskAllocations` (r:1 w:0)
 	/// Proof: `TaskManagement::TaskAllocations` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
@@ -124,11 +153,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		//  Measured:  `294`
 		//  Estimated: `3686`
 		// Minimum execution time: 10_000_000 picoseconds.
+		// Placeholder estimate: storage accesses adjusted by hand since the last benchmark.
 		Weight::from_parts(11_153_446, 3686)
 			// Standard Error: 702
 			.saturating_add(Weight::from_parts(3_686, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `TaskManagement::TaskStatus` (r:1 w:1)
 	/// Proof: `TaskManagement::TaskStatus` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
@@ -140,9 +170,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		//  Measured:  `359`
 		//  Estimated: `3686`
 		// Minimum execution time: 10_000_000 picoseconds.
+		// Placeholder estimate: storage accesses adjusted by hand since the last benchmark.
 		Weight::from_parts(11_444_111, 3686)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 
 	fn confirm_task_reception() -> Weight {
@@ -216,6 +247,406 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(6_500_000, 2592)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 	}
+	/// Storage: `TaskManagement::Tasks` (r:1 w:1)
+	/// Proof: `TaskManagement::Tasks` (`max_values`: None, `max_size`: Some(182), added: 2657, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskAllocations` (r:1 w:0)
+	/// Proof: `TaskManagement::TaskAllocations` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::ComputeAggregations` (r:1 w:1)
+	/// Proof: `TaskManagement::ComputeAggregations` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskStatus` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskStatus` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 500]`.
+	fn submit_task_result(s: u32, ) -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(25_120_000, 3647)
+			.saturating_add(Weight::from_parts(1_540, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `TaskManagement::TaskDeadlines` (r:1 w:2)
+	/// Proof: `TaskManagement::TaskDeadlines` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::Tasks` (r:1 w:1)
+	/// Proof: `TaskManagement::Tasks` (`max_values`: None, `max_size`: Some(182), added: 2657, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskAllocations` (r:1 w:1)
+	/// Proof: `TaskManagement::TaskAllocations` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::SuspendedWorkers` (r:2 w:0)
+	/// Proof: `EdgeConnect::SuspendedWorkers` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::ExecutableWorkers` (r:2 w:1)
+	/// Proof: `EdgeConnect::ExecutableWorkers` (`max_values`: None, `max_size`: Some(223), added: 2698, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskRequirements` (r:1 w:0)
+	/// Proof: `TaskManagement::TaskRequirements` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::ExpiringTasks` (r:1 w:1)
+	/// Proof: `TaskManagement::ExpiringTasks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TaskManagement::TaskStatus` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskStatus` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	fn expire_task() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(50_310_000, 6575)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `TaskManagement::Tasks` (r:1 w:1)
+	/// Proof: `TaskManagement::Tasks` (`max_values`: None, `max_size`: Some(182), added: 2657, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::GatekeeperAccount` (r:1 w:0)
+	/// Proof: `TaskManagement::GatekeeperAccount` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::ComputeAggregations` (r:1 w:1)
+	/// Proof: `TaskManagement::ComputeAggregations` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Payment::ComputeHours` (r:1 w:1)
+	/// Proof: `Payment::ComputeHours` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskDeadlines` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskDeadlines` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskStatus` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskStatus` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	fn cancel_task() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(25_400_000, 3647)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `TaskManagement::GatekeeperAccount` (r:1 w:0)
+	/// Proof: `TaskManagement::GatekeeperAccount` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskRateLimits` (r:1 w:1)
+	/// Proof: `TaskManagement::TaskRateLimits` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::NextTaskId` (r:1 w:1)
+	/// Proof: `TaskManagement::NextTaskId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Payment::ComputeHours` (r:1 w:1)
+	/// Proof: `Payment::ComputeHours` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskChildren` (r:1 w:1)
+	/// Proof: `TaskManagement::TaskChildren` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskParents` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskParents` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskOwners` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::Tasks` (r:0 w:1)
+	/// Proof: `TaskManagement::Tasks` (`max_values`: None, `max_size`: Some(182), added: 2657, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskStatus` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskStatus` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 16]`.
+	fn submit_task_graph(n: u32, ) -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(18_750_000, 3614)
+			.saturating_add(Weight::from_parts(14_200_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `TaskManagement::GatekeeperAccount` (r:1 w:0)
+	/// Proof: `TaskManagement::GatekeeperAccount` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskRateLimits` (r:1 w:1)
+	/// Proof: `TaskManagement::TaskRateLimits` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Payment::ComputeHours` (r:1 w:1)
+	/// Proof: `Payment::ComputeHours` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::NextScheduleId` (r:1 w:1)
+	/// Proof: `TaskManagement::NextScheduleId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskAgenda` (r:1 w:1)
+	/// Proof: `TaskManagement::TaskAgenda` (`max_values`: None, `max_size`: Some(518), added: 2993, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::ScheduledTasks` (r:0 w:1)
+	/// Proof: `TaskManagement::ScheduledTasks` (`max_values`: None, `max_size`: Some(15648), added: 18123, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 500]`.
+	fn schedule_task_at(s: u32, ) -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(28_114_000, 3983)
+			.saturating_add(Weight::from_parts(1_210, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `TaskManagement::ScheduledTasks` (r:1 w:1)
+	/// Proof: `TaskManagement::ScheduledTasks` (`max_values`: None, `max_size`: Some(15648), added: 18123, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::GatekeeperAccount` (r:1 w:0)
+	/// Proof: `TaskManagement::GatekeeperAccount` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskAgenda` (r:1 w:1)
+	/// Proof: `TaskManagement::TaskAgenda` (`max_values`: None, `max_size`: Some(518), added: 2993, mode: `MaxEncodedLen`)
+	/// Storage: `Payment::ComputeHours` (r:1 w:1)
+	/// Proof: `Payment::ComputeHours` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn cancel_scheduled_task() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(22_300_000, 19113)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `TaskManagement::ScheduledTasks` (r:1 w:1)
+	/// Proof: `TaskManagement::ScheduledTasks` (`max_values`: None, `max_size`: Some(15648), added: 18123, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::NextTaskId` (r:1 w:1)
+	/// Proof: `TaskManagement::NextTaskId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::ExecutableWorkers` (r:2 w:0)
+	/// Proof: `EdgeConnect::ExecutableWorkers` (`max_values`: None, `max_size`: Some(227), added: 2702, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::SuspendedWorkers` (r:1 w:0)
+	/// Proof: `EdgeConnect::SuspendedWorkers` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskRequirements` (r:1 w:0)
+	/// Proof: `TaskManagement::TaskRequirements` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::ExpiringTasks` (r:1 w:1)
+	/// Proof: `TaskManagement::ExpiringTasks` (`max_values`: None, `max_size`: Some(2054), added: 4529, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskAgenda` (r:1 w:1)
+	/// Proof: `TaskManagement::TaskAgenda` (`max_values`: None, `max_size`: Some(518), added: 2993, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskOwners` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskAllocations` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskAllocations` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskDeadlines` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskDeadlines` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::Tasks` (r:0 w:1)
+	/// Proof: `TaskManagement::Tasks` (`max_values`: None, `max_size`: Some(182), added: 2657, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskStatus` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskStatus` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	fn start_scheduled_task() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(54_800_000, 19113)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: `TaskManagement::PruningQueue` (r:1 w:1)
+	/// Proof: `TaskManagement::PruningQueue` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::Tasks` (r:1 w:1)
+	/// Proof: `TaskManagement::Tasks` (`max_values`: None, `max_size`: Some(15691), added: 18166, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskAllocations` (r:1 w:1)
+	/// Proof: `TaskManagement::TaskAllocations` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskStatus` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskStatus` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskOwners` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::ComputeAggregations` (r:0 w:1)
+	/// Proof: `TaskManagement::ComputeAggregations` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskRequirements` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskRequirements` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskVerifications` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskVerifications` (`max_values`: None, `max_size`: Some(1767), added: 4242, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskDeadlines` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskDeadlines` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskParents` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskParents` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskChildren` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskChildren` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskSummaries` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskSummaries` (`max_values`: None, `max_size`: Some(158), added: 2633, mode: `MaxEncodedLen`)
+	fn prune_task() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(50_100_000, 19156)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+	}
+	/// Storage: `TaskManagement::NextModelId` (r:1 w:1)
+	/// Proof: `TaskManagement::NextModelId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::Models` (r:0 w:1)
+	/// Proof: `TaskManagement::Models` (`max_values`: None, `max_size`: Some(509), added: 2984, mode: `MaxEncodedLen`)
+	fn register_model() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(9_600_000, 1493)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TaskManagement::Models` (r:1 w:1)
+	/// Proof: `TaskManagement::Models` (`max_values`: None, `max_size`: Some(509), added: 2984, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::ModelVersions` (r:0 w:1)
+	/// Proof: `TaskManagement::ModelVersions` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	fn add_model_version() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(11_700_000, 3974)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TaskManagement::Models` (r:1 w:0)
+	/// Proof: `TaskManagement::Models` (`max_values`: None, `max_size`: Some(509), added: 2984, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::GatekeeperAccount` (r:1 w:0)
+	/// Proof: `TaskManagement::GatekeeperAccount` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::ModelVersions` (r:1 w:1)
+	/// Proof: `TaskManagement::ModelVersions` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	fn deprecate_model_version() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(13_500_000, 3974)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TaskManagement::Models` (r:1 w:0)
+	/// Proof: `TaskManagement::Models` (`max_values`: None, `max_size`: Some(509), added: 2984, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::ModelTransferOffers` (r:0 w:1)
+	/// Proof: `TaskManagement::ModelTransferOffers` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn transfer_model() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(10_400_000, 3974)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TaskManagement::ModelTransferOffers` (r:1 w:1)
+	/// Proof: `TaskManagement::ModelTransferOffers` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::Models` (r:1 w:1)
+	/// Proof: `TaskManagement::Models` (`max_values`: None, `max_size`: Some(509), added: 2984, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::ModelDeposits` (r:1 w:0)
+	/// Proof: `TaskManagement::ModelDeposits` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn accept_model_transfer() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(30_200_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `TaskManagement::Models` (r:1 w:0)
+	/// Proof: `TaskManagement::Models` (`max_values`: None, `max_size`: Some(509), added: 2984, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::ModelVersions` (r:1 w:1)
+	/// Proof: `TaskManagement::ModelVersions` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::ModelDeposits` (r:1 w:1)
+	/// Proof: `TaskManagement::ModelDeposits` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn remove_model_version() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(22_600_000, 3974)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `TaskManagement::Models` (r:1 w:1)
+	/// Proof: `TaskManagement::Models` (`max_values`: None, `max_size`: Some(509), added: 2984, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::ModelVersions` (r:1 w:0)
+	/// Proof: `TaskManagement::ModelVersions` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::ModelDeposits` (r:1 w:1)
+	/// Proof: `TaskManagement::ModelDeposits` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::ModelTransferOffers` (r:0 w:1)
+	/// Proof: `TaskManagement::ModelTransferOffers` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn remove_model() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(23_100_000, 3974)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `TaskManagement::Tasks` (r:1 w:0)
+	/// Proof: `TaskManagement::Tasks` (`max_values`: None, `max_size`: Some(182), added: 2657, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::ConfidentialTasks` (r:1 w:0)
+	/// Proof: `TaskManagement::ConfidentialTasks` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskAllocations` (r:1 w:0)
+	/// Proof: `TaskManagement::TaskAllocations` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::ExecutableWorkers` (r:1 w:0)
+	/// Proof: `EdgeConnect::ExecutableWorkers` (`max_values`: None, `max_size`: Some(260), added: 2735, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskDeadlines` (r:1 w:2)
+	/// Proof: `TaskManagement::TaskDeadlines` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskPayloads` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskPayloads` (`max_values`: None, `max_size`: Some(1138), added: 3613, mode: `MaxEncodedLen`)
+	fn submit_task_payload() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(22_300_000, 3725)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `TaskManagement::ConfidentialTasks` (r:1 w:0)
+	/// Proof: `TaskManagement::ConfidentialTasks` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::Tasks` (r:1 w:1)
+	/// Proof: `TaskManagement::Tasks` (`max_values`: None, `max_size`: Some(182), added: 2657, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskAllocations` (r:1 w:0)
+	/// Proof: `TaskManagement::TaskAllocations` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::ComputeAggregations` (r:1 w:1)
+	/// Proof: `TaskManagement::ComputeAggregations` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskStatus` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskStatus` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::EncryptedResults` (r:0 w:1)
+	/// Proof: `TaskManagement::EncryptedResults` (`max_values`: None, `max_size`: Some(1138), added: 3613, mode: `MaxEncodedLen`)
+	fn submit_encrypted_task_result() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(28_450_000, 3647)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `TaskManagement::Tasks` (r:1 w:1)
+	/// Proof: `TaskManagement::Tasks` (`max_values`: None, `max_size`: Some(182), added: 2657, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::DisputableResults` (r:1 w:1)
+	/// Proof: `TaskManagement::DisputableResults` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `Payment::ServiceProviderAccount` (r:1 w:0)
+	/// Proof: `Payment::ServiceProviderAccount` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::ConfidentialTasks` (r:1 w:0)
+	/// Proof: `TaskManagement::ConfidentialTasks` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskRequirements` (r:1 w:0)
+	/// Proof: `TaskManagement::TaskRequirements` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::ExecutableWorkers` (r:101 w:1)
+	/// Proof: `EdgeConnect::ExecutableWorkers` (`max_values`: None, `max_size`: Some(260), added: 2735, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::ActiveAllocations` (r:1 w:1)
+	/// Proof: `TaskManagement::ActiveAllocations` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskDisputes` (r:1 w:1)
+	/// Proof: `TaskManagement::TaskDisputes` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskDeadlines` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskDeadlines` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskStatus` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskStatus` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	fn dispute_task_result() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(418_300_000, 277_235)
+			.saturating_add(T::DbWeight::get().reads(111_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: `TaskManagement::Tasks` (r:1 w:1)
+	/// Proof: `TaskManagement::Tasks` (`max_values`: None, `max_size`: Some(182), added: 2657, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskDisputes` (r:1 w:1)
+	/// Proof: `TaskManagement::TaskDisputes` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::ExecutableWorkers` (r:2 w:2)
+	/// Proof: `EdgeConnect::ExecutableWorkers` (`max_values`: None, `max_size`: Some(260), added: 2735, mode: `MaxEncodedLen`)
+	/// Storage: `Payment::ServiceProviderAccount` (r:1 w:0)
+	/// Proof: `Payment::ServiceProviderAccount` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Payment::ComputeHours` (r:1 w:1)
+	/// Proof: `Payment::ComputeHours` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::ActiveAllocations` (r:1 w:1)
+	/// Proof: `TaskManagement::ActiveAllocations` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskStatus` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskStatus` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 500]`.
+	fn submit_dispute_verification(s: u32, ) -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(60_870_000, 6_196)
+			.saturating_add(Weight::from_parts(1_540, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: `TaskManagement::Tasks` (r:1 w:1)
+	/// Proof: `TaskManagement::Tasks` (`max_values`: None, `max_size`: Some(182), added: 2657, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskDisputes` (r:1 w:1)
+	/// Proof: `TaskManagement::TaskDisputes` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::ExecutableWorkers` (r:2 w:2)
+	/// Proof: `EdgeConnect::ExecutableWorkers` (`max_values`: None, `max_size`: Some(260), added: 2735, mode: `MaxEncodedLen`)
+	/// Storage: `Payment::ServiceProviderAccount` (r:1 w:0)
+	/// Proof: `Payment::ServiceProviderAccount` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Payment::ComputeHours` (r:1 w:1)
+	/// Proof: `Payment::ComputeHours` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::ActiveAllocations` (r:1 w:1)
+	/// Proof: `TaskManagement::ActiveAllocations` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskStatus` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskStatus` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	fn resolve_dispute() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(57_140_000, 6_196)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: `EdgeConnect::ExecutableWorkers` (r:2 w:0)
+	/// Proof: `EdgeConnect::ExecutableWorkers` (`max_values`: None, `max_size`: Some(260), added: 2735, mode: `Measured`)
+	/// Storage: `TaskManagement::ActiveAllocations` (r:1 w:1)
+	/// Proof: `TaskManagement::ActiveAllocations` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `Measured`)
+	/// Storage: `TaskManagement::PendingTasks` (r:6 w:1)
+	/// Proof: `TaskManagement::PendingTasks` (`max_values`: None, `max_size`: Some(823), added: 3298, mode: `Measured`)
+	/// Storage: `TaskManagement::TaskRequirements` (r:600 w:0)
+	/// Proof: `TaskManagement::TaskRequirements` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `Measured`)
+	/// Storage: `TaskManagement::ConfidentialTasks` (r:600 w:0)
+	/// Proof: `TaskManagement::ConfidentialTasks` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `Measured`)
+	/// Storage: `TaskManagement::TaskTips` (r:200 w:0)
+	/// Proof: `TaskManagement::TaskTips` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `Measured`)
+	/// Storage: `TaskManagement::Tasks` (r:1 w:1)
+	/// Proof: `TaskManagement::Tasks` (`max_values`: None, `max_size`: Some(182), added: 2657, mode: `Measured`)
+	/// Storage: `TaskManagement::TaskDeadlines` (r:1 w:2)
+	/// Proof: `TaskManagement::TaskDeadlines` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `Measured`)
+	/// Storage: `TaskManagement::TaskAllocations` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskAllocations` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `Measured`)
+	/// Storage: `TaskManagement::TaskStatus` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskStatus` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `Measured`)
+	fn dispatch_pending_task() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(185_000_000, 190_000)
+			.saturating_add(T::DbWeight::get().reads(1411_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
 
 
 }
@@ -237,14 +668,17 @@ impl WeightInfo for () {
 	/// Storage: `TaskManagement::TaskOwners` (r:0 w:1)
 	/// Proof: `TaskManagement::TaskOwners` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[0, 100]`.
+	/// Storage: `TaskManagement::TaskRateLimits` (r:1 w:1)
+	/// Proof: `TaskManagement::TaskRateLimits` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
 	fn task_scheduler_nzk(_s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `302`
 		//  Estimated: `6386`
 		// Minimum execution time: 20_000_000 picoseconds.
+		// Placeholder estimate: storage accesses adjusted by hand since the last benchmark.
 		Weight::from_parts(22_654_374, 6386)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: `EdgeConnect::AccountWorkers` (r:2 w:0)
 	/// Proof: `EdgeConnect::AccountWorkers` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
@@ -261,14 +695,17 @@ impl WeightInfo for () {
 	/// Storage: `TaskManagement::TaskOwners` (r:0 w:1)
 	/// Proof: `TaskManagement::TaskOwners` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[0, 100]`.
+	/// Storage: `TaskManagement::TaskRateLimits` (r:1 w:1)
+	/// Proof: `TaskManagement::TaskRateLimits` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
 	fn task_scheduler_no_nzk(_s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `302`
 		//  Estimated: `6386`
 		// Minimum execution time: 20_000_000 picoseconds.
+		// Placeholder estimate: storage accesses adjusted by hand since the last benchmark.
 		Weight::from_parts(22_654_374, 6386)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: `TaskManagement::TaskAllocations` (r:1 w:0)
 	/// Proof: `TaskManagement::TaskAllocations` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
@@ -298,11 +735,12 @@ impl WeightInfo for () {
 		//  Measured:  `294`
 		//  Estimated: `3686`
 		// Minimum execution time: 10_000_000 picoseconds.
+		// Placeholder estimate: storage accesses adjusted by hand since the last benchmark.
 		Weight::from_parts(11_153_446, 3686)
 			// Standard Error: 702
 			.saturating_add(Weight::from_parts(3_686, 0).saturating_mul(s.into()))
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `TaskManagement::TaskStatus` (r:1 w:1)
 	/// Proof: `TaskManagement::TaskStatus` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
@@ -314,9 +752,10 @@ impl WeightInfo for () {
 		//  Measured:  `359`
 		//  Estimated: `3686`
 		// Minimum execution time: 10_000_000 picoseconds.
+		// Placeholder estimate: storage accesses adjusted by hand since the last benchmark.
 		Weight::from_parts(11_444_111, 3686)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 
 	fn confirm_task_reception() -> Weight {
@@ -388,4 +827,404 @@ impl WeightInfo for () {
 		Weight::from_parts(6_500_000, 2560)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 	}
+	/// Storage: `TaskManagement::Tasks` (r:1 w:1)
+	/// Proof: `TaskManagement::Tasks` (`max_values`: None, `max_size`: Some(182), added: 2657, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskAllocations` (r:1 w:0)
+	/// Proof: `TaskManagement::TaskAllocations` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::ComputeAggregations` (r:1 w:1)
+	/// Proof: `TaskManagement::ComputeAggregations` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskStatus` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskStatus` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 500]`.
+	fn submit_task_result(s: u32, ) -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(25_120_000, 3647)
+			.saturating_add(Weight::from_parts(1_540, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `TaskManagement::TaskDeadlines` (r:1 w:2)
+	/// Proof: `TaskManagement::TaskDeadlines` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::Tasks` (r:1 w:1)
+	/// Proof: `TaskManagement::Tasks` (`max_values`: None, `max_size`: Some(182), added: 2657, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskAllocations` (r:1 w:1)
+	/// Proof: `TaskManagement::TaskAllocations` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::SuspendedWorkers` (r:2 w:0)
+	/// Proof: `EdgeConnect::SuspendedWorkers` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::ExecutableWorkers` (r:2 w:1)
+	/// Proof: `EdgeConnect::ExecutableWorkers` (`max_values`: None, `max_size`: Some(223), added: 2698, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskRequirements` (r:1 w:0)
+	/// Proof: `TaskManagement::TaskRequirements` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::ExpiringTasks` (r:1 w:1)
+	/// Proof: `TaskManagement::ExpiringTasks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TaskManagement::TaskStatus` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskStatus` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	fn expire_task() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(50_310_000, 6575)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `TaskManagement::Tasks` (r:1 w:1)
+	/// Proof: `TaskManagement::Tasks` (`max_values`: None, `max_size`: Some(182), added: 2657, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::GatekeeperAccount` (r:1 w:0)
+	/// Proof: `TaskManagement::GatekeeperAccount` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::ComputeAggregations` (r:1 w:1)
+	/// Proof: `TaskManagement::ComputeAggregations` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Payment::ComputeHours` (r:1 w:1)
+	/// Proof: `Payment::ComputeHours` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskDeadlines` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskDeadlines` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskStatus` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskStatus` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	fn cancel_task() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(25_400_000, 3647)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `TaskManagement::GatekeeperAccount` (r:1 w:0)
+	/// Proof: `TaskManagement::GatekeeperAccount` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskRateLimits` (r:1 w:1)
+	/// Proof: `TaskManagement::TaskRateLimits` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::NextTaskId` (r:1 w:1)
+	/// Proof: `TaskManagement::NextTaskId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Payment::ComputeHours` (r:1 w:1)
+	/// Proof: `Payment::ComputeHours` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskChildren` (r:1 w:1)
+	/// Proof: `TaskManagement::TaskChildren` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskParents` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskParents` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskOwners` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::Tasks` (r:0 w:1)
+	/// Proof: `TaskManagement::Tasks` (`max_values`: None, `max_size`: Some(182), added: 2657, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskStatus` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskStatus` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 16]`.
+	fn submit_task_graph(n: u32, ) -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(18_750_000, 3614)
+			.saturating_add(Weight::from_parts(14_200_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((6_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `TaskManagement::GatekeeperAccount` (r:1 w:0)
+	/// Proof: `TaskManagement::GatekeeperAccount` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskRateLimits` (r:1 w:1)
+	/// Proof: `TaskManagement::TaskRateLimits` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Payment::ComputeHours` (r:1 w:1)
+	/// Proof: `Payment::ComputeHours` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::NextScheduleId` (r:1 w:1)
+	/// Proof: `TaskManagement::NextScheduleId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskAgenda` (r:1 w:1)
+	/// Proof: `TaskManagement::TaskAgenda` (`max_values`: None, `max_size`: Some(518), added: 2993, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::ScheduledTasks` (r:0 w:1)
+	/// Proof: `TaskManagement::ScheduledTasks` (`max_values`: None, `max_size`: Some(15648), added: 18123, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 500]`.
+	fn schedule_task_at(s: u32, ) -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(28_114_000, 3983)
+			.saturating_add(Weight::from_parts(1_210, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `TaskManagement::ScheduledTasks` (r:1 w:1)
+	/// Proof: `TaskManagement::ScheduledTasks` (`max_values`: None, `max_size`: Some(15648), added: 18123, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::GatekeeperAccount` (r:1 w:0)
+	/// Proof: `TaskManagement::GatekeeperAccount` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskAgenda` (r:1 w:1)
+	/// Proof: `TaskManagement::TaskAgenda` (`max_values`: None, `max_size`: Some(518), added: 2993, mode: `MaxEncodedLen`)
+	/// Storage: `Payment::ComputeHours` (r:1 w:1)
+	/// Proof: `Payment::ComputeHours` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn cancel_scheduled_task() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(22_300_000, 19113)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `TaskManagement::ScheduledTasks` (r:1 w:1)
+	/// Proof: `TaskManagement::ScheduledTasks` (`max_values`: None, `max_size`: Some(15648), added: 18123, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::NextTaskId` (r:1 w:1)
+	/// Proof: `TaskManagement::NextTaskId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::ExecutableWorkers` (r:2 w:0)
+	/// Proof: `EdgeConnect::ExecutableWorkers` (`max_values`: None, `max_size`: Some(227), added: 2702, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::SuspendedWorkers` (r:1 w:0)
+	/// Proof: `EdgeConnect::SuspendedWorkers` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskRequirements` (r:1 w:0)
+	/// Proof: `TaskManagement::TaskRequirements` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::ExpiringTasks` (r:1 w:1)
+	/// Proof: `TaskManagement::ExpiringTasks` (`max_values`: None, `max_size`: Some(2054), added: 4529, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskAgenda` (r:1 w:1)
+	/// Proof: `TaskManagement::TaskAgenda` (`max_values`: None, `max_size`: Some(518), added: 2993, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskOwners` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskAllocations` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskAllocations` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskDeadlines` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskDeadlines` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::Tasks` (r:0 w:1)
+	/// Proof: `TaskManagement::Tasks` (`max_values`: None, `max_size`: Some(182), added: 2657, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskStatus` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskStatus` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	fn start_scheduled_task() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(54_800_000, 19113)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: `TaskManagement::PruningQueue` (r:1 w:1)
+	/// Proof: `TaskManagement::PruningQueue` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::Tasks` (r:1 w:1)
+	/// Proof: `TaskManagement::Tasks` (`max_values`: None, `max_size`: Some(15691), added: 18166, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskAllocations` (r:1 w:1)
+	/// Proof: `TaskManagement::TaskAllocations` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskStatus` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskStatus` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskOwners` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskOwners` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::ComputeAggregations` (r:0 w:1)
+	/// Proof: `TaskManagement::ComputeAggregations` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskRequirements` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskRequirements` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskVerifications` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskVerifications` (`max_values`: None, `max_size`: Some(1767), added: 4242, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskDeadlines` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskDeadlines` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskParents` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskParents` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskChildren` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskChildren` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskSummaries` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskSummaries` (`max_values`: None, `max_size`: Some(158), added: 2633, mode: `MaxEncodedLen`)
+	fn prune_task() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(50_100_000, 19156)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
+	}
+	/// Storage: `TaskManagement::NextModelId` (r:1 w:1)
+	/// Proof: `TaskManagement::NextModelId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::Models` (r:0 w:1)
+	/// Proof: `TaskManagement::Models` (`max_values`: None, `max_size`: Some(509), added: 2984, mode: `MaxEncodedLen`)
+	fn register_model() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(9_600_000, 1493)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TaskManagement::Models` (r:1 w:1)
+	/// Proof: `TaskManagement::Models` (`max_values`: None, `max_size`: Some(509), added: 2984, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::ModelVersions` (r:0 w:1)
+	/// Proof: `TaskManagement::ModelVersions` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	fn add_model_version() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(11_700_000, 3974)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TaskManagement::Models` (r:1 w:0)
+	/// Proof: `TaskManagement::Models` (`max_values`: None, `max_size`: Some(509), added: 2984, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::GatekeeperAccount` (r:1 w:0)
+	/// Proof: `TaskManagement::GatekeeperAccount` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::ModelVersions` (r:1 w:1)
+	/// Proof: `TaskManagement::ModelVersions` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	fn deprecate_model_version() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(13_500_000, 3974)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TaskManagement::Models` (r:1 w:0)
+	/// Proof: `TaskManagement::Models` (`max_values`: None, `max_size`: Some(509), added: 2984, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::ModelTransferOffers` (r:0 w:1)
+	/// Proof: `TaskManagement::ModelTransferOffers` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn transfer_model() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(10_400_000, 3974)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TaskManagement::ModelTransferOffers` (r:1 w:1)
+	/// Proof: `TaskManagement::ModelTransferOffers` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::Models` (r:1 w:1)
+	/// Proof: `TaskManagement::Models` (`max_values`: None, `max_size`: Some(509), added: 2984, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::ModelDeposits` (r:1 w:0)
+	/// Proof: `TaskManagement::ModelDeposits` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn accept_model_transfer() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(30_200_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `TaskManagement::Models` (r:1 w:0)
+	/// Proof: `TaskManagement::Models` (`max_values`: None, `max_size`: Some(509), added: 2984, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::ModelVersions` (r:1 w:1)
+	/// Proof: `TaskManagement::ModelVersions` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::ModelDeposits` (r:1 w:1)
+	/// Proof: `TaskManagement::ModelDeposits` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn remove_model_version() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(22_600_000, 3974)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `TaskManagement::Models` (r:1 w:1)
+	/// Proof: `TaskManagement::Models` (`max_values`: None, `max_size`: Some(509), added: 2984, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::ModelVersions` (r:1 w:0)
+	/// Proof: `TaskManagement::ModelVersions` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::ModelDeposits` (r:1 w:1)
+	/// Proof: `TaskManagement::ModelDeposits` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::ModelTransferOffers` (r:0 w:1)
+	/// Proof: `TaskManagement::ModelTransferOffers` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn remove_model() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(23_100_000, 3974)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `TaskManagement::Tasks` (r:1 w:0)
+	/// Proof: `TaskManagement::Tasks` (`max_values`: None, `max_size`: Some(182), added: 2657, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::ConfidentialTasks` (r:1 w:0)
+	/// Proof: `TaskManagement::ConfidentialTasks` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskAllocations` (r:1 w:0)
+	/// Proof: `TaskManagement::TaskAllocations` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::ExecutableWorkers` (r:1 w:0)
+	/// Proof: `EdgeConnect::ExecutableWorkers` (`max_values`: None, `max_size`: Some(260), added: 2735, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskDeadlines` (r:1 w:2)
+	/// Proof: `TaskManagement::TaskDeadlines` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskPayloads` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskPayloads` (`max_values`: None, `max_size`: Some(1138), added: 3613, mode: `MaxEncodedLen`)
+	fn submit_task_payload() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(22_300_000, 3725)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `TaskManagement::ConfidentialTasks` (r:1 w:0)
+	/// Proof: `TaskManagement::ConfidentialTasks` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::Tasks` (r:1 w:1)
+	/// Proof: `TaskManagement::Tasks` (`max_values`: None, `max_size`: Some(182), added: 2657, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskAllocations` (r:1 w:0)
+	/// Proof: `TaskManagement::TaskAllocations` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::ComputeAggregations` (r:1 w:1)
+	/// Proof: `TaskManagement::ComputeAggregations` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskStatus` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskStatus` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::EncryptedResults` (r:0 w:1)
+	/// Proof: `TaskManagement::EncryptedResults` (`max_values`: None, `max_size`: Some(1138), added: 3613, mode: `MaxEncodedLen`)
+	fn submit_encrypted_task_result() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(28_450_000, 3647)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `TaskManagement::Tasks` (r:1 w:1)
+	/// Proof: `TaskManagement::Tasks` (`max_values`: None, `max_size`: Some(182), added: 2657, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::DisputableResults` (r:1 w:1)
+	/// Proof: `TaskManagement::DisputableResults` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `Payment::ServiceProviderAccount` (r:1 w:0)
+	/// Proof: `Payment::ServiceProviderAccount` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::ConfidentialTasks` (r:1 w:0)
+	/// Proof: `TaskManagement::ConfidentialTasks` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskRequirements` (r:1 w:0)
+	/// Proof: `TaskManagement::TaskRequirements` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::ExecutableWorkers` (r:101 w:1)
+	/// Proof: `EdgeConnect::ExecutableWorkers` (`max_values`: None, `max_size`: Some(260), added: 2735, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::ActiveAllocations` (r:1 w:1)
+	/// Proof: `TaskManagement::ActiveAllocations` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskDisputes` (r:1 w:1)
+	/// Proof: `TaskManagement::TaskDisputes` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskDeadlines` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskDeadlines` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskStatus` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskStatus` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	fn dispute_task_result() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(418_300_000, 277_235)
+			.saturating_add(RocksDbWeight::get().reads(111_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: `TaskManagement::Tasks` (r:1 w:1)
+	/// Proof: `TaskManagement::Tasks` (`max_values`: None, `max_size`: Some(182), added: 2657, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskDisputes` (r:1 w:1)
+	/// Proof: `TaskManagement::TaskDisputes` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::ExecutableWorkers` (r:2 w:2)
+	/// Proof: `EdgeConnect::ExecutableWorkers` (`max_values`: None, `max_size`: Some(260), added: 2735, mode: `MaxEncodedLen`)
+	/// Storage: `Payment::ServiceProviderAccount` (r:1 w:0)
+	/// Proof: `Payment::ServiceProviderAccount` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Payment::ComputeHours` (r:1 w:1)
+	/// Proof: `Payment::ComputeHours` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::ActiveAllocations` (r:1 w:1)
+	/// Proof: `TaskManagement::ActiveAllocations` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskStatus` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskStatus` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 500]`.
+	fn submit_dispute_verification(s: u32, ) -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(60_870_000, 6_196)
+			.saturating_add(Weight::from_parts(1_540, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: `TaskManagement::Tasks` (r:1 w:1)
+	/// Proof: `TaskManagement::Tasks` (`max_values`: None, `max_size`: Some(182), added: 2657, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskDisputes` (r:1 w:1)
+	/// Proof: `TaskManagement::TaskDisputes` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::ExecutableWorkers` (r:2 w:2)
+	/// Proof: `EdgeConnect::ExecutableWorkers` (`max_values`: None, `max_size`: Some(260), added: 2735, mode: `MaxEncodedLen`)
+	/// Storage: `Payment::ServiceProviderAccount` (r:1 w:0)
+	/// Proof: `Payment::ServiceProviderAccount` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Payment::ComputeHours` (r:1 w:1)
+	/// Proof: `Payment::ComputeHours` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::ActiveAllocations` (r:1 w:1)
+	/// Proof: `TaskManagement::ActiveAllocations` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskStatus` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskStatus` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	fn resolve_dispute() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(57_140_000, 6_196)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: `EdgeConnect::ExecutableWorkers` (r:2 w:0)
+	/// Proof: `EdgeConnect::ExecutableWorkers` (`max_values`: None, `max_size`: Some(260), added: 2735, mode: `Measured`)
+	/// Storage: `TaskManagement::ActiveAllocations` (r:1 w:1)
+	/// Proof: `TaskManagement::ActiveAllocations` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `Measured`)
+	/// Storage: `TaskManagement::PendingTasks` (r:6 w:1)
+	/// Proof: `TaskManagement::PendingTasks` (`max_values`: None, `max_size`: Some(823), added: 3298, mode: `Measured`)
+	/// Storage: `TaskManagement::TaskRequirements` (r:600 w:0)
+	/// Proof: `TaskManagement::TaskRequirements` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `Measured`)
+	/// Storage: `TaskManagement::ConfidentialTasks` (r:600 w:0)
+	/// Proof: `TaskManagement::ConfidentialTasks` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `Measured`)
+	/// Storage: `TaskManagement::TaskTips` (r:200 w:0)
+	/// Proof: `TaskManagement::TaskTips` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `Measured`)
+	/// Storage: `TaskManagement::Tasks` (r:1 w:1)
+	/// Proof: `TaskManagement::Tasks` (`max_values`: None, `max_size`: Some(182), added: 2657, mode: `Measured`)
+	/// Storage: `TaskManagement::TaskDeadlines` (r:1 w:2)
+	/// Proof: `TaskManagement::TaskDeadlines` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `Measured`)
+	/// Storage: `TaskManagement::TaskAllocations` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskAllocations` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `Measured`)
+	/// Storage: `TaskManagement::TaskStatus` (r:0 w:1)
+	/// Proof: `TaskManagement::TaskStatus` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `Measured`)
+	fn dispatch_pending_task() -> Weight {
+		// Placeholder estimate written by hand, not benchmarked yet.
+		Weight::from_parts(185_000_000, 190_000)
+			.saturating_add(RocksDbWeight::get().reads(1411_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
 }
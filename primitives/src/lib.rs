@@ -4,6 +4,8 @@ pub mod worker;
 
 pub mod task;
 
+pub mod model;
+
 pub mod oracle;
 
 pub mod payment;
@@ -0,0 +1,47 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{pallet_prelude::ConstU32, sp_runtime::RuntimeDebug, BoundedVec};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+pub type ModelId = u64;
+
+/// Number of a version of a model, counting from 0 in the order the versions were added.
+pub type ModelVersionId = u32;
+
+pub type ModelName = BoundedVec<u8, ConstU32<128>>;
+
+pub type ModelFramework = BoundedVec<u8, ConstU32<64>>;
+
+pub type LicenseUri = BoundedVec<u8, ConstU32<256>>;
+
+/// A model of the model registry.
+#[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ModelInfo<AccountId, BlockNumber> {
+	pub owner: AccountId,              // Who can add and deprecate versions.
+	pub name: ModelName,               // Human readable name.
+	pub framework: ModelFramework,     // Framework the model runs on, e.g. "onnx".
+	pub license_uri: LicenseUri,       // Where the license of the model can be found.
+	pub created_block: BlockNumber,    // Block when registered.
+	pub version_count: ModelVersionId, // Number of versions added so far.
+}
+
+/// A version of a model of the model registry.
+#[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ModelVersion<Hash, BlockNumber> {
+	pub artifact_hash: Hash, // Hash workers check the downloaded artifact against.
+	pub size: u64,           // Size of the artifact in bytes.
+	pub created_block: BlockNumber, // Block when added.
+	pub deprecated: bool,    // Deprecated versions cannot be used by new tasks.
+}
+
+/// The model version a task runs.
+#[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct TaskModel<Hash> {
+	pub model_id: ModelId,
+	pub version: ModelVersionId,
+	pub artifact_hash: Hash, // Copied from the version when the task was scheduled.
+}
@@ -1,10 +1,19 @@
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{pallet_prelude::ConstU32, sp_runtime::RuntimeDebug, BoundedVec};
 use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+use crate::model::{ModelId, ModelVersionId};
+use crate::worker::{
+	CpuCores, EncryptionKey, Location, RamBytes, StorageBytes, WorkerId, WorkerSpecs,
+};
 
 pub type TaskId = u64;
+pub type ScheduleId = u64;
 
 #[derive(PartialEq, Eq, Clone, Decode, Encode, TypeInfo, Debug, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum TaskStatusType {
 	/// Task has been assigned to a worker, but miner hasn't confirmed reception yet.
 	Assigned,
@@ -17,17 +26,119 @@ pub enum TaskStatusType {
 
 	/// Miner reset hardware after stopping task.
 	Vacated,
+
+	/// Miner submitted the result of the task and its compute usage has been recorded.
+	Completed,
+
+	/// Executor submitted a result, waiting for the verifier to re-run the task.
+	PendingValidation,
+
+	/// Executor and verifier disagree, waiting for the resolver to re-run the task.
+	PendingResolution,
+
+	/// Task could not be completed and its deposit was refunded.
+	Failed,
+
+	/// No worker could take the task yet, it waits in the pending queue with its deposit locked.
+	Pending,
+
+	/// Task belongs to a task graph and waits for its parent tasks to complete.
+	WaitingForDependencies,
+
+	/// The owner disputed the result of the completed task, waiting for the dispute to be decided.
+	Disputed,
+}
+
+/// Reference of a container image, e.g. `registry.example.com/team/job:1.2`.
+pub type ImageReference = BoundedVec<u8, ConstU32<256>>;
+
+/// A container image, pinned to the sha256 digest of its manifest so that workers run exactly
+/// the image the task owner submitted.
+#[derive(PartialEq, Eq, Clone, Decode, Encode, TypeInfo, Debug, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ContainerImage {
+	pub reference: ImageReference,
+	pub digest: [u8; 32],
+}
+
+/// Ciphertext of an encrypted payload, large enough for a 500 bytes plaintext and its tag.
+pub type Ciphertext = BoundedVec<u8, ConstU32<1024>>;
+
+/// Task metadata or result encrypted to the key of its recipient, the encrypted counterpart of
+/// the plaintext `metadata` and `result` of a task.
+///
+/// Payloads are sealed with the NaCl `crypto_box` construction: the sender agrees on a shared
+/// secret between a one-time X25519 key, published as `ephemeral_key`, and `recipient`, then
+/// encrypts the payload with XSalsa20-Poly1305 under that secret and `nonce`. The ciphertext
+/// carries the 16 bytes Poly1305 tag. Only the holder of the secret key of `recipient` can
+/// decrypt it.
+#[derive(PartialEq, Eq, Clone, Decode, Encode, TypeInfo, Debug, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct EncryptedPayload {
+	pub recipient: EncryptionKey,     // Key the payload is encrypted to.
+	pub ephemeral_key: EncryptionKey, // One-time key of the sender.
+	pub nonce: [u8; 24],
+	pub ciphertext: Ciphertext,
 }
 
 /// Kinds of overall tasks at a logical level (business logic: inference vs zk proof).
 #[derive(PartialEq, Eq, Clone, Decode, Encode, TypeInfo, Debug, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum TaskKind {
-	NeuroZK,       // A Zero-Knowledge Proof Generation task.
-	OpenInference, // An AI Inference Task (normal).
+	NeuroZK,                   // A Zero-Knowledge Proof Generation task.
+	OpenInference,             // An AI Inference Task (normal).
+	Container(ContainerImage), // A job run from a container image on a Docker worker.
+}
+
+impl TaskKind {
+	/// The class of the task, i.e. its kind without the parameters of the kind.
+	pub fn class(&self) -> TaskClass {
+		match self {
+			TaskKind::NeuroZK => TaskClass::NeuroZK,
+			TaskKind::OpenInference => TaskClass::OpenInference,
+			TaskKind::Container(_) => TaskClass::Container,
+		}
+	}
+}
+
+/// Classes of tasks, one per variant of [`TaskKind`]. Tasks of the same class wait in the same
+/// pending queues.
+#[derive(PartialEq, Eq, Clone, Copy, Decode, Encode, TypeInfo, Debug, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum TaskClass {
+	NeuroZK,
+	OpenInference,
+	Container,
+}
+
+impl TaskClass {
+	/// Every class of task.
+	pub const ALL: [TaskClass; 3] = [
+		TaskClass::NeuroZK,
+		TaskClass::OpenInference,
+		TaskClass::Container,
+	];
+}
+
+/// Priority of a task waiting in the pending queue. Higher priorities are dispatched first.
+#[derive(Default, PartialEq, Eq, Clone, Copy, Decode, Encode, TypeInfo, Debug, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum TaskPriority {
+	Low,
+	#[default]
+	Normal,
+	High,
+}
+
+impl TaskPriority {
+	/// Every priority, from the most to the least urgent.
+	pub const DESCENDING: [TaskPriority; 3] =
+		[TaskPriority::High, TaskPriority::Normal, TaskPriority::Low];
 }
 
 ///Detailed information about a specific task.
 #[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct TaskInfo<AccountId, BlockNumber> {
 	pub task_owner: AccountId,                   // Who scheduled the task.
 	pub create_block: BlockNumber,               // Block when created.
@@ -42,6 +153,68 @@ pub struct TaskInfo<AccountId, BlockNumber> {
 	pub task_status: TaskStatusType,             // Current lifecycle status.
 }
 
+/// What is kept of a finished task once its history has been pruned.
+#[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct TaskSummary<AccountId, Hash> {
+	pub task_hash: Hash,                       // Hash of the pruned `TaskInfo`.
+	pub task_owner: AccountId,                 // Who scheduled the task.
+	pub worker: Option<(AccountId, WorkerId)>, // Last worker the task was allocated to.
+	pub outcome: TaskStatusType,               // Final status of the task.
+	pub consumed_compute_hours: u32,           // Compute hours charged for the task.
+}
+
+/// Restricts a task to workers located within `radius` of `center`.
+///
+/// Both values use the fixed-point coordinate units of [`Location`]; the distance is measured on
+/// the coordinate plane, which is precise enough to keep work in a region.
+#[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct LocationRequirement {
+	pub center: Location,
+	pub radius: u32,
+}
+
+/// Minimum hardware a worker must provide to be handed a task.
+#[derive(Default, PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ResourceRequirements {
+	pub ram: RamBytes,                         // Minimum RAM in bytes.
+	pub storage: StorageBytes,                 // Minimum storage in bytes.
+	pub cpu: CpuCores,                         // Minimum number of CPU cores.
+	pub location: Option<LocationRequirement>, // Optional: region the worker must be in.
+}
+
+impl ResourceRequirements {
+	/// Whether a worker with `specs` registered at `location` can run the task.
+	pub fn is_satisfied_by(&self, specs: &WorkerSpecs, location: &Location) -> bool {
+		let fits_specs =
+			specs.ram >= self.ram && specs.storage >= self.storage && specs.cpu >= self.cpu;
+
+		let in_range = self.location.as_ref().is_none_or(|required| {
+			// Widened so that squaring the largest coordinate differences cannot overflow.
+			let d_lat = (location.latitude as i64 - required.center.latitude as i64).unsigned_abs();
+			let d_lon =
+				(location.longitude as i64 - required.center.longitude as i64).unsigned_abs();
+			(d_lat as u128).pow(2) + (d_lon as u128).pow(2) <= (required.radius as u128).pow(2)
+		});
+
+		fits_specs && in_range
+	}
+}
+
+/// Optional parameters of a task submitted with `task_scheduler`.
+///
+/// The default is a public task of normal priority, without tip, model or resource
+/// requirements.
+#[derive(Default, PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo)]
+pub struct TaskOptions<Balance> {
+	pub requirements: Option<ResourceRequirements>, // Minimum hardware of the worker.
+	pub priority: TaskPriority,                     // Position in the pending queue.
+	pub tip: Option<Balance>,                       // Paid to the worker whose result is accepted.
+	pub model: Option<(ModelId, ModelVersionId)>,   // Model version the task runs.
+	pub confidential: Option<EncryptionKey>,        // Key of the owner the result is encrypted to.
+}
+
 pub type ZkInput = BoundedVec<u8, ConstU32<5000>>;
 pub type ZkSettings = BoundedVec<u8, ConstU32<5000>>;
 pub type ZkVerifyingKey = BoundedVec<u8, ConstU32<500000>>;
@@ -54,7 +227,39 @@ pub struct NeuroZkTaskSubmissionDetails {
 	pub zk_verifying_key: ZkVerifyingKey,
 }
 
+/// Maximum number of parents of a node of a task graph.
+pub type MaxTaskParents = ConstU32<16>;
+
+/// A task of a task graph, scheduled once all of its parents completed.
+#[derive(Clone, Decode, Encode, TypeInfo, MaxEncodedLen, PartialEq, Debug)]
+pub struct TaskGraphNode {
+	pub task_kind: TaskKind,
+	pub task_location: BoundedVec<u8, ConstU32<500>>,
+	pub nzk_info: Option<NeuroZkTaskSubmissionDetails>,
+	pub requirements: Option<ResourceRequirements>,
+	pub compute_hours_deposit: u32,
+	/// Positions in the graph of the nodes this task depends on, each before this node.
+	pub parents: BoundedVec<u32, MaxTaskParents>,
+}
+
+/// A task to start at a future block, possibly repeated every `interval` blocks.
+///
+/// The compute hours of every remaining run are paid when the task is scheduled.
+#[derive(Clone, Decode, Encode, TypeInfo, MaxEncodedLen, PartialEq, Debug)]
+pub struct ScheduledTask<AccountId, BlockNumber> {
+	pub task_owner: AccountId,
+	pub task_kind: TaskKind,
+	pub task_location: BoundedVec<u8, ConstU32<500>>,
+	pub nzk_info: Option<NeuroZkTaskSubmissionDetails>,
+	pub requirements: Option<ResourceRequirements>,
+	pub compute_hours_deposit: u32,    // Deposit of each run.
+	pub next_run: BlockNumber,         // Block at which the next run starts.
+	pub interval: Option<BlockNumber>, // Blocks between two runs, if the task repeats.
+	pub remaining_runs: u32,           // Runs left, including the next one.
+}
+
 #[derive(Clone, Decode, Encode, TypeInfo, MaxEncodedLen, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct NzkData<BlockNumber> {
 	pub zk_input: ZkInput,
 	pub zk_settings: ZkSettings,
@@ -63,20 +268,48 @@ pub struct NzkData<BlockNumber> {
 	pub last_proof_accepted: Option<(bool, BlockNumber)>,
 }
 
-// #[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
-// pub struct VerificationHashes<AccountId> {
-// 	pub account: AccountId,
-// 	pub completed_hash: Option<H256>,
-// }
+/// Result submitted by one of the workers taking part in the verification of a task.
+#[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct VerificationHashes<AccountId, Hash> {
+	pub worker: (AccountId, WorkerId),                  // Worker assigned to this role.
+	pub completed_hash: Option<Hash>,                   // Hash of the result, once submitted.
+	pub result: Option<BoundedVec<u8, ConstU32<500>>>, // Submitted result.
+}
+
+impl<AccountId, Hash> VerificationHashes<AccountId, Hash> {
+	pub fn assigned(worker: (AccountId, WorkerId)) -> Self {
+		Self {
+			worker,
+			completed_hash: None,
+			result: None,
+		}
+	}
+}
 
-// #[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
-// pub struct Verifications<AccountId> {
-// 	pub executor: VerificationHashes<AccountId>,
-// 	pub verifier: Option<VerificationHashes<AccountId>>,
-// 	pub resolver: Option<VerificationHashes<AccountId>>,
-// }
+/// Results of the executor, verifier and resolver of an inference task.
+#[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct Verifications<AccountId, Hash> {
+	pub executor: VerificationHashes<AccountId, Hash>,
+	pub verifier: Option<VerificationHashes<AccountId, Hash>>,
+	pub resolver: Option<VerificationHashes<AccountId, Hash>>,
+}
+
+/// A dispute the owner of a task opened against its result.
+#[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct TaskDispute<AccountId, Balance> {
+	/// Owner of the task, who put up the bond.
+	pub disputer: AccountId,
+	/// Bond forfeited by the disputer if the result is upheld.
+	pub bond: Balance,
+	/// Worker whose result is disputed.
+	pub worker: (AccountId, WorkerId),
+	/// Worker re-running the task to decide the dispute, if one could be found.
+	pub verifier: Option<(AccountId, WorkerId)>,
+}
 
 pub trait NzkTaskInfoHandler<AccountId, TaskId, BlockNumber> {
 	fn get_nzk_task(task_id: TaskId) -> Option<TaskInfo<AccountId, BlockNumber>>;
 	fn update_nzk_task(task_id: TaskId, task: TaskInfo<AccountId, BlockNumber>);
+	/// The worker the task was allocated to, if it is still known.
+	fn get_nzk_task_worker(task_id: TaskId) -> Option<(AccountId, WorkerId)>;
 }
@@ -1,6 +1,10 @@
 use codec::{Decode, Encode, MaxEncodedLen};
-use frame_support::{pallet_prelude::ConstU32, sp_runtime::RuntimeDebug, BoundedVec};
+use frame_support::{
+	pallet_prelude::ConstU32, sp_runtime::RuntimeDebug, weights::Weight, BoundedVec,
+};
 use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 
 pub type WorkerId = u64;
 
@@ -16,6 +20,11 @@ pub type StorageBytes = u64;
 
 pub type CpuCores = u16;
 
+/// X25519 public key confidential task payloads are encrypted to, see
+/// [`EncryptedPayload`](crate::task::EncryptedPayload) for the scheme. Account keys such as
+/// sr25519 keys cannot be used.
+pub type EncryptionKey = [u8; 32];
+
 /// An enum that is used to differentiate between the different kinds of workers that are
 /// registered on the cyborg parachain. There is no differentiation between the ZK Worker and the
 /// Executable Worker, as the executable worker will be able to execute ZK Tasks
@@ -39,6 +48,7 @@ pub struct WorkerAPI {
 }
 
 #[derive(Default, PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct Location {
 	pub latitude: Latitude,
 	pub longitude: Longitude,
@@ -57,6 +67,8 @@ pub struct Worker<AccountId, BlockNumber, TimeStamp> {
 	pub owner: AccountId,
 	pub location: Location,
 	pub specs: WorkerSpecs,
+	pub max_concurrent_tasks: u32,
+	pub encryption_key: Option<EncryptionKey>,
 	pub reputation: WorkerReputation<BlockNumber>,
 	pub start_block: BlockNumber,
 	pub status: WorkerStatusType,
@@ -77,6 +89,25 @@ pub trait WorkerInfoHandler<AccountId, WorkerId, BlockNumber, TimeStamp> {
 	);
 }
 
+/// Notified when the reported status of a worker makes it available to take new tasks.
+pub trait WorkerStatusHandler<AccountId, WorkerId> {
+	/// Returns the weight used.
+	fn on_worker_active(worker_key: &(AccountId, WorkerId), worker_type: &WorkerType) -> Weight;
+
+	/// Upper bound of the weight of `on_worker_active`.
+	fn on_worker_active_weight() -> Weight;
+}
+
+impl<AccountId, WorkerId> WorkerStatusHandler<AccountId, WorkerId> for () {
+	fn on_worker_active(_worker_key: &(AccountId, WorkerId), _worker_type: &WorkerType) -> Weight {
+		Weight::zero()
+	}
+
+	fn on_worker_active_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
 #[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen, Copy)]
 pub struct WorkerReputation<BlockNumber> {
 	pub score: i32,
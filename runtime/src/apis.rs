@@ -40,13 +40,20 @@ use sp_runtime::{
 use sp_std::prelude::Vec;
 use sp_version::RuntimeVersion;
 
+use cyborg_primitives::{
+	model::{ModelId, ModelInfo, ModelVersion, ModelVersionId, TaskModel},
+	task::{
+		ResourceRequirements, TaskClass, TaskId, TaskInfo, TaskKind, TaskPriority, TaskStatusType,
+	},
+	worker::WorkerId,
+};
 use pallet_task_management::Event as TaskManagementPalletEvent;
 
 // Local module imports
 use super::{
-	AccountId, Balance, Block, ConsensusHook, Executive, InherentDataExt, Nonce, ParachainSystem,
-	Runtime, RuntimeCall, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment,
-	SLOT_DURATION, VERSION,
+	AccountId, Balance, Block, BlockNumber, ConsensusHook, Executive, Hash, InherentDataExt, Nonce,
+	ParachainSystem, Runtime, RuntimeCall, RuntimeGenesisConfig, SessionKeys, System,
+	TransactionPayment, SLOT_DURATION, VERSION,
 };
 
 impl_runtime_apis! {
@@ -295,6 +302,66 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl crate::apis::TaskManagementApi<Block> for Runtime {
+		fn task_info(task_id: TaskId) -> Option<TaskInfo<AccountId, BlockNumber>> {
+			pallet_task_management::Tasks::<Runtime>::get(task_id)
+		}
+
+		fn tasks_by_owner(
+			owner: AccountId,
+			start_after: Option<TaskId>,
+			limit: u32,
+		) -> Vec<(TaskId, TaskStatusType)> {
+			pallet_task_management::Pallet::<Runtime>::tasks_by_owner(&owner, start_after, limit)
+		}
+
+		fn tasks_by_worker(
+			worker: (AccountId, WorkerId),
+			start_after: Option<TaskId>,
+			limit: u32,
+		) -> Vec<(TaskId, TaskStatusType)> {
+			pallet_task_management::Pallet::<Runtime>::tasks_by_worker(&worker, start_after, limit)
+		}
+
+		fn pending_tasks(task_class: TaskClass) -> Vec<(TaskId, TaskPriority)> {
+			pallet_task_management::Pallet::<Runtime>::pending_tasks(task_class)
+		}
+
+		fn task_cost_estimate(task_kind: TaskKind, compute_hours: u32) -> Balance {
+			pallet_task_management::Pallet::<Runtime>::task_cost_estimate(&task_kind, compute_hours)
+		}
+
+		fn eligible_workers(
+			task_class: TaskClass,
+			requirements: Option<ResourceRequirements>,
+		) -> Vec<(AccountId, WorkerId)> {
+			let worker_type =
+				pallet_task_management::Pallet::<Runtime>::worker_type_for(task_class);
+			pallet_task_management::Pallet::<Runtime>::eligible_workers(
+				&worker_type,
+				requirements.as_ref(),
+			)
+		}
+
+		fn task_submission_allowance(account: AccountId) -> (u32, BlockNumber) {
+			pallet_task_management::Pallet::<Runtime>::task_submission_allowance(&account)
+		}
+
+		fn model(model_id: ModelId) -> Option<ModelInfo<AccountId, BlockNumber>> {
+			pallet_task_management::Models::<Runtime>::get(model_id)
+		}
+
+		fn model_versions(
+			model_id: ModelId,
+		) -> Vec<(ModelVersionId, ModelVersion<Hash, BlockNumber>)> {
+			pallet_task_management::Pallet::<Runtime>::model_versions(model_id)
+		}
+
+		fn task_model(task_id: TaskId) -> Option<TaskModel<Hash>> {
+			pallet_task_management::TaskModels::<Runtime>::get(task_id)
+		}
+	}
+
 }
 
 decl_runtime_apis! {
@@ -302,4 +369,54 @@ decl_runtime_apis! {
 	pub trait TaskManagementEventsApi {
 			fn get_recent_events() -> Vec<TaskManagementPalletEvent<Runtime>>;
 	}
+
+	/// Queries over the tasks of the task-management pallet.
+	#[api_version(1)]
+	pub trait TaskManagementApi {
+		/// The task `task_id`, if it exists and has not been pruned yet.
+		fn task_info(task_id: TaskId) -> Option<TaskInfo<AccountId, BlockNumber>>;
+
+		/// Up to `limit` tasks of `owner` with their status, at most `MAX_TASKS_PER_QUERY` per
+		/// page. Pass the last task of a page as `start_after` to get the next page.
+		fn tasks_by_owner(
+			owner: AccountId,
+			start_after: Option<TaskId>,
+			limit: u32,
+		) -> Vec<(TaskId, TaskStatusType)>;
+
+		/// Up to `limit` tasks `worker` is currently involved in with their status, paginated
+		/// like `tasks_by_owner`.
+		fn tasks_by_worker(
+			worker: (AccountId, WorkerId),
+			start_after: Option<TaskId>,
+			limit: u32,
+		) -> Vec<(TaskId, TaskStatusType)>;
+
+		/// Tasks of `task_class` waiting for a worker with their priority, in dispatch order.
+		fn pending_tasks(task_class: TaskClass) -> Vec<(TaskId, TaskPriority)>;
+
+		/// What running a task of `task_kind` for `compute_hours` compute hours costs.
+		fn task_cost_estimate(task_kind: TaskKind, compute_hours: u32) -> Balance;
+
+		/// Workers that can currently be handed a task of `task_class` with `requirements`.
+		fn eligible_workers(
+			task_class: TaskClass,
+			requirements: Option<ResourceRequirements>,
+		) -> Vec<(AccountId, WorkerId)>;
+
+		/// How many more tasks `account` can schedule in its current rate limit window, and the
+		/// block from which its next window starts.
+		fn task_submission_allowance(account: AccountId) -> (u32, BlockNumber);
+
+		/// The model `model_id` of the model registry.
+		fn model(model_id: ModelId) -> Option<ModelInfo<AccountId, BlockNumber>>;
+
+		/// All versions of the model `model_id`, oldest first.
+		fn model_versions(
+			model_id: ModelId,
+		) -> Vec<(ModelVersionId, ModelVersion<Hash, BlockNumber>)>;
+
+		/// The model version the task `task_id` runs, if it was scheduled with one.
+		fn task_model(task_id: TaskId) -> Option<TaskModel<Hash>>;
+	}
 }
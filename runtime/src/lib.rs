@@ -18,7 +18,7 @@ use oracle_router::OracleRouter;
 use smallvec::smallvec;
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
-	traits::{BlakeTwo256, IdentifyAccount, Verify},
+	traits::{BlakeTwo256, Hash as HashT, IdentifyAccount, Verify},
 	MultiSignature,
 };
 
@@ -29,7 +29,7 @@ use sp_version::RuntimeVersion;
 
 use frame_support::{
 	parameter_types,
-	traits::{ConstU32, ConstU8},
+	traits::{ConstBool, ConstI32, ConstU128, ConstU32, ConstU8},
 	weights::{
 		constants::WEIGHT_REF_TIME_PER_SECOND, Weight, WeightToFeeCoefficient, WeightToFeeCoefficients,
 		WeightToFeePolynomial,
@@ -111,6 +111,12 @@ pub type SignedExtra = (
 pub type UncheckedExtrinsic =
 	generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
 
+/// Migrations to apply on runtime upgrade.
+pub type Migrations = (
+	pallet_edge_connect::migrations::v1::MigrateToV1<Runtime>,
+	pallet_task_management::migrations::v1::MigrateToV1<Runtime>,
+);
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -118,6 +124,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
 /// Handles converting a weight scalar to a fee value, based on the scale and granularity of the
@@ -349,14 +356,104 @@ impl frame_support::traits::SortedMembers<AccountId> for OracleMembershipWrapper
 	}
 }
 
+parameter_types! {
+	pub const WorkerPenaltySlash: Perbill = Perbill::from_percent(5);
+	pub const WorkerBanSlash: Perbill = Perbill::from_percent(50);
+}
+
 impl pallet_edge_connect::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = weights::pallet_edge_connect::SubstrateWeight<Runtime>;
+	type BondCurrency = Balances;
+	type WorkerBond = ConstU128<{ 100 * UNIT }>;
+	type UnbondingPeriod = ConstU32<{ 7 * DAYS }>;
+	type PenaltySlash = WorkerPenaltySlash;
+	type BanSlash = WorkerBanSlash;
+	type Slash = ();
+	type ReattestSpecUpgrades = ConstBool<true>;
+	type BanAppealOrigin = EnsureRoot<AccountId>;
+	type SuccessReward = ConstI32<1>;
+	type MaxReputationScore = ConstI32<100>;
+	type ViolationDecayPeriod = ConstU32<DAYS>;
+	type ReputationBaseline = ConstI32<60>;
+	type ReputationRecovery = ConstI32<5>;
+	type MaxWorkersPerAccount = ConstU32<100>;
+	type WorkerStatusHandler = TaskManagement;
+}
+
+/// Randomness used by task-management to pick a worker, derived from the VRF output of the
+/// relay chain block this block is built on, read from the relay chain state proof.
+///
+/// The VRF output is only known once the relay chain block is authored, so users cannot work out
+/// which worker a task will get when they sign it. Picks made before the validation data of the
+/// block is set, in `on_initialize`, fall back to the parent block hash, which the collator can
+/// predict. Rerolling the pick by cancelling an assigned task costs `CancellationCharge`.
+pub struct RelayBlockRandomness;
+
+impl RelayBlockRandomness {
+	/// The VRF output of the relay parent, or the randomness of the previous epoch when the relay
+	/// chain state proof does not have it.
+	fn relay_randomness() -> Option<[u8; 32]> {
+		use cumulus_primitives_core::relay_chain::well_known_keys;
+
+		let validation_data = ParachainSystem::validation_data()?;
+		let proof = cumulus_pallet_parachain_system::RelayStateProof::<Runtime>::get()?;
+		let relay_state = cumulus_pallet_parachain_system::RelayChainStateProof::new(
+			ParachainInfo::parachain_id(),
+			validation_data.relay_parent_storage_root,
+			proof,
+		)
+		.ok()?;
+		relay_state
+			.read_optional_entry::<Option<[u8; 32]>>(well_known_keys::CURRENT_BLOCK_RANDOMNESS)
+			.ok()
+			.flatten()
+			.flatten()
+			.or_else(|| {
+				relay_state
+					.read_optional_entry::<[u8; 32]>(well_known_keys::ONE_EPOCH_AGO_RANDOMNESS)
+					.ok()
+					.flatten()
+			})
+	}
+}
+
+impl frame_support::traits::Randomness<Hash, BlockNumber> for RelayBlockRandomness {
+	fn random(subject: &[u8]) -> (Hash, BlockNumber) {
+		let block_number = System::block_number();
+		let seed = match Self::relay_randomness() {
+			Some(randomness) => BlakeTwo256::hash_of(&(randomness, subject)),
+			None => BlakeTwo256::hash_of(&(System::parent_hash(), subject)),
+		};
+		(seed, block_number.saturating_sub(1))
+	}
 }
 
 impl pallet_task_management::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = weights::pallet_task_management::SubstrateWeight<Runtime>;
+	type Randomness = RelayBlockRandomness;
+	type BlocksPerHour = ConstU32<HOURS>;
+	type FalseCompletionPenalty = ConstI32<20>;
+	type ReceptionTimeout = ConstU32<{ 10 * MINUTES }>;
+	type CancellationCharge = ConstU32<1>;
+	// Cap of the execution deadline, which otherwise follows the compute hours deposit
+	type ExecutionTimeout = ConstU32<DAYS>;
+	type LateResponsePenalty = ConstI32<10>;
+	type MaxExpiringTasksPerBlock = ConstU32<256>;
+	type RateLimitWindow = ConstU32<MINUTES>;
+	type MaxTasksPerWindow = ConstU32<5>;
+	type MaxPendingTasks = ConstU32<100>;
+	type MaxTasksPerDispatch = ConstU32<4>;
+	type MaxGraphNodes = ConstU32<16>;
+	type MaxScheduledTasksPerBlock = ConstU32<64>;
+	type TaskRetentionPeriod = ConstU32<{ 7 * DAYS }>;
+	type DisputeWindow = ConstU32<DAYS>;
+	type DisputeBond = ConstU128<{ 10 * UNIT }>;
+	type DisputeOrigin = EnsureRoot<AccountId>;
+	type ModelDeposit = ConstU128<{ 10 * UNIT }>;
+	type ModelVersionDeposit = ConstU128<UNIT>;
+	type MaxWorkerCandidates = ConstU32<16>;
 }
 
 parameter_types! {
@@ -385,6 +482,7 @@ impl pallet_status_aggregator::Config for Runtime {
 	type ThresholdUptimeStatus = ConstU8<75>;
 	type MaxAggregateParamLength = ConstU32<300>;
 	type WorkerInfoHandler = EdgeConnect;
+	type WorkerStatusHandler = TaskManagement;
 }
 
 impl pallet_neuro_zk::Config for Runtime {
@@ -414,7 +512,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("cyborg-runtime"),
 	impl_name: create_runtime_str!("cyborg-runtime"),
 	authoring_version: 1,
-	spec_version: 1,
+	spec_version: 2,
 	impl_version: 0,
 	apis: apis::RUNTIME_API_VERSIONS,
 	transaction_version: 1,
@@ -6,6 +6,8 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `desktop-tb`, CPU: `AMD Ryzen 9 5900X 12-Core Processor`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
+//!
+//! Functions marked as placeholder estimates were written by hand and are still to be benchmarked.
 
 // Executed Command:
 // ./target/release/cyborg-node
@@ -70,14 +72,19 @@ impl<T: frame_system::Config> pallet_neuro_zk::WeightInfo for SubstrateWeight<T>
 	/// Proof: `NeuroZk::SubmittedPerProof` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `NeuroZk::VerificationResultsPerProof` (r:1 w:1)
 	/// Proof: `NeuroZk::VerificationResultsPerProof` (`max_values`: None, `max_size`: Some(22), added: 2497, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskAllocations` (r:1 w:0)
+	/// Proof: `TaskManagement::TaskAllocations` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::ExecutableWorkers` (r:1 w:1)
+	/// Proof: `EdgeConnect::ExecutableWorkers` (`max_values`: None, `max_size`: Some(260), added: 2735, mode: `MaxEncodedLen`)
 	fn on_new_data_finalize() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `49`
 		//  Estimated: `564553`
 		// Minimum execution time: 24_751_000 picoseconds.
+		// Placeholder estimate: storage accesses adjusted by hand since the last benchmark.
 		Weight::from_parts(25_831_000, 564553)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 }
 
@@ -115,13 +122,18 @@ impl WeightInfo for () {
 	/// Proof: `NeuroZk::SubmittedPerProof` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `NeuroZk::VerificationResultsPerProof` (r:1 w:1)
 	/// Proof: `NeuroZk::VerificationResultsPerProof` (`max_values`: None, `max_size`: Some(22), added: 2497, mode: `MaxEncodedLen`)
+	/// Storage: `TaskManagement::TaskAllocations` (r:1 w:0)
+	/// Proof: `TaskManagement::TaskAllocations` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `EdgeConnect::ExecutableWorkers` (r:1 w:1)
+	/// Proof: `EdgeConnect::ExecutableWorkers` (`max_values`: None, `max_size`: Some(260), added: 2735, mode: `MaxEncodedLen`)
 	fn on_new_data_finalize() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `49`
 		//  Estimated: `564553`
 		// Minimum execution time: 24_751_000 picoseconds.
+		// Placeholder estimate: storage accesses adjusted by hand since the last benchmark.
 		Weight::from_parts(25_831_000, 564553)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 }